@@ -0,0 +1,130 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{BitInstructions, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 定宽整数运算示例：证明 y = (a * x + b) mod 2^NUM_BITS，a、b 为电路外固定的常数，x 为 private input，y 为 public input
+///
+/// 电路中的加法和乘法都是模 p 的（p 约为 2^254），不会在 2^NUM_BITS 处回绕，所以要显式地做取模：
+/// - 在域中计算 t = a * x + b，a、b、x 都小于 2^NUM_BITS 时 t < 2^(2 * NUM_BITS)，不会超过 p；
+/// - 证明者给出商 q 和余数 y（witness），约束 t = q * 2^NUM_BITS + y；
+/// - 用二进制分解约束 y < 2^NUM_BITS、q < 2^(NUM_BITS + 1)，x 也要约束在 NUM_BITS 位中。
+///
+/// 没有对 y 的范围检查时，证明者可以取 q = 0、y = t，公开一个没有取模的结果；
+/// 没有对 q 的范围检查时，q 可以取很大的值，让 q * 2^NUM_BITS + y 在模 p 下回绕，y 几乎可以是任意值。
+///
+/// 整数的位数
+pub const NUM_BITS: usize = 8;
+
+/// 电路外计算 (a * x + b) mod 2^NUM_BITS，用于得到正确的 public input
+pub fn affine_mod(a: u64, b: u64, x: u64) -> u64 {
+    (a * x + b) % (1 << NUM_BITS)
+}
+
+/// a、b 为电路结构的一部分；q、y 为证明者给出的商和余数，new 计算正确的值，也可以直接构造错误的值
+#[derive(Clone, Debug)]
+pub struct AffineModCircuit<F: PrimeField> {
+    pub a: u64,
+    pub b: u64,
+    pub x: Value<F>,
+    pub quotient: Value<F>,
+    pub y: Value<F>,
+}
+
+impl<F: PrimeField> AffineModCircuit<F> {
+    pub fn new(a: u64, b: u64, x: u64) -> Self {
+        assert!(
+            a < 1 << NUM_BITS && b < 1 << NUM_BITS,
+            "a and b must fit in NUM_BITS bits"
+        );
+        Self {
+            a,
+            b,
+            x: Value::known(F::from(x)),
+            quotient: Value::known(F::from((a * x + b) >> NUM_BITS)),
+            y: Value::known(F::from(affine_mod(a, b, x))),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for AffineModCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+            x: Value::unknown(),
+            quotient: Value::unknown(),
+            y: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        chip.to_bits(layouter.namespace(|| "x range"), x.clone(), NUM_BITS)?;
+        let t = chip.linear_combination(
+            layouter.namespace(|| "t = a * x + b"),
+            &[x],
+            &[F::from(self.a)],
+            F::from(self.b),
+        )?;
+
+        let q = chip.load_private(layouter.namespace(|| "load q"), self.quotient)?;
+        let y = chip.load_private(layouter.namespace(|| "load y"), self.y)?;
+        chip.to_bits(layouter.namespace(|| "y range"), y.clone(), NUM_BITS)?;
+        chip.to_bits(layouter.namespace(|| "q range"), q.clone(), NUM_BITS + 1)?;
+        let recomposed = chip.linear_combination(
+            layouter.namespace(|| "q * 2^NUM_BITS + y"),
+            &[q, y.clone()],
+            &[F::from(1 << NUM_BITS), F::ONE],
+            F::ZERO,
+        )?;
+        chip.link(
+            layouter.namespace(|| "t = q * 2^NUM_BITS + y"),
+            t,
+            recomposed,
+        )?;
+
+        chip.expose_public(layouter.namespace(|| "expose y"), y, 0)
+    }
+}
+
+/// public input 为取模后的 y
+impl<F: PrimeField> DescribeInstance for AffineModCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// a = 3、b = 7（与示例 main 相同），x 为随机的 NUM_BITS 位整数
+impl RandomWitness for AffineModCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let (a, b) = (3, 7);
+        let x = rng.next_u64() % (1 << NUM_BITS);
+        let circuit = Self::new(a, b, x);
+        (circuit, vec![vec![Fp::from(affine_mod(a, b, x))]])
+    }
+}
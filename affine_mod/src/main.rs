@@ -0,0 +1,57 @@
+use affine_mod::{affine_mod, AffineModCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{
+    backend::{self, Backend},
+    report,
+};
+
+fn main() {
+    // 定义电路的行数
+    let row = 7;
+
+    // 3 * 10 + 7 = 37，没有溢出
+    let circuit = AffineModCircuit::<Fp>::new(3, 7, 10);
+    let out = Fp::from(affine_mod(3, 7, 10));
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![out]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: affine transformation mod 2^n...");
+
+    // 默认用 MockProver 检查，带 --ipa 参数时生成真实的证明
+    let backend = backend::from_args();
+    let res_backend = backend.check(row, &circuit, &[vec![out]]);
+    println!("res ({} backend): {:?}", backend.name(), res_backend);
+
+    let prover = MockProver::run(row, &circuit, vec![vec![out]]).unwrap();
+    let res = prover.verify();
+    println!("res1 (3 * 10 + 7): {:?}", res);
+
+    // 200 * 200 + 100 = 40100 = 156 * 256 + 164，需要取模：公开的是 164
+    let overflow = AffineModCircuit::<Fp>::new(200, 100, 200);
+    let prover_2 = MockProver::run(row, &overflow, vec![vec![Fp::from(164)]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2 (200 * 200 + 100): {:?}", res_2);
+
+    // 公开域中的结果 40100（没有取模）也不行
+    let prover_3 = MockProver::run(row, &overflow, vec![vec![Fp::from(40100)]]).unwrap();
+    println!(
+        "res3 (unreduced public input): {:?}",
+        prover_3.verify().is_ok()
+    );
+
+    // 证明者取 q = 0、y = 40100，t = q * 2^8 + y 仍然成立，只有 y 的范围检查能发现
+    let mut unreduced = overflow.clone();
+    unreduced.quotient = Value::known(Fp::zero());
+    unreduced.y = Value::known(Fp::from(40100));
+    let res_4 = MockProver::run(row, &unreduced, vec![vec![Fp::from(40100)]])
+        .unwrap()
+        .verify();
+    println!("res4 (q = 0, y = 40100): {:?}", res_4);
+
+    // x 也需要在 8 位中
+    let wide = AffineModCircuit::<Fp>::new(1, 0, 256);
+    let prover_5 = MockProver::run(row, &wide, vec![vec![Fp::zero()]]).unwrap();
+    println!("res5 (x = 256): {:?}", prover_5.verify().is_ok());
+}
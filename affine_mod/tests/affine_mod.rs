@@ -0,0 +1,73 @@
+//! affine_mod 示例电路的测试
+
+use affine_mod::{affine_mod, AffineModCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, layout, prover};
+
+const K: u32 = 7;
+
+/// 3 * 10 + 7 = 37，没有溢出；电路放得进 2^K 行
+#[test]
+fn without_overflow() {
+    assert_eq!(affine_mod(3, 7, 10), 37);
+    let circuit = AffineModCircuit::<Fp>::new(3, 7, 10);
+    assert!(layout::measure(&circuit).k <= K);
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::from(37)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 200 * 200 + 100 = 40100 = 156 * 256 + 164，公开的是取模后的 164，没有取模的 40100 验证失败
+#[test]
+fn overflow_is_reduced() {
+    assert_eq!(affine_mod(200, 100, 200), 164);
+    let overflow = AffineModCircuit::<Fp>::new(200, 100, 200);
+    let res = MockProver::run(K, &overflow, vec![vec![Fp::from(164)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &overflow, vec![vec![Fp::from(40100)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 证明者取 q = 0、y = 40100：失败的是 y 的二进制分解（running sum 的最后一项不为 0），
+/// t = q * 2^8 + y 的拷贝约束仍然满足
+#[test]
+fn unreduced_witness_fails_range_check() {
+    let mut unreduced = AffineModCircuit::<Fp>::new(200, 100, 200);
+    unreduced.quotient = Value::known(Fp::zero());
+    unreduced.y = Value::known(Fp::from(40100));
+    let failures = MockProver::run(K, &unreduced, vec![vec![Fp::from(40100)]])
+        .unwrap()
+        .verify()
+        .unwrap_err();
+    let messages: Vec<String> = failures.iter().map(|f| f.to_string()).collect();
+    assert!(messages.iter().any(|m| m.contains("('bits')")));
+    assert!(messages.iter().all(|m| !m.contains("('link')")));
+}
+
+/// x 也需要在 8 位中
+#[test]
+fn wide_input_fails() {
+    let wide = AffineModCircuit::<Fp>::new(1, 0, 256);
+    let res = MockProver::run(K, &wide, vec![vec![Fp::zero()]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<AffineModCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = AffineModCircuit::<Fp>::new(3, 7, 10);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![Fp::from(37)]]).is_ok());
+}
@@ -0,0 +1,174 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::witness::RandomWitness;
+use simple::{
+    gadgets, prover::DescribeInstance, BitInstructions, NumInstructions, SimpleChip, SimpleConfig,
+};
+
+///////////////////////////////////////////////////////////////////////
+/// 二分查找示例：证明 private 的 target 是否在一个固定的有序数组中，公开 target 和 found
+/// 电路不做查找，而是由证明者给出二分查找的结果：插入位置 i（第一个 >= target 的元素的下标，0 <= i <= n），
+/// 电路只检查相邻的两个元素：arr[i-1] < target <= arr[i]，再判断 arr[i] 是否等于 target。
+///
+/// 下标 i 用 one-hot 向量 e_0, ..., e_n 表示（每个 e_j 为布尔值，Σ e_j = 1），
+/// 数组是常量，所以 arr[i-1]、arr[i] 可以用 dot 指令按 e 取出。两端没有邻居，用哨兵代替：
+/// - left  = Σ e_j * (arr[j-1] + 1)，e_0 对应的哨兵为 0，要求 left < target + 1
+/// - right = Σ e_j * (arr[j] + 2)，e_n 对应的哨兵为 2^BITS + 2，要求 target + 1 < right
+///
+/// 平移后哨兵比任何元素都小（大），两端不需要单独处理；found = is_zero(right - (target + 2))，i = n 时 right 为哨兵，found 为 0。
+///
+/// 数组元素和 target 的位数
+pub const BITS: usize = 8;
+
+/// 电路外计算插入位置：第一个 >= target 的元素的下标
+pub fn insertion_index(array: &[u64], target: u64) -> usize {
+    array.partition_point(|x| *x < target)
+}
+
+pub struct BinSearchCircuit<F: PrimeField> {
+    /// 有序数组，作为常量写入电路
+    pub array: Vec<u64>,
+    pub target: Value<F>,
+    /// 证明者给出的插入位置
+    pub index: Value<usize>,
+}
+
+impl<F: PrimeField> BinSearchCircuit<F> {
+    /// 下标由电路外的二分查找给出
+    pub fn new(array: Vec<u64>, target: u64) -> Self {
+        assert!(
+            array.windows(2).all(|w| w[0] <= w[1]),
+            "array must be sorted"
+        );
+        assert!(array.iter().all(|x| *x < 1 << BITS) && target < 1 << BITS);
+        let index = insertion_index(&array, target);
+        Self {
+            array,
+            target: Value::known(F::from(target)),
+            index: Value::known(index),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for BinSearchCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 数组是电路结构的一部分，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            target: Value::unknown(),
+            index: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+        let n = self.array.len();
+
+        let target = chip.load_private(layouter.namespace(|| "load target"), self.target)?;
+
+        // one-hot 的下标：每个 e_j 分解为 1 位即约束为布尔值，并约束 Σ e_j = 1
+        let mut one_hot = vec![];
+        for j in 0..=n {
+            let e = self.index.map(|i| F::from((i == j) as u64));
+            let e = chip.load_private(layouter.namespace(|| format!("load e_{}", j)), e)?;
+            chip.to_bits(
+                layouter.namespace(|| format!("e_{} is boolean", j)),
+                e.clone(),
+                1,
+            )?;
+            one_hot.push(e);
+        }
+        let sum = chip.sum(layouter.namespace(|| "Σ e"), &one_hot)?;
+        chip.constrain_constant(layouter.namespace(|| "Σ e = 1"), sum, F::ONE)?;
+
+        // 平移后的邻居，两端为哨兵
+        let left_weights: Vec<F> = std::iter::once(F::ZERO)
+            .chain(self.array.iter().map(|x| F::from(x + 1)))
+            .collect();
+        let right_weights: Vec<F> = self
+            .array
+            .iter()
+            .map(|x| F::from(x + 2))
+            .chain(std::iter::once(F::from((1 << BITS) + 2)))
+            .collect();
+        let left = chip.dot(layouter.namespace(|| "left"), &one_hot, &left_weights)?;
+        let right = chip.dot(layouter.namespace(|| "right"), &one_hot, &right_weights)?;
+
+        // arr[i-1] < target <= arr[i]
+        let target_plus_one = chip.linear_combination(
+            layouter.namespace(|| "target + 1"),
+            std::slice::from_ref(&target),
+            &[F::ONE],
+            F::ONE,
+        )?;
+        chip.assert_increasing(
+            layouter.namespace(|| "left < target + 1"),
+            left,
+            target_plus_one.clone(),
+            BITS + 2,
+        )?;
+        chip.assert_increasing(
+            layouter.namespace(|| "target + 1 < right"),
+            target_plus_one,
+            right.clone(),
+            BITS + 2,
+        )?;
+
+        // found = [arr[i] == target]
+        let diff = chip.linear_combination(
+            layouter.namespace(|| "right - target - 2"),
+            &[right, target.clone()],
+            &[F::ONE, -F::ONE],
+            -F::from(2),
+        )?;
+        let found = gadgets::is_zero(&chip, layouter.namespace(|| "found"), diff)?;
+
+        chip.expose_public(layouter.namespace(|| "expose target"), target, 0)?;
+        chip.expose_public(layouter.namespace(|| "expose found"), found, 1)
+    }
+}
+
+/// public input 为 [target, found]
+impl<F: PrimeField> DescribeInstance for BinSearchCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![2]
+    }
+}
+
+/// 数组与示例 main 相同；target 一半的概率取数组中的元素（found = 1），否则为随机的 BITS 位整数
+impl RandomWitness for BinSearchCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let array = vec![3, 8, 15, 23, 42];
+        let target = if rng.next_u32().is_multiple_of(2) {
+            array[rng.next_u32() as usize % array.len()]
+        } else {
+            rng.next_u64() % (1 << BITS)
+        };
+        let found = array.contains(&target);
+        let circuit = Self::new(array, target);
+        (
+            circuit,
+            vec![vec![Fp::from(target), Fp::from(found as u64)]],
+        )
+    }
+}
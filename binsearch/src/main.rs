@@ -0,0 +1,47 @@
+use binsearch::BinSearchCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 9;
+
+    let array = vec![3, 8, 15, 23, 42];
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(
+        row,
+        &BinSearchCircuit::<Fp>::new(array.clone(), 15),
+        vec![vec![Fp::from(15), Fp::from(1)]],
+    ) {
+        return;
+    }
+    println!("Hello, this is halo2 example: binary search membership...");
+
+    // 存在的值（包括第一个和最后一个元素）和不存在的值（小于最小值、在中间的空隙、大于最大值）
+    for (target, found) in [
+        (15, true),
+        (3, true),
+        (42, true),
+        (0, false),
+        (10, false),
+        (255, false),
+    ] {
+        let circuit = BinSearchCircuit::<Fp>::new(array.clone(), target);
+        let public_input = vec![Fp::from(target), Fp::from(found as u64)];
+        let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+        let res = prover.verify();
+        println!("res (target = {}, found = {}): {:?}", target, found, res);
+    }
+
+    // 证明者给出错误的下标，试图证明 15 不在数组中：相邻元素的检查不通过
+    for index in [0, 3, 5] {
+        let circuit = BinSearchCircuit {
+            index: Value::known(index),
+            ..BinSearchCircuit::<Fp>::new(array.clone(), 15)
+        };
+        let prover = MockProver::run(row, &circuit, vec![vec![Fp::from(15), Fp::zero()]]).unwrap();
+        let res = prover.verify();
+        println!("res (target = 15, index = {}): {}", index, res.is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+//! binsearch 示例电路的测试
+
+use binsearch::BinSearchCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+
+const K: u32 = 9;
+
+const ARRAY: [u64; 5] = [3, 8, 15, 23, 42];
+
+/// 存在的值（包括第一个和最后一个元素）和不存在的值（小于最小值、在中间的空隙、大于最大值）；
+/// 公开相反的结果时验证失败
+#[test]
+fn membership() {
+    for (target, found) in [
+        (15, true),
+        (3, true),
+        (42, true),
+        (0, false),
+        (10, false),
+        (255, false),
+    ] {
+        let circuit = BinSearchCircuit::<Fp>::new(ARRAY.to_vec(), target);
+        let public_input = vec![Fp::from(target), Fp::from(found as u64)];
+        let res = MockProver::run(K, &circuit, vec![public_input])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "target = {}", target);
+
+        let flipped = vec![Fp::from(target), Fp::from(!found as u64)];
+        let res = MockProver::run(K, &circuit, vec![flipped])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "target = {}", target);
+    }
+}
+
+/// 证明者给出错误的下标，试图证明 15 不在数组中：相邻元素的检查不通过
+#[test]
+fn wrong_index_fails() {
+    for index in [0, 3, 5] {
+        let circuit = BinSearchCircuit {
+            index: Value::known(index),
+            ..BinSearchCircuit::<Fp>::new(ARRAY.to_vec(), 15)
+        };
+        let res = MockProver::run(K, &circuit, vec![vec![Fp::from(15), Fp::zero()]])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "index = {}", index);
+    }
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<BinSearchCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = BinSearchCircuit::<Fp>::new(ARRAY.to_vec(), 15);
+    let public_input = vec![Fp::from(15), Fp::from(1)];
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
@@ -0,0 +1,70 @@
+use halo2_proofs::{
+    dev::CircuitGates,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem},
+};
+use simple::layout;
+
+///////////////////////////////////////////////////////////////////////
+/// 示例电路的资源预算：`cargo test -p budget` 检查每个示例电路的 advice 列数、门的个数和行数不超过预算，
+/// 重构时如果不小心让教学电路变大，测试就会失败。确实需要更多资源时，同时修改 tests/budget.rs 中的预算。
+///
+/// - 门的个数来自 CircuitGates 的统计（"Total gates"）；
+/// - advice 列数来自 ConstraintSystem（字段不公开，从 Debug 输出中读取）；
+/// - 行数来自 simple::layout::measure，与 CircuitCost 的 max_rows 相同，但不要求电路实现 Debug
+///   （CircuitCost 的 Debug 要求电路实现 Debug，大部分示例电路没有实现）。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Budget {
+    pub advice_columns: usize,
+    pub gates: usize,
+    pub rows: usize,
+}
+
+/// 统计电路实际使用的资源，字段含义与 Budget 相同
+pub fn usage<C: Circuit<Fp>>(circuit: &C) -> Budget {
+    let gates = CircuitGates::collect::<Fp, C>().to_string();
+    let gates = gates
+        .split("Total gates: ")
+        .nth(1)
+        .and_then(|rest| rest.lines().next())
+        .and_then(|n| n.trim().parse().ok())
+        .expect("CircuitGates output should contain the total gate count");
+
+    let mut cs = ConstraintSystem::<Fp>::default();
+    C::configure(&mut cs);
+    let advice_columns = format!("{:?}", cs)
+        .split("num_advice_columns: ")
+        .nth(1)
+        .and_then(|rest| rest.split(',').next())
+        .and_then(|n| n.trim().parse().ok())
+        .expect("ConstraintSystem debug output should contain num_advice_columns");
+
+    Budget {
+        advice_columns,
+        gates,
+        rows: layout::measure(circuit).rows,
+    }
+}
+
+/// 检查电路的资源不超过预算，超出时返回哪一项超出以及实际的使用量
+pub fn check<C: Circuit<Fp>>(circuit: &C, budget: Budget) -> Result<Budget, String> {
+    let used = usage(circuit);
+    let over: Vec<String> = [
+        ("advice columns", used.advice_columns, budget.advice_columns),
+        ("gates", used.gates, budget.gates),
+        ("rows", used.rows, budget.rows),
+    ]
+    .iter()
+    .filter(|(_, used, limit)| used > limit)
+    .map(|(name, used, limit)| format!("{}: {} > {}", name, used, limit))
+    .collect();
+    if over.is_empty() {
+        Ok(used)
+    } else {
+        Err(format!(
+            "over budget ({}), used {:?}",
+            over.join(", "),
+            used
+        ))
+    }
+}
@@ -0,0 +1,303 @@
+//! 每个示例电路的资源预算，数值为当前的实际用量
+//! 预算检查失败说明电路变大了：确认是有意的修改后再同时调整这里的预算
+
+use std::marker::PhantomData;
+
+use budget::{check, Budget};
+use halo2_proofs::{circuit::Value, pasta::Fp, plonk::Circuit};
+
+fn assert_within<C: Circuit<Fp>>(circuit: &C, advice_columns: usize, gates: usize, rows: usize) {
+    let budget = Budget {
+        advice_columns,
+        gates,
+        rows,
+    };
+    if let Err(err) = check(circuit, budget) {
+        panic!("{}", err);
+    }
+}
+
+#[test]
+fn simple() {
+    let (circuit, _) = simple::SimpleCircuit::<Fp>::builder().a(2).b(3).build();
+    assert_within(&circuit, 2, 7, 9);
+}
+
+#[test]
+fn fibonacci() {
+    assert_within(
+        &fibonacci::version_1::FibonacciCircuit::<Fp>(PhantomData),
+        3,
+        1,
+        8,
+    );
+    assert_within(
+        &fibonacci::version_2::FibonacciCircuit::<Fp>(PhantomData),
+        1,
+        1,
+        10,
+    );
+}
+
+#[test]
+fn commitment() {
+    let circuit = commitment::CommitmentCircuit {
+        value: Value::known(Fp::from(42)),
+        blinding: Value::known(Fp::from(7)),
+    };
+    assert_within(&circuit, 3, 2, 67);
+}
+
+#[test]
+fn moving_avg() {
+    let circuit = moving_avg::MovingAvgCircuit {
+        inputs: (1..=6).map(|x| Value::known(Fp::from(x))).collect(),
+        window: 3,
+    };
+    assert_within(&circuit, 2, 7, 34);
+}
+
+struct Allowed;
+
+impl whitelist::Whitelist for Allowed {
+    const ALLOWED: &'static [u64] = &[3, 5, 7];
+}
+
+#[test]
+fn whitelist() {
+    let circuit = whitelist::WhitelistCircuit::<Fp, Allowed>::new(Value::known(Fp::from(5)));
+    assert_within(&circuit, 1, 0, 4);
+}
+
+#[test]
+fn range_and_gate() {
+    assert_within(
+        &range_and_gate::RangeAndGateCircuit::new(Fp::from(12)),
+        2,
+        1,
+        256,
+    );
+}
+
+#[test]
+fn region_reuse() {
+    let inputs: Vec<_> = (1..=4).map(|x| Value::known(Fp::from(x))).collect();
+    let per_op = region_reuse::ProductCircuit {
+        inputs: inputs.clone(),
+        layout: region_reuse::RegionLayout::PerOp,
+    };
+    let shared = region_reuse::ProductCircuit {
+        inputs,
+        layout: region_reuse::RegionLayout::Shared,
+    };
+    assert_within(&per_op, 3, 1, 7);
+    assert_within(&shared, 3, 1, 3);
+}
+
+#[test]
+fn lookup_demo() {
+    let circuit = lookup_demo::LookupDemoCircuit {
+        values: vec![1, 5, 15],
+    };
+    assert_within(&circuit, 1, 0, 16);
+}
+
+#[test]
+fn matvec() {
+    let matrix = vec![
+        vec![Fp::from(1), Fp::from(2)],
+        vec![Fp::from(3), Fp::from(4)],
+    ];
+    let x = vec![Value::known(Fp::from(5)), Value::known(Fp::from(6))];
+    assert_within(&matvec::MatVecCircuit::new(matrix, x), 2, 7, 18);
+}
+
+#[test]
+fn sorted() {
+    let circuit = sorted::SortedCircuit {
+        values: [1, 3, 3, 8]
+            .iter()
+            .map(|&x| Value::known(Fp::from(x)))
+            .collect(),
+    };
+    assert_within(&circuit, 7, 4, 256);
+}
+
+#[test]
+fn hash_chain() {
+    let circuit = hash_chain::HashChainCircuit {
+        seed: Value::known(Fp::from(1)),
+        depth: 3,
+    };
+    assert_within(&circuit, 3, 2, 197);
+}
+
+#[test]
+fn constants_demo() {
+    let circuit = constants_demo::ConstantsCircuit {
+        x: Value::known(Fp::from(9)),
+    };
+    assert_within(&circuit, 2, 1, 7);
+}
+
+#[test]
+fn distinct() {
+    let circuit = distinct::DistinctCircuit {
+        block: std::array::from_fn(|i| Value::known(Fp::from(i as u64 + 1))),
+    };
+    assert_within(&circuit, 5, 1, 72);
+}
+
+#[test]
+fn range_check() {
+    let circuit = range_check::RangeCheckCircuit {
+        value: Value::known(Fp::from(1000)),
+        num_limbs: 2,
+    };
+    assert_within(&circuit, 1, 1, 256);
+}
+
+#[test]
+fn composite() {
+    let circuit = composite::CompositeCircuit {
+        x: Value::known(Fp::from(12)),
+    };
+    assert_within(&circuit, 2, 8, 256);
+}
+
+#[test]
+fn parity() {
+    let circuit = parity::ParityCircuit {
+        x: Value::known(Fp::from(6)),
+    };
+    assert_within(&circuit, 2, 7, 16);
+}
+
+#[test]
+fn linreg() {
+    let model = linreg::LinearModel {
+        weights: vec![Fp::from(2), Fp::from(3)],
+        bias: Fp::from(1),
+    };
+    let x = vec![Value::known(Fp::from(4)), Value::known(Fp::from(5))];
+    assert_within(&linreg::LinRegCircuit::new(model, x), 2, 7, 13);
+}
+
+#[test]
+fn rotation_demo() {
+    let xs = [1, 4, 9].map(Fp::from);
+    assert_within(&rotation_demo::DiffCircuit::new(&xs), 2, 1, 3);
+}
+
+#[test]
+fn recursion_demo() {
+    let circuit = recursion_demo::RecursionDemoCircuit {
+        constant: Fp::from(1),
+        a: Value::known(Fp::from(2)),
+        b: Value::known(Fp::from(3)),
+        inner_result: Value::known(Fp::from(36)),
+    };
+    assert_within(&circuit, 2, 7, 10);
+}
+
+#[test]
+fn binsearch() {
+    let circuit = binsearch::BinSearchCircuit::<Fp>::new(vec![1, 4, 9, 16], 9);
+    assert_within(&circuit, 2, 7, 182);
+}
+
+#[test]
+fn vec_scale() {
+    let xs = [1, 2, 3].map(Fp::from);
+    assert_within(&vec_scale::VecScaleCircuit::new(&xs), 2, 7, 10);
+}
+
+#[test]
+fn scalar_mul_field() {
+    let circuit = scalar_mul_field::ScalarMulCircuit::new(Fp::from(3), 13);
+    assert_within(&circuit, 2, 7, 60);
+}
+
+#[test]
+fn cubic() {
+    let circuit = cubic::CubicCircuit {
+        a: Value::known(Fp::from(3)),
+    };
+    assert_within(&circuit, 2, 1, 1);
+}
+
+#[test]
+fn relu() {
+    let circuit = relu::ReluCircuit {
+        x: Value::known(Fp::from(42)),
+    };
+    assert_within(&circuit, 2, 7, 19);
+}
+
+#[test]
+fn shared_selector() {
+    let pairs = [(Fp::from(2), Fp::from(3)), (Fp::from(4), Fp::from(5))];
+    let circuit = shared_selector::SharedSelectorCircuit::<Fp>::new(&pairs);
+    assert_within(&circuit, 4, 1, 4);
+}
+
+#[test]
+fn affine_mod() {
+    let circuit = affine_mod::AffineModCircuit::<Fp>::new(200, 100, 200);
+    assert_within(&circuit, 2, 7, 48);
+}
+
+#[test]
+fn schnorr_field() {
+    let circuit = schnorr_field::SchnorrCircuit {
+        x: Value::known(Fp::from(42)),
+        r: Value::known(Fp::from(7)),
+    };
+    assert_within(&circuit, 2, 7, 13);
+}
+
+#[test]
+fn square_lookup() {
+    let circuit = square_lookup::SquareLookupCircuit::<Fp>::new(7);
+    assert_within(&circuit, 2, 0, 16);
+}
+
+#[test]
+fn value_demo() {
+    let circuit = value_demo::ValueDemoCircuit {
+        a: Value::known(Fp::from(3)),
+        b: Value::known(Fp::from(5)),
+    };
+    assert_within(&circuit, 4, 1, 1);
+}
+
+#[test]
+fn linked_circuits() {
+    let first = linked_circuits::ProducerCircuit {
+        a: Value::known(Fp::from(3)),
+        b: Value::known(Fp::from(4)),
+    };
+    assert_within(&first, 2, 7, 4);
+    let second = linked_circuits::ConsumerCircuit {
+        c: Value::known(Fp::from(5)),
+    };
+    assert_within(&second, 2, 7, 4);
+}
+
+#[test]
+fn instance_gate() {
+    let circuit = instance_gate::InstanceGateCircuit::<Fp>::new(&[(2, 3), (4, 5), (6, 7)]);
+    assert_within(&circuit, 2, 1, 3);
+}
+
+#[test]
+fn onehot() {
+    let circuit = onehot::OneHotCircuit::<Fp>::new(5, onehot::LEN);
+    assert_within(&circuit, 5, 3, 8);
+}
+
+#[test]
+fn fixed_vector() {
+    let circuit = fixed_vector::FixedVectorCircuit::<Fp>::new(&[2, 3, 5, 7], &[10, 20, 30, 40]);
+    assert_within(&circuit, 2, 1, 4);
+}
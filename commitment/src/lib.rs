@@ -0,0 +1,109 @@
+use group::ff::{Field, PrimeField};
+use halo2_poseidon::{P128Pow5T3, Spec};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, Column, ConstraintSystem, Error, Instance},
+};
+use poseidon::{PoseidonChip, PoseidonConfig, RATE, WIDTH};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 承诺（commitment）示例：证明公开的承诺值 commitment = hash(value, blinding)
+/// 其中 value 为被承诺的值，blinding 为随机的盲化因子，二者都是 private input，
+/// commitment 为 public input。
+/// 验证者只看到 commitment，证明者通过电路证明自己知道能“打开”这个承诺的 value 和 blinding。
+///
+/// 电路外计算承诺值：与电路中的 Poseidon 哈希一致
+pub fn commit<F>(value: F, blinding: F) -> F
+where
+    F: PrimeField,
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    poseidon::hash_to_field([value, blinding])
+}
+
+#[derive(Clone, Debug)]
+pub struct CommitmentConfig<F: PrimeField> {
+    poseidon: PoseidonConfig<F>,
+    instance: Column<Instance>,
+}
+
+#[derive(Default)]
+pub struct CommitmentCircuit<F: PrimeField> {
+    pub value: Value<F>,
+    pub blinding: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for CommitmentCircuit<F>
+where
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    type Config = CommitmentConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // 3个 advice 列存放 Poseidon 的状态，3个 fixed 列存放轮常数
+        let state = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let rc = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        let constant = meta.fixed_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        CommitmentConfig {
+            poseidon: PoseidonChip::configure::<P128Pow5T3>(meta, state, rc, constant),
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = PoseidonChip::construct(config.poseidon);
+
+        let value = chip.load_private(layouter.namespace(|| "load value"), self.value)?;
+        let blinding = chip.load_private(layouter.namespace(|| "load blinding"), self.blinding)?;
+
+        // commitment = hash(value, blinding)
+        let commitment = chip.hash(layouter.namespace(|| "commit"), [value, blinding])?;
+
+        // 把承诺值约束为电路的 public input
+        layouter.constrain_instance(commitment.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为 commitment
+impl<F: PrimeField> DescribeInstance for CommitmentCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// value 和 blinding 都是随机的域元素
+impl RandomWitness for CommitmentCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let value = Fp::random(&mut *rng);
+        let blinding = Fp::random(&mut *rng);
+        let circuit = Self {
+            value: Value::known(value),
+            blinding: Value::known(blinding),
+        };
+        (circuit, vec![vec![commit(value, blinding)]])
+    }
+}
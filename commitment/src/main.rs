@@ -0,0 +1,37 @@
+use commitment::{commit, CommitmentCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 7;
+
+    // 被承诺的值和盲化因子
+    let value = Fp::from(42);
+    let blinding = Fp::from(123456789);
+    let commitment = commit(value, blinding);
+
+    // 用正确的 value 和 blinding 打开承诺，验证成功
+    let circuit = CommitmentCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding),
+    };
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![commitment]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: commitment...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![commitment]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 使用错误的盲化因子，算出的哈希与公开的承诺值不一致，验证失败
+    let circuit_2 = CommitmentCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding + Fp::one()),
+    };
+    let prover_2 = MockProver::run(row, &circuit_2, vec![vec![commitment]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
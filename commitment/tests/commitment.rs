@@ -0,0 +1,48 @@
+//! commitment 示例电路的测试
+
+use commitment::{commit, CommitmentCircuit};
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+
+const K: u32 = 7;
+
+/// 用正确的 value 和 blinding 打开承诺，验证成功；使用错误的盲化因子时算出的哈希与公开的承诺值不一致
+#[test]
+fn open_commitment() {
+    let (value, blinding) = (Fp::from(42), Fp::from(123456789));
+    let commitment = commit(value, blinding);
+    let circuit = CommitmentCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding),
+    };
+    let res = MockProver::run(K, &circuit, vec![vec![commitment]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+
+    let wrong_blinding = CommitmentCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding + Fp::one()),
+    };
+    let res = MockProver::run(K, &wrong_blinding, vec![vec![commitment]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<CommitmentCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (value, blinding) = (Fp::from(42), Fp::from(123456789));
+    let circuit = CommitmentCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding),
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![commit(value, blinding)]]).is_ok());
+}
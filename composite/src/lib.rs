@@ -0,0 +1,85 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use range_check::{RangeCheckChip, RangeCheckConfig};
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 芯片组合示例：先检查 private 的输入 x 是 8 bit 的数，再计算 x^2 并公开
+/// 两个芯片在同一个 ConstraintSystem 中配置，并共用 advice 列：
+/// - SimpleChip（算术芯片）使用 advice[0]、advice[1]，提供 load_private、mul、expose_public
+/// - RangeCheckChip（范围检查芯片）把 advice[0] 作为 running sum 列
+///
+/// 两个芯片的选择器不同，所以同一列上的不同 region 只会激活各自的约束；
+/// 单元格通过拷贝约束在芯片之间传递（SimpleChip 的 ACell 与 AssignedCell 互相转换）。
+#[derive(Clone, Debug)]
+pub struct CompositeConfig {
+    arith: SimpleConfig,
+    range: RangeCheckConfig,
+}
+
+/// x 的范围：0 <= x < 2^(8 * NUM_LIMBS)
+pub const NUM_LIMBS: usize = 1;
+
+#[derive(Default)]
+pub struct CompositeCircuit<F: PrimeField> {
+    pub x: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for CompositeCircuit<F> {
+    type Config = CompositeConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        CompositeConfig {
+            arith: SimpleChip::configure(meta, advice, instance, constant),
+            // 与算术芯片共用 advice[0]
+            range: RangeCheckChip::configure(meta, advice[0]),
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let arith = SimpleChip::<F>::construct(config.arith);
+        let range = RangeCheckChip::construct(config.range);
+        range.load_table(layouter.namespace(|| "load range table"))?;
+
+        let x = arith.load_private(layouter.namespace(|| "load x"), self.x)?;
+        range.range_check(layouter.namespace(|| "x < 2^8"), x.inner(), NUM_LIMBS)?;
+
+        let square = arith.mul(layouter.namespace(|| "x * x"), x.clone(), x)?;
+        arith.expose_public(layouter.namespace(|| "expose x^2"), square, 0)
+    }
+}
+
+/// public input 为 x^2
+impl<F: PrimeField> DescribeInstance for CompositeCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// x 为随机的 8 * NUM_LIMBS 位整数
+impl RandomWitness for CompositeCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let x = Fp::from(rng.next_u64() % (1 << (8 * NUM_LIMBS)));
+        (Self { x: Value::known(x) }, vec![vec![x.square()]])
+    }
+}
@@ -0,0 +1,34 @@
+use composite::CompositeCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+
+fn main() {
+    // 范围表有 256 行，电路至少需要 2^9 行
+    let row = 9;
+
+    // x = 200 在范围内，x^2 = 40000，验证成功
+    let circuit = CompositeCircuit {
+        x: Value::known(Fp::from(200)),
+    };
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![Fp::from(40000)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: composite chips...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![Fp::from(40000)]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 范围检查通过，但公开的平方错误，验证失败
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![Fp::from(40001)]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // x = 300 超出 8 bit，即使平方正确，范围检查也不满足
+    let circuit_3 = CompositeCircuit {
+        x: Value::known(Fp::from(300)),
+    };
+    let prover_3 = MockProver::run(row, &circuit_3, vec![vec![Fp::from(90000)]]).unwrap();
+    println!("res3: {:?}", prover_3.verify());
+}
@@ -0,0 +1,49 @@
+//! composite 示例电路的测试
+
+use composite::CompositeCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+
+/// 范围表有 256 行，电路至少需要 2^9 行
+const K: u32 = 9;
+
+/// x = 200 在范围内，x^2 = 40000，验证成功；范围检查通过但公开的平方错误时验证失败
+#[test]
+fn square_in_range() {
+    let circuit = CompositeCircuit {
+        x: Value::known(Fp::from(200)),
+    };
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::from(40000)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::from(40001)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// x = 300 超出 8 bit，即使平方正确，范围检查也不满足
+#[test]
+fn out_of_range_fails() {
+    let circuit = CompositeCircuit {
+        x: Value::known(Fp::from(300)),
+    };
+    let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(90000)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "range_check_zero");
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<CompositeCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = CompositeCircuit {
+        x: Value::known(Fp::from(200)),
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![Fp::from(40000)]]).is_ok());
+}
@@ -0,0 +1,188 @@
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Fixed, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// 常量示例：用 fixed 列加载常数 0 和 1，通过乘法门证明 0 * x = 0 和 1 * x = x
+///
+/// 为什么需要 enable_constant：
+/// advice 列中的值由证明者任意填写，直接 assign_advice(0) 得到的“0”并没有被约束，证明者可以填任何值。
+/// 常量必须来自 fixed 列，fixed 列的值在生成密钥时就确定了，验证者也知道。
+/// assign_advice_from_constant 会做两件事：
+/// 1）把常量写入一个被 enable_constant 启用的 fixed 列（由布局器统一放置）
+/// 2）在 advice 单元格和这个 fixed 单元格之间添加拷贝约束，advice 中的值因此被固定为常量
+/// 所以 fixed 列必须先用 enable_constant 启用（这同时会 enable_equality），
+/// 否则布局器找不到放常量的列，synthesize 会返回 Error::NotEnoughColumnsForConstants。
+///
+/// | a0  | a1  | s_mul |
+/// |-----|-----|-------|
+/// | lhs | rhs | 1     |
+/// | out |     |       |
+#[derive(Clone, Debug)]
+pub struct ConstantsConfig {
+    advice: [Column<Advice>; 2],
+    s_mul: Selector,
+}
+
+pub struct ConstantsChip<F: Field> {
+    config: ConstantsConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> ConstantsChip<F> {
+    pub fn construct(config: ConstantsConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 2],
+        constant: Column<Fixed>,
+    ) -> ConstantsConfig {
+        // 拷贝约束需要 advice 列启用 equality
+        for c in advice {
+            meta.enable_equality(c);
+        }
+        // 启用 fixed 列用于存放常量
+        meta.enable_constant(constant);
+
+        let s_mul = meta.selector();
+        meta.create_gate("mul", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_mul = meta.query_selector(s_mul);
+            vec![s_mul * (lhs * rhs - out)]
+        });
+
+        ConstantsConfig { advice, s_mul }
+    }
+
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        x: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "load_private",
+            |mut region| region.assign_advice(|| "x", self.config.advice[0], 0, || x),
+        )
+    }
+
+    /// 从 fixed 列加载常量：advice 单元格通过拷贝约束等于 fixed 列中的常量
+    pub fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "load_constant",
+            |mut region| {
+                region.assign_advice_from_constant(
+                    || "constant",
+                    self.config.advice[0],
+                    0,
+                    constant,
+                )
+            },
+        )
+    }
+
+    pub fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "mul",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+                a.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                let res = a.value().copied() * b.value();
+                region.assign_advice(|| "lhs * rhs", config.advice[0], 1, || res)
+            },
+        )
+    }
+
+    /// 约束两个单元格相等
+    pub fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "constrain_equal",
+            |mut region| region.constrain_equal(a.cell(), b.cell()),
+        )
+    }
+}
+
+/// 电路中只有一个 private 的 x，没有 public input
+#[derive(Default)]
+pub struct ConstantsCircuit<F: Field> {
+    pub x: Value<F>,
+}
+
+impl<F: Field> Circuit<F> for ConstantsCircuit<F> {
+    type Config = ConstantsConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let constant = meta.fixed_column();
+        ConstantsChip::configure(meta, advice, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = ConstantsChip::construct(config);
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let zero = chip.load_constant(layouter.namespace(|| "load 0"), F::ZERO)?;
+        let one = chip.load_constant(layouter.namespace(|| "load 1"), F::ONE)?;
+
+        // 0 * x = 0
+        let zero_x = chip.mul(layouter.namespace(|| "0 * x"), &zero, &x)?;
+        chip.constrain_equal(layouter.namespace(|| "0 * x == 0"), &zero_x, &zero)?;
+
+        // 1 * x = x
+        let one_x = chip.mul(layouter.namespace(|| "1 * x"), &one, &x)?;
+        chip.constrain_equal(layouter.namespace(|| "1 * x == x"), &one_x, &x)
+    }
+}
+
+/// 没有 public input
+impl<F: Field> DescribeInstance for ConstantsCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// x 为随机的域元素，没有 public input
+impl RandomWitness for ConstantsCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let x = Fp::random(rng);
+        (Self { x: Value::known(x) }, vec![])
+    }
+}
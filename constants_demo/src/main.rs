@@ -0,0 +1,28 @@
+use constants_demo::ConstantsCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{field::fp_to_decimal, report};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(
+        row,
+        &ConstantsCircuit {
+            x: Value::known(Fp::from(7)),
+        },
+        vec![],
+    ) {
+        return;
+    }
+    println!("Hello, this is halo2 example: constants...");
+
+    // 0 * x = 0 和 1 * x = x 对任意的 x 都成立，包括 0、1 和 -1
+    for x in [Fp::zero(), Fp::one(), Fp::from(7), -Fp::one()] {
+        let circuit = ConstantsCircuit { x: Value::known(x) };
+        let prover = MockProver::run(row, &circuit, vec![]).unwrap();
+        let res = prover.verify();
+        println!("res (x = {}): {:?}", fp_to_decimal(x), res);
+    }
+}
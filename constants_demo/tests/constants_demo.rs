@@ -0,0 +1,32 @@
+//! constants_demo 示例电路的测试
+
+use constants_demo::ConstantsCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+
+const K: u32 = 4;
+
+/// 0 * x = 0 和 1 * x = x 对任意的 x 都成立，包括 0、1 和 -1
+#[test]
+fn holds_for_any_x() {
+    for x in [Fp::zero(), Fp::one(), Fp::from(7), -Fp::one()] {
+        let circuit = ConstantsCircuit { x: Value::known(x) };
+        let res = MockProver::run(K, &circuit, vec![]).unwrap().verify();
+        assert_eq!(res, Ok(()), "x = {:?}", x);
+    }
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ConstantsCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = ConstantsCircuit {
+        x: Value::known(Fp::from(7)),
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[]).is_ok());
+}
@@ -0,0 +1,103 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 高次门示例：用一个自定义门证明 out = a^3，其中 a 为 private input，out 为 public input
+/// 门的约束为 s * (a * a * a - out) = 0，选择器也要算一次，所以门的次数为 4，
+/// 其它示例中的门（例如乘法门 s * (lhs * rhs - out)）次数为 3。
+///
+/// | a | out | s_cube |
+/// |---|-----|--------|
+/// | a | a^3 | 1      |
+///
+/// 次数对电路的影响：
+/// - 次数不影响行数，所以不影响最小的 k（k 只由行数和 blinding 行决定）；
+/// - 证明者需要在 2^(k + ⌈log2(d - 1)⌉) 大小的扩展域上计算商多项式，次数越高，证明越慢；
+/// - 商多项式被拆成 d - 1 段分别承诺，次数越高，证明越大。
+///
+/// 用两个次数为 3 的乘法门（t = a * a，out = t * a）也能实现，但需要多一行和一个中间值的单元格。
+/// 一般在行数和次数之间权衡：所有门的最大次数决定了整个电路的开销。
+#[derive(Clone, Debug)]
+pub struct CubicConfig {
+    a: Column<Advice>,
+    out: Column<Advice>,
+    instance: Column<Instance>,
+    s_cube: Selector,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CubicCircuit<F: PrimeField> {
+    pub a: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for CubicCircuit<F> {
+    type Config = CubicConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let out = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(out);
+        meta.enable_equality(instance);
+
+        let s_cube = meta.selector();
+        meta.create_gate("cube", |meta| {
+            let s = meta.query_selector(s_cube);
+            let a = meta.query_advice(a, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+            // Expression 的乘法会累加次数：s(1) * a(1) * a(1) * a(1) 的次数为 4
+            vec![s * (a.clone() * a.clone() * a - out)]
+        });
+
+        CubicConfig {
+            a,
+            out,
+            instance,
+            s_cube,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let out = layouter.assign_region(
+            || "cube",
+            |mut region| {
+                config.s_cube.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || self.a)?;
+                region.assign_advice(|| "a^3", config.out, 0, || self.a.map(|a| a.square() * a))
+            },
+        )?;
+        layouter.constrain_instance(out.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为 a^3
+impl<F: PrimeField> DescribeInstance for CubicCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// a 为随机的域元素
+impl RandomWitness for CubicCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = Fp::random(rng);
+        (Self { a: Value::known(a) }, vec![vec![a.square() * a]])
+    }
+}
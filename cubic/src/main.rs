@@ -0,0 +1,58 @@
+use cubic::CubicCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{
+    backend::{self, Backend},
+    dev, layout, prover, report, SimpleCircuit,
+};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    let circuit = CubicCircuit {
+        a: Value::known(Fp::from(3)),
+    };
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![Fp::from(27)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: cubic gate...");
+
+    // 默认用 MockProver 检查，带 --ipa 参数时生成真实的证明
+    let backend = backend::from_args();
+    let res_backend = backend.check(row, &circuit, &[vec![Fp::from(27)]]);
+    println!("res ({} backend): {:?}", backend.name(), res_backend);
+
+    // 3^3 = 27，验证成功
+    let prover = MockProver::run(row, &circuit, vec![vec![Fp::from(27)]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 错误的 public input，out 与 instance 的拷贝约束不满足
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![Fp::from(9)]]).unwrap();
+    println!("res2: {:?}", prover_2.verify());
+
+    // 次数：立方门为 4，SimpleCircuit 中的门（以及拷贝约束参数）为 3
+    let cubic_degree = dev::measure_degree::<CubicCircuit<Fp>>();
+    let simple_degree = dev::measure_degree::<SimpleCircuit<Fp>>();
+    println!(
+        "degree: cubic = {}, simple = {}",
+        cubic_degree, simple_degree
+    );
+
+    // 次数不影响最小的 k：电路只有一行，加上 blinding 行后 2^3 行就够了
+    let min_k = layout::measure(&circuit).k;
+    println!("min k: {}", min_k);
+
+    // 真实证明中商多项式 h(X) 分为 d - 1 = 3 段承诺，次数为 3 的电路只需要 2 段
+    let proof_size = prover::proof_size(row, &circuit, &[vec![Fp::from(27)]]).unwrap();
+    println!("proof size (k = {}): {} bytes", row, proof_size);
+
+    let (prove_time, verify_time, verified) =
+        prover::prove_and_verify_timed(row, &circuit, &[vec![Fp::from(27)]]);
+    println!(
+        "timing (k = {}): prove {:?}, verify {:?}, verified {}",
+        row, prove_time, verify_time, verified
+    );
+}
@@ -0,0 +1,56 @@
+//! cubic 示例电路的测试
+
+use cubic::CubicCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, layout, prover, SimpleCircuit};
+
+const K: u32 = 4;
+
+fn circuit() -> CubicCircuit<Fp> {
+    CubicCircuit {
+        a: Value::known(Fp::from(3)),
+    }
+}
+
+/// 3^3 = 27，验证成功；错误的 public input，out 与 instance 的拷贝约束不满足
+#[test]
+fn cube() {
+    let res = MockProver::run(K, &circuit(), vec![vec![Fp::from(27)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &circuit(), vec![vec![Fp::from(9)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 次数：立方门为 4，SimpleCircuit 中的门（以及拷贝约束参数）为 3
+#[test]
+fn degree() {
+    assert_eq!(dev::measure_degree::<CubicCircuit<Fp>>(), 4);
+    assert_eq!(dev::measure_degree::<SimpleCircuit<Fp>>(), 3);
+}
+
+/// 次数不影响最小的 k：电路只有一行，加上 blinding 行后 2^3 行就够了
+#[test]
+fn min_k() {
+    let min_k = layout::measure(&circuit()).k;
+    assert_eq!(min_k, 3);
+    assert_eq!(
+        Some(min_k),
+        prover::min_k(&circuit(), &[vec![Fp::from(27)]])
+    );
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<CubicCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    assert!(prover::roundtrip_in_memory(K, &circuit(), &[vec![Fp::from(27)]]).is_ok());
+}
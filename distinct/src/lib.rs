@@ -0,0 +1,256 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn,
+    },
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// 数独风格的约束：证明一个 private 的 3x3 宫格中的 9 个数都在 1..=9 中，且两两不同
+/// 1）范围检查：每个数通过 lookup 检查在 1..=9 的表中
+/// 2）两两不同：对每一对 (x_i, x_j)，用 is_zero 计算 [x_i - x_j == 0]，再约束结果为 0
+///
+/// 宫格的大小
+pub const CELLS: usize = 9;
+
+/// 范围检查的布局，所有的数放在同一个 region 中：
+///
+/// | value | s_range |
+/// |-------|---------|
+/// | x_0   | 1       |
+/// | ...   | ...     |
+///
+/// lookup 的输入为 s * (value - 1) + 1：未启用的行输入为 1，总在表中；表中不含 0，所以 0 无法通过。
+///
+/// is_zero 的布局，每一对数一个 region：
+///
+/// | a   | b   | inv          | is_zero | s_is_zero |
+/// |-----|-----|--------------|---------|-----------|
+/// | x_i | x_j | (x_i-x_j)^-1 | out     | 1         |
+///
+/// 记 d = a - b，约束为 out = 1 - d * inv 和 d * out = 0：
+/// d != 0 时，第二个约束要求 out = 0，此时 inv 必须是 d 的逆；d = 0 时，out = 1 - 0 = 1。
+#[derive(Clone, Debug)]
+pub struct DistinctConfig {
+    value: Column<Advice>,
+    a: Column<Advice>,
+    b: Column<Advice>,
+    inv: Column<Advice>,
+    is_zero: Column<Advice>,
+    s_range: Selector,
+    s_is_zero: Selector,
+    table: TableColumn,
+}
+
+pub struct DistinctChip<F: PrimeField> {
+    config: DistinctConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> DistinctChip<F> {
+    pub fn construct(config: DistinctConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 5],
+        constant: Column<Fixed>,
+    ) -> DistinctConfig {
+        let [value, a, b, inv, is_zero] = advice;
+        for c in [value, a, b, is_zero] {
+            meta.enable_equality(c);
+        }
+        meta.enable_constant(constant);
+
+        let s_range = meta.complex_selector();
+        let s_is_zero = meta.selector();
+        let table = meta.lookup_table_column();
+
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_range);
+            let v = meta.query_advice(value, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            vec![(s * (v - one.clone()) + one, table)]
+        });
+
+        meta.create_gate("is_zero", |meta| {
+            let s = meta.query_selector(s_is_zero);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let inv = meta.query_advice(inv, Rotation::cur());
+            let out = meta.query_advice(is_zero, Rotation::cur());
+            let d = a - b;
+            vec![
+                s.clone() * (Expression::Constant(F::ONE) - d.clone() * inv - out.clone()),
+                s * d * out,
+            ]
+        });
+
+        DistinctConfig {
+            value,
+            a,
+            b,
+            inv,
+            is_zero,
+            s_range,
+            s_is_zero,
+            table,
+        }
+    }
+
+    /// 加载范围表：1, 2, ..., 9
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = &self.config;
+        layouter.assign_table(
+            || "1..=9 table",
+            |mut table| {
+                for (i, v) in (1..=CELLS as u64).enumerate() {
+                    table.assign_cell(|| "value", config.table, i, || Value::known(F::from(v)))?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 加载宫格中的数，并检查它们都在 1..=9 中
+    pub fn load_block(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "block",
+            |mut region| {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, v)| {
+                        config.s_range.enable(&mut region, offset)?;
+                        region.assign_advice(|| format!("x{}", offset), config.value, offset, || *v)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    /// 返回 [a - b == 0]
+    pub fn is_zero_diff(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "is_zero",
+            |mut region| {
+                config.s_is_zero.enable(&mut region, 0)?;
+                a.copy_advice(|| "a", &mut region, config.a, 0)?;
+                b.copy_advice(|| "b", &mut region, config.b, 0)?;
+                let d = a.value().copied() - b.value();
+                // d = 0 时没有逆，inv 可以取任意值，这里取 0
+                let inv = d.map(|d| d.invert().unwrap_or(F::ZERO));
+                region.assign_advice(|| "inv", config.inv, 0, || inv)?;
+                let out = d.map(|d| if d.is_zero_vartime() { F::ONE } else { F::ZERO });
+                region.assign_advice(|| "is_zero", config.is_zero, 0, || out)
+            },
+        )
+    }
+
+    /// 约束 cell 等于常数 0
+    pub fn assert_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "assert_zero",
+            |mut region| {
+                let zero =
+                    region.assign_advice_from_constant(|| "0", self.config.is_zero, 0, F::ZERO)?;
+                region.constrain_equal(cell.cell(), zero.cell())
+            },
+        )
+    }
+}
+
+/// 宫格中的 9 个数，按行排列
+#[derive(Default)]
+pub struct DistinctCircuit<F: PrimeField> {
+    pub block: [Value<F>; CELLS],
+}
+
+impl<F: PrimeField> Circuit<F> for DistinctCircuit<F> {
+    type Config = DistinctConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let constant = meta.fixed_column();
+        DistinctChip::configure(meta, advice, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = DistinctChip::construct(config);
+        chip.load_table(layouter.namespace(|| "load table"))?;
+        let xs = chip.load_block(layouter.namespace(|| "load block"), &self.block)?;
+
+        // 共 9 * 8 / 2 = 36 对
+        for i in 0..CELLS {
+            for j in (i + 1)..CELLS {
+                let mut layouter = layouter.namespace(|| format!("x{} != x{}", i, j));
+                let eq =
+                    chip.is_zero_diff(layouter.namespace(|| "x_i - x_j == 0"), &xs[i], &xs[j])?;
+                chip.assert_zero(layouter.namespace(|| "not equal"), &eq)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 没有 public input
+impl<F: PrimeField> DescribeInstance for DistinctCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// 1..=9 的随机排列（Fisher-Yates 洗牌），没有 public input
+impl RandomWitness for DistinctCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let mut values: [u64; CELLS] = std::array::from_fn(|i| i as u64 + 1);
+        for i in (1..CELLS).rev() {
+            values.swap(i, rng.next_u32() as usize % (i + 1));
+        }
+        let circuit = Self {
+            block: values.map(|v| Value::known(Fp::from(v))),
+        };
+        (circuit, vec![])
+    }
+}
@@ -0,0 +1,35 @@
+use distinct::DistinctCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+
+fn block(values: [u64; 9]) -> DistinctCircuit<Fp> {
+    DistinctCircuit {
+        block: values.map(|v| Value::known(Fp::from(v))),
+    }
+}
+
+fn main() {
+    // 9 行范围检查 + 36 对 is_zero，需要 2^7 行
+    let row = 7;
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &block([5, 3, 4, 6, 7, 2, 1, 9, 8]), vec![]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: distinct...");
+
+    // 1..=9 的一个排列，验证成功
+    let prover = MockProver::run(row, &block([5, 3, 4, 6, 7, 2, 1, 9, 8]), vec![]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // x2 和 x6 都是 4：只有这一对的差为 0，is_zero 输出 1，与常数 0 的拷贝约束不满足
+    let prover_2 = MockProver::run(row, &block([5, 3, 4, 6, 7, 2, 4, 9, 8]), vec![]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // 0 不在 1..=9 中，即使两两不同，lookup 也不满足
+    let prover_3 = MockProver::run(row, &block([0, 3, 4, 6, 7, 2, 1, 9, 8]), vec![]).unwrap();
+    let res_3 = prover_3.verify();
+    println!("res3: {:?}", res_3);
+}
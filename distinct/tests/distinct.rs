@@ -0,0 +1,59 @@
+//! distinct 示例电路的测试
+
+use distinct::DistinctCircuit;
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+
+/// 9 行范围检查 + 36 对 is_zero，需要 2^7 行
+const K: u32 = 7;
+
+fn block(values: [u64; 9]) -> DistinctCircuit<Fp> {
+    DistinctCircuit {
+        block: values.map(|v| Value::known(Fp::from(v))),
+    }
+}
+
+/// 1..=9 的一个排列，验证成功
+#[test]
+fn permutation() {
+    let res = MockProver::run(K, &block([5, 3, 4, 6, 7, 2, 1, 9, 8]), vec![])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// x2 和 x6 都是 4：只有这一对的差为 0，is_zero 输出 1，与常数 0 的拷贝约束不满足；
+/// 失败的拷贝约束位于 is_zero 的输出单元格，且只有一对（x2, x6）
+#[test]
+fn duplicate_fails() {
+    let failures = MockProver::run(K, &block([5, 3, 4, 6, 7, 2, 4, 9, 8]), vec![])
+        .unwrap()
+        .verify()
+        .unwrap_err();
+    let is_zero_failures = failures
+        .iter()
+        .filter(|failure| failure.to_string().contains("('is_zero')"))
+        .count();
+    assert_eq!(is_zero_failures, 1);
+}
+
+/// 0 不在 1..=9 中，即使两两不同，lookup 也不满足
+#[test]
+fn out_of_range_fails() {
+    let res = MockProver::run(K, &block([0, 3, 4, 6, 7, 2, 1, 9, 8]), vec![])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<DistinctCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    assert!(prover::roundtrip_in_memory(K, &block([5, 3, 4, 6, 7, 2, 1, 9, 8]), &[]).is_ok());
+}
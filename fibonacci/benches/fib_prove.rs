@@ -0,0 +1,60 @@
+//! 两个版本的 Fibonacci 电路对同一个 n 生成真实的 IPA 证明，比较证明耗时、验证耗时和证明大小
+//!
+//! version 1 每行三列 (f(i), f(i+1), f(i+2))，相邻行之间用拷贝约束连接；
+//! version 2 只有一列，门用 Rotation::cur、Rotation::next 和 Rotation(2) 查询连续三行。两者的行数相近，k 取各自能容纳的最小值，
+//! 差别主要来自列数、拷贝约束（permutation 参数）和门中旋转的个数。
+//!
+//! 运行：cargo bench -p fibonacci
+
+use fibonacci::{fibonacci_public, version_1, version_2};
+use halo2_proofs::{pasta::Fp, plonk::Circuit};
+use simple::prover::{min_k, proof_size, prove_and_verify_timed, DescribeInstance};
+use std::{marker::PhantomData, time::Duration};
+
+/// 每个 n 重复证明的次数，取平均值
+const SAMPLES: u32 = 3;
+
+/// 返回 (k, 平均证明耗时, 平均验证耗时, 证明字节数)，每个证明都必须通过验证
+fn bench<C: Circuit<Fp> + DescribeInstance>(
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> (u32, Duration, Duration, usize) {
+    let k = min_k(circuit, public).expect("circuit does not fit in any k");
+    let (mut prove_total, mut verify_total) = (Duration::ZERO, Duration::ZERO);
+    for _ in 0..SAMPLES {
+        let (prove_time, verify_time, verified) = prove_and_verify_timed(k, circuit, public);
+        assert!(verified, "proof does not verify at k = {}", k);
+        prove_total += prove_time;
+        verify_total += verify_time;
+    }
+    let size = proof_size(k, circuit, public).expect("proof generation failed");
+    (k, prove_total / SAMPLES, verify_total / SAMPLES, size)
+}
+
+fn main() {
+    println!(
+        "{:>5} {:>8} {:>3} {:>12} {:>12} {:>8}",
+        "n", "version", "k", "prove", "verify", "bytes"
+    );
+    for n in [10, 100, 500] {
+        let public = vec![fibonacci_public(Fp::one(), Fp::one(), n)];
+        let v1 = version_1::FibonacciNCircuit::<Fp> {
+            n,
+            tampered_row: None,
+            _marker: PhantomData,
+        };
+        let v2 = version_2::FibonacciNCircuit::<Fp> {
+            n,
+            tampered_row: None,
+            _marker: PhantomData,
+        };
+        for (version, (k, prove, verify, size)) in
+            [(1, bench(&v1, &public)), (2, bench(&v2, &public))]
+        {
+            println!(
+                "{:>5} {:>8} {:>3} {:>12.2?} {:>12.2?} {:>8}",
+                n, version, k, prove, verify, size
+            );
+        }
+    }
+}
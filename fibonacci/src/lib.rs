@@ -1,2 +1,14 @@
+use group::ff::Field;
+
 pub mod version_1;
 pub mod version_2;
+
+/// 电路外计算 public input [a, b, f(n)]，其中 f(0) = a，f(1) = b，f(i) = f(i-1) + f(i-2)
+/// 用它构造 public input，避免手动计算的结果与电路不一致
+pub fn fibonacci_public<F: Field>(a: F, b: F, n: usize) -> Vec<F> {
+    let (mut x, mut y) = (a, b);
+    for _ in 0..n {
+        (x, y) = (y, x + y);
+    }
+    vec![a, b, x]
+}
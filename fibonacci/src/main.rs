@@ -1,4 +1,10 @@
+use fibonacci::fibonacci_public;
 use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{
+    layout::measure,
+    prover::{prove_and_verify_timed, vk_fingerprint},
+    report, SimpleCircuit,
+};
 use std::marker::PhantomData;
 
 fn test_version_1() {
@@ -10,14 +16,14 @@ fn test_version_1() {
 
     let a = Fp::from(1);
     let b = Fp::from(1);
-    let out = Fp::from(55);
+    // 电路计算 f(9)
+    let public_input = fibonacci_public(a, b, 9);
 
     // 用隐私输入实例化电路，这里没有隐私输入，所以输入占位符
     let circuit: FibonacciCircuit<Fp> = FibonacciCircuit(PhantomData);
 
     // 输入正确的 public input ,验证成功
-    let public_input = vec![a, b, out];
-    let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
     // println!("res1: {:?}", prover);
     let res = prover.verify();
     println!("res1: {:?}", res);
@@ -28,6 +34,14 @@ fn test_version_1() {
     let prover_2 = MockProver::run(row, &circuit, vec![public_input_2]).unwrap();
     let res_2 = prover_2.verify();
     println!("res2: {:?}", res_2);
+
+    // 真实证明的耗时
+    let (prove_time, verify_time, verified) =
+        prove_and_verify_timed(row, &circuit, &[public_input]);
+    println!(
+        "timing (k = {}): prove {:?}, verify {:?}, verified {}",
+        row, prove_time, verify_time, verified
+    );
 }
 
 fn test_version_2() {
@@ -39,14 +53,14 @@ fn test_version_2() {
 
     let a = Fp::from(1);
     let b = Fp::from(1);
-    let out = Fp::from(55);
+    // 电路计算 f(9)
+    let public_input = fibonacci_public(a, b, 9);
 
     // 用隐私输入实例化电路，这里没有隐私输入，所以输入占位符
     let circuit: FibonacciCircuit<Fp> = FibonacciCircuit(PhantomData);
 
     // 输入正确的 public input ,验证成功
-    let public_input = vec![a, b, out];
-    let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
     // println!("res1: {:?}", prover);
     let res = prover.verify();
     println!("res1: {:?}", res);
@@ -58,8 +72,115 @@ fn test_version_2() {
     let res_2 = prover_2.verify();
     println!("res2: {:?}", res_2);
 }
+fn test_chunked() {
+    use fibonacci::version_2::ChunkedFibonacciCircuit;
+
+    println!("Hello, this is halo2 example: fabonacci_2 chunked...");
+    // 定义电路的行数
+    let row = 7;
+
+    let a = Fp::from(1);
+    let b = Fp::from(1);
+    let n = 100;
+
+    // 电路外计算 f(100) 作为参考
+    let public_input = fibonacci_public(a, b, n);
+    let out = public_input[2];
+
+    // 每个 region 最多计算 16 项
+    let circuit: ChunkedFibonacciCircuit<Fp> = ChunkedFibonacciCircuit {
+        n,
+        chunk_size: 16,
+        _marker: PhantomData,
+    };
+
+    // 输入正确的 public input ,验证成功
+    let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 输入错误的 public input ,验证错误
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![a, b, out + Fp::one()]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
+
+fn test_sequence() {
+    use fibonacci::version_2::FibonacciSequenceCircuit;
+
+    println!("Hello, this is halo2 example: fabonacci sequence...");
+    // 定义电路的行数
+    let row = 5;
+    let n = 10;
+
+    let circuit: FibonacciSequenceCircuit<Fp> = FibonacciSequenceCircuit {
+        n,
+        _marker: PhantomData,
+    };
+
+    // 公开整个数列 [f(0), ..., f(10)] = [1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]
+    let mut sequence = vec![Fp::from(1), Fp::from(1)];
+    for i in 2..=n {
+        sequence.push(sequence[i - 2] + sequence[i - 1]);
+    }
+    let prover = MockProver::run(row, &circuit, vec![sequence.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 修改数列中的一项，验证失败
+    let mut corrupted = sequence;
+    corrupted[5] += Fp::one();
+    let prover_2 = MockProver::run(row, &circuit, vec![corrupted]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
+
+/// 验证密钥指纹：不同电路的指纹不同
+fn test_vk_fingerprint() {
+    let k = 4;
+    let simple = vk_fingerprint::<SimpleCircuit<Fp>>(k);
+    let fibonacci = vk_fingerprint::<fibonacci::version_2::FibonacciCircuit<Fp>>(k);
+    println!("simple vk fingerprint: {:02x?}", simple);
+    println!("fibonacci vk fingerprint: {:02x?}", fibonacci);
+}
+
+fn test_measure() {
+    use fibonacci::version_2::{ChunkedFibonacciCircuit, FibonacciCircuit};
+
+    println!("Hello, this is halo2 example: fabonacci measure...");
+
+    // 静态测量得到的行数和 k
+    let circuit: FibonacciCircuit<Fp> = FibonacciCircuit(PhantomData);
+    let measurements = measure(&circuit);
+    println!("version 2: {:?}", measurements);
+
+    for n in [10, 50, 100] {
+        let circuit: ChunkedFibonacciCircuit<Fp> = ChunkedFibonacciCircuit {
+            n,
+            chunk_size: 16,
+            _marker: PhantomData,
+        };
+        let measurements = measure(&circuit);
+        println!("chunked, n = {}: {:?}", n, measurements);
+    }
+}
+
 fn main() {
+    // --json：只输出 version 1 电路的 JSON 报告
+    let circuit = fibonacci::version_1::FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::from(1), Fp::from(1), 9);
+    if report::maybe_emit_json(4, &circuit, vec![public_input]) {
+        return;
+    }
     test_version_1();
     println!("-------------------------");
     test_version_2();
+    println!("-------------------------");
+    test_chunked();
+    println!("-------------------------");
+    test_sequence();
+    println!("-------------------------");
+    test_vk_fingerprint();
+    println!("-------------------------");
+    test_measure();
 }
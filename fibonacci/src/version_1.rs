@@ -1,10 +1,14 @@
 // #![allow(unused)]
 use group::ff::Field;
 use halo2_proofs::{
-    circuit::{AssignedCell, Layouter, SimpleFloorPlanner},
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
     plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
     poly::Rotation,
 };
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
 use std::marker::PhantomData;
 
 ///////////////////////////////////////////////////////////////////////
@@ -26,7 +30,6 @@ pub struct FibonacciChip<F: Field> {
 ///////////////////////////////////////////////////////////////////////
 /// 实现自定义芯片：包括实现芯片配置，和芯片中的其他功能
 ///
-
 /// 这里先定义 tuple struct ACell，用于简化与电路中单元格的交互（原因见simple example）
 #[derive(Debug, Clone)]
 pub struct ACell<F: Field>(AssignedCell<F, F>);
@@ -75,8 +78,25 @@ impl<F: Field> FibonacciChip<F> {
     /// 1、初始化第一行为固定值（1，1，2）
     /// 2、根据 Fibonacci 数列的特性，进行循环赋值和计算
     /// 3、expose public
+    pub fn assign_row(&self, layouter: impl Layouter<F>, n: usize) -> Result<ACell<F>, Error> {
+        self.assign_row_tampered(layouter, n, None)
+    }
 
-    pub fn assign_row(&self, mut layouter: impl Layouter<F>, n: usize) -> Result<ACell<F>, Error> {
+    /// 与 assign_row 相同，但在第 tampered_row 行的 c 中填入 a + b + 1，用来构造不满足 "add" 门的 witness
+    fn assign_row_tampered(
+        &self,
+        mut layouter: impl Layouter<F>,
+        n: usize,
+        tampered_row: Option<usize>,
+    ) -> Result<ACell<F>, Error> {
+        // 第 row 行的 c 比 a + b 多出的值
+        let error = |row| {
+            if tampered_row == Some(row) {
+                F::ONE
+            } else {
+                F::ZERO
+            }
+        };
         layouter.assign_region(
             || "next row",
             |mut region| {
@@ -97,7 +117,7 @@ impl<F: Field> FibonacciChip<F> {
                     .assign_advice_from_instance(
                         || "f(1)",
                         self.config.instance,
-                        0,
+                        1,
                         self.config.advice[1],
                         0,
                     )
@@ -108,7 +128,7 @@ impl<F: Field> FibonacciChip<F> {
                         || "f(2)",
                         self.config.advice[2],
                         0,
-                        || a.0.value().copied() + b.0.value().copied(),
+                        || a.0.value().copied() + b.0.value().copied() + Value::known(error(0)),
                     )
                     .map(ACell)?;
                 if n == 0 {
@@ -129,7 +149,11 @@ impl<F: Field> FibonacciChip<F> {
                                 || "f(n)",
                                 self.config.advice[2],
                                 row,
-                                || a.0.value().copied() + b.0.value().copied(),
+                                || {
+                                    a.0.value().copied()
+                                        + b.0.value().copied()
+                                        + Value::known(error(row))
+                                },
                             )
                             .map(ACell)?;
                     }
@@ -152,9 +176,8 @@ impl<F: Field> FibonacciChip<F> {
 ///////////////////////////////////////////////////////////////////////
 /// 使用上面自定义的芯片来构建电路
 ///
-
 /// 电路中没有私有输入，所以这里定义电路结构体时，仅使用占位符
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct FibonacciCircuit<F>(pub PhantomData<F>);
 
 impl<F: Field> Circuit<F> for FibonacciCircuit<F> {
@@ -192,3 +215,68 @@ impl<F: Field> Circuit<F> for FibonacciCircuit<F> {
         Ok(())
     }
 }
+
+/// public input 为 [a, b, out]
+impl<F> DescribeInstance for FibonacciCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![3]
+    }
+}
+
+/// 电路中没有 private input，随机的是 public input 中的初始值 a、b，out = f(9)
+impl RandomWitness for FibonacciCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = Fp::random(&mut *rng);
+        let b = Fp::random(&mut *rng);
+        (Self(PhantomData), vec![crate::fibonacci_public(a, b, 9)])
+    }
+}
+
+/// 证明 f(n) 的电路，n 由构造电路时给出，n >= 2
+/// assign_row(n + 1) 的最后一行为 f(n)；public input 为 [a, b, f(n)]
+/// tampered_row 为 Some(row) 时第 row 行的 c 多加 1，用于负面测试，正常使用时为 None
+#[derive(Default)]
+pub struct FibonacciNCircuit<F> {
+    pub n: usize,
+    pub tampered_row: Option<usize>,
+    pub _marker: PhantomData<F>,
+}
+
+impl<F: Field> Circuit<F> for FibonacciNCircuit<F> {
+    type Config = FibonacciConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            n: self.n,
+            tampered_row: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        FibonacciCircuit::<F>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        assert!(self.n >= 2, "FibonacciNCircuit needs n >= 2");
+        let fibonacci_chip = FibonacciChip::construct(config);
+        let c = fibonacci_chip.assign_row_tampered(
+            layouter.namespace(|| "next row"),
+            self.n + 1,
+            self.tampered_row,
+        )?;
+        fibonacci_chip.expose_public(layouter.namespace(|| "out"), &c, 2)
+    }
+}
+
+/// public input 为 [a, b, f(n)]
+impl<F> DescribeInstance for FibonacciNCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![3]
+    }
+}
@@ -3,16 +3,19 @@ use std::marker::PhantomData;
 
 use group::ff::Field;
 use halo2_proofs::{
-    circuit::{layouter, AssignedCell, Chip, Layouter, SimpleFloorPlanner},
+    circuit::{layouter, AssignedCell, Chip, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
     plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
     poly::Rotation,
 };
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
 
 ///////////////////////////////////////////////////////////////////////
 /// 重写 fibonacci：
 /// 删除每次从上一行赋值pre_b和pre_c，改用直接访问多行：只用1列表示，当前值等于上一行+上上一行
 ///
-
 ///////////////////////////////////////////////////////////////////////
 /// 本例中不需要自定义的指令，所以这里直接创建自定义芯片和芯片的配置结构
 ///
@@ -32,7 +35,6 @@ pub struct FibonacciChip<F: Field> {
 ///////////////////////////////////////////////////////////////////////
 /// 实现自定义芯片：包括实现芯片配置，和芯片中的其他功能
 ///
-
 /// 这里先定义 tuple struct ACell，用于简化与电路中单元格的交互（原因见simple example）
 pub struct ACell<F: Field>(AssignedCell<F, F>);
 
@@ -73,11 +75,29 @@ impl<F: Field> FibonacciChip<F> {
         }
     }
 
-    pub fn assign_row(&self, mut layouter: impl Layouter<F>, n: usize) -> Result<ACell<F>, Error> {
+    /// 在一个 region 中赋值 f(0), f(1), ..., f(n-1)，返回 f(n-1)；selector 在第 0 到 n-3 行启用
+    pub fn assign_row(&self, layouter: impl Layouter<F>, n: usize) -> Result<ACell<F>, Error> {
+        self.assign_row_tampered(layouter, n, None)
+    }
+
+    /// 与 assign_row 相同，但在第 tampered_row 行填入 f(row-2) + f(row-1) + 1，用来构造不满足 "add" 门的 witness
+    fn assign_row_tampered(
+        &self,
+        mut layouter: impl Layouter<F>,
+        n: usize,
+        tampered_row: Option<usize>,
+    ) -> Result<ACell<F>, Error> {
+        // 第 row 行比前两行之和多出的值
+        let error = |row| {
+            if tampered_row == Some(row) {
+                F::ONE
+            } else {
+                F::ZERO
+            }
+        };
         layouter.assign_region(
             || "first row",
             |mut region| {
-                self.config.selector.enable(&mut region, 0)?;
                 let mut a = region
                     .assign_advice_from_instance(
                         || "f(0)",
@@ -98,26 +118,164 @@ impl<F: Field> FibonacciChip<F> {
                     .map(ACell)?;
 
                 for row in 2..n {
-                    if row < n - 2 {
-                        self.config.selector.enable(&mut region, row)?;
-                    }
+                    // 第 row - 2 行的门检查 f(row-2) + f(row-1) = f(row)
+                    self.config.selector.enable(&mut region, row - 2)?;
 
                     let mut c = region
                         .assign_advice(
                             || "f(n)",
                             self.config.advice,
                             row,
-                            || a.0.value().copied() + b.0.value(),
+                            || a.0.value().copied() + b.0.value() + Value::known(error(row)),
                         )
                         .map(ACell)?;
                     a = b;
                     b = c;
                 }
-                Ok((b))
+                Ok(b)
+            },
+        )
+    }
+
+    /// 分块赋值：把数列分成若干个 region，每个 region 最多计算 chunk_size 个新的项。
+    /// 每个 region 的前两行拷贝上一个 region 的最后两项（第一个 region 从 instance 中加载 f(0)、f(1)），
+    /// 通过拷贝约束把相邻的 region 连接起来。
+    ///
+    /// 分块不会减少内存：无论怎样划分 region，证明者都要分配整张 2^k 行的表；
+    /// 与 assign_row 相比，每多一个 region 还要多占两行、多两个拷贝约束。
+    /// 它只是演示如何用拷贝约束把多个 region 拼接成一个计算：
+    ///
+    /// | region | row | a0       | selector |
+    /// |--------|-----|----------|----------|
+    /// | 0      | 0   | f(0)     | s        |
+    /// | 0      | 1   | f(1)     | s        |
+    /// | 0      | 2   | f(2)     |          |
+    /// | 1      | 0   | f(1) 拷贝 | s        |
+    /// | 1      | 1   | f(2) 拷贝 | s        |
+    /// | 1      | 2   | f(3)     |          |
+    ///
+    /// 返回 f(n)
+    pub fn assign_rows_chunked(
+        &self,
+        mut layouter: impl Layouter<F>,
+        n: usize,
+        chunk_size: usize,
+    ) -> Result<ACell<F>, Error> {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        // 上一个 region 的最后两项
+        let mut last: Option<(ACell<F>, ACell<F>)> = None;
+        // 已经计算到的最大下标
+        let mut computed = 1;
+        loop {
+            let m = chunk_size.min(n.saturating_sub(computed));
+            let (a, b) = layouter.assign_region(
+                || "chunk",
+                |mut region| {
+                    let (mut a, mut b) = match &last {
+                        None => (
+                            region
+                                .assign_advice_from_instance(
+                                    || "f(0)",
+                                    self.config.instance,
+                                    0,
+                                    self.config.advice,
+                                    0,
+                                )
+                                .map(ACell)?,
+                            region
+                                .assign_advice_from_instance(
+                                    || "f(1)",
+                                    self.config.instance,
+                                    1,
+                                    self.config.advice,
+                                    1,
+                                )
+                                .map(ACell)?,
+                        ),
+                        Some((a, b)) => (
+                            a.0.copy_advice(|| "a", &mut region, self.config.advice, 0)
+                                .map(ACell)?,
+                            b.0.copy_advice(|| "b", &mut region, self.config.advice, 1)
+                                .map(ACell)?,
+                        ),
+                    };
+                    for row in 0..m {
+                        self.config.selector.enable(&mut region, row)?;
+                        let c = region
+                            .assign_advice(
+                                || "f(n)",
+                                self.config.advice,
+                                row + 2,
+                                || a.0.value().copied() + b.0.value(),
+                            )
+                            .map(ACell)?;
+                        a = b;
+                        b = c;
+                    }
+                    Ok((a, b))
+                },
+            )?;
+            computed += m;
+            last = Some((a, b));
+            if computed >= n {
+                break;
+            }
+        }
+        let (a, b) = last.unwrap();
+        Ok(if n == 0 { a } else { b })
+    }
+
+    /// 在一个 region 中赋值整个数列 f(0), f(1), ..., f(n)，返回所有的项
+    /// f(0)、f(1) 从 instance 的第0、1行加载，第 row 行为 f(row)，selector 在第 0 到 n-2 行启用
+    pub fn assign_sequence(
+        &self,
+        mut layouter: impl Layouter<F>,
+        n: usize,
+    ) -> Result<Vec<ACell<F>>, Error> {
+        assert!(n >= 1, "sequence needs at least f(0) and f(1)");
+        layouter.assign_region(
+            || "sequence",
+            |mut region| {
+                let mut terms = Vec::with_capacity(n + 1);
+                for row in 0..2 {
+                    terms.push(
+                        region
+                            .assign_advice_from_instance(
+                                || format!("f({})", row),
+                                self.config.instance,
+                                row,
+                                self.config.advice,
+                                row,
+                            )
+                            .map(ACell)?,
+                    );
+                }
+                for row in 2..=n {
+                    self.config.selector.enable(&mut region, row - 2)?;
+                    let c = terms[row - 2].0.value().copied() + terms[row - 1].0.value();
+                    terms.push(
+                        region
+                            .assign_advice(|| format!("f({})", row), self.config.advice, row, || c)
+                            .map(ACell)?,
+                    );
+                }
+                Ok(terms)
             },
         )
     }
 
+    /// 把数列的每一项拷贝约束到 instance 列：第 i 项对应第 i 行，验证者可以检查整个数列
+    pub fn expose_sequence(
+        &self,
+        mut layouter: impl Layouter<F>,
+        terms: &[ACell<F>],
+    ) -> Result<(), Error> {
+        for (row, term) in terms.iter().enumerate() {
+            layouter.constrain_instance(term.0.cell(), self.config.instance, row)?;
+        }
+        Ok(())
+    }
+
     pub fn expose_public(
         &self,
         mut layouter: impl Layouter<F>,
@@ -131,7 +289,7 @@ impl<F: Field> FibonacciChip<F> {
 ///////////////////////////////////////////////////////////////////////
 /// 使用上面自定义的芯片来构建电路
 ///
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct FibonacciCircuit<F>(pub PhantomData<F>);
 
 impl<F: Field> Circuit<F> for FibonacciCircuit<F> {
@@ -162,3 +320,163 @@ impl<F: Field> Circuit<F> for FibonacciCircuit<F> {
         Ok(())
     }
 }
+
+/// 证明 f(n) 的电路，n 由构造电路时给出，n >= 2
+/// 整个数列放在一个 region 中：assign_row(n + 1) 的最后一行为 f(n)；public input 为 [a, b, f(n)]
+/// tampered_row 为 Some(row) 时第 row 行多加 1，用于负面测试，正常使用时为 None
+#[derive(Default)]
+pub struct FibonacciNCircuit<F> {
+    pub n: usize,
+    pub tampered_row: Option<usize>,
+    pub _marker: PhantomData<F>,
+}
+
+impl<F: Field> Circuit<F> for FibonacciNCircuit<F> {
+    type Config = FibonacciConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            n: self.n,
+            tampered_row: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        FibonacciCircuit::<F>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        assert!(self.n >= 2, "FibonacciNCircuit needs n >= 2");
+        let fibonacci_chip = FibonacciChip::construct(config);
+        let out = fibonacci_chip.assign_row_tampered(
+            layouter.namespace(|| "entire table"),
+            self.n + 1,
+            self.tampered_row,
+        )?;
+        fibonacci_chip.expose_public(layouter.namespace(|| "out"), out, 2)
+    }
+}
+
+/// 分块赋值的电路：证明 f(n)，每个 region 最多计算 chunk_size 个新的项
+/// public input 为 [f(0), f(1), f(n)]
+#[derive(Clone, Default)]
+pub struct ChunkedFibonacciCircuit<F> {
+    pub n: usize,
+    pub chunk_size: usize,
+    pub _marker: PhantomData<F>,
+}
+
+impl<F: Field> Circuit<F> for ChunkedFibonacciCircuit<F> {
+    type Config = FibonacciConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            n: self.n,
+            chunk_size: self.chunk_size,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = meta.advice_column();
+        let instance = meta.instance_column();
+        FibonacciChip::configure(meta, advice, instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let fibonacci_chip = FibonacciChip::construct(config);
+
+        let out = fibonacci_chip.assign_rows_chunked(
+            layouter.namespace(|| "chunked table"),
+            self.n,
+            self.chunk_size,
+        )?;
+
+        fibonacci_chip.expose_public(layouter.namespace(|| "out"), out, 2)
+    }
+}
+
+/// 公开整个数列的电路：public input 为 [f(0), f(1), ..., f(n)]
+#[derive(Default)]
+pub struct FibonacciSequenceCircuit<F> {
+    pub n: usize,
+    pub _marker: PhantomData<F>,
+}
+
+impl<F: Field> Circuit<F> for FibonacciSequenceCircuit<F> {
+    type Config = FibonacciConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            n: self.n,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = meta.advice_column();
+        let instance = meta.instance_column();
+        FibonacciChip::configure(meta, advice, instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let fibonacci_chip = FibonacciChip::construct(config);
+
+        let terms = fibonacci_chip.assign_sequence(layouter.namespace(|| "sequence"), self.n)?;
+
+        fibonacci_chip.expose_sequence(layouter.namespace(|| "expose sequence"), &terms)
+    }
+}
+
+/// public input 为 [a, b, out]
+impl<F> DescribeInstance for FibonacciCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![3]
+    }
+}
+
+/// 电路中没有 private input，随机的是 public input 中的初始值 a、b，out = f(9)
+impl RandomWitness for FibonacciCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = Fp::random(&mut *rng);
+        let b = Fp::random(&mut *rng);
+        (Self(PhantomData), vec![crate::fibonacci_public(a, b, 9)])
+    }
+}
+
+/// public input 为 [f(0), f(1), f(n)]
+impl<F> DescribeInstance for ChunkedFibonacciCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![3]
+    }
+}
+
+/// public input 为 [f(0), f(1), ..., f(n)]
+impl<F> DescribeInstance for FibonacciSequenceCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.n + 1]
+    }
+}
+
+/// public input 为 [a, b, f(n)]
+impl<F> DescribeInstance for FibonacciNCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![3]
+    }
+}
@@ -0,0 +1,59 @@
+//! 两个版本的芯片对同一个 n 得到相同的 f(n)，防止其中一个实现改动后与另一个不一致
+
+use fibonacci::{fibonacci_public, version_1, version_2};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use std::marker::PhantomData;
+
+/// 两个电路都只公开 [a, b, f(n)]，这里不从电路中读出 f(n)，而是用同一个参考值 fibonacci_public 检查：
+/// 两个电路都接受参考值，且都拒绝 f(n) + 1，说明两者公开的 f(n) 都等于参考值。
+/// 初始值取 a = 1、b = 2（两者不同），可以发现把 f(0) 当作 f(1) 之类的错误。
+/// n >= 2；k 取能容纳 n + 1 行和盲化行的最小 2 的幂
+fn assert_chips_agree(n: usize) {
+    let (a, b) = (Fp::one(), Fp::from(2));
+    let public = fibonacci_public(a, b, n);
+    let mut wrong = public.clone();
+    wrong[2] += Fp::one();
+    let k = (n + 1 + 16).next_power_of_two().trailing_zeros();
+
+    let v1 = version_1::FibonacciNCircuit::<Fp> {
+        n,
+        tampered_row: None,
+        _marker: PhantomData,
+    };
+    let v2 = version_2::FibonacciNCircuit::<Fp> {
+        n,
+        tampered_row: None,
+        _marker: PhantomData,
+    };
+    let accepts = |public: &Vec<Fp>| {
+        (
+            MockProver::run(k, &v1, vec![public.clone()])
+                .unwrap()
+                .verify()
+                .is_ok(),
+            MockProver::run(k, &v2, vec![public.clone()])
+                .unwrap()
+                .verify()
+                .is_ok(),
+        )
+    };
+    assert_eq!(
+        accepts(&public),
+        (true, true),
+        "n = {}: both versions should accept the reference f(n)",
+        n
+    );
+    assert_eq!(
+        accepts(&wrong),
+        (false, false),
+        "n = {}: both versions should reject f(n) + 1",
+        n
+    );
+}
+
+#[test]
+fn chips_agree_for_small_n() {
+    for n in 3..=20 {
+        assert_chips_agree(n);
+    }
+}
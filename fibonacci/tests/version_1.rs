@@ -0,0 +1,67 @@
+//! version_1 的测试：三列 advice 的 Fibonacci 电路
+
+use fibonacci::{
+    fibonacci_public,
+    version_1::{FibonacciCircuit, FibonacciNCircuit},
+};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{dev, planner::assert_planner_agnostic, prover};
+use std::marker::PhantomData;
+
+const K: u32 = 4;
+
+/// 电路计算 f(9)：a = b = 1 时为 55，公开 56 时验证失败
+#[test]
+fn public_output() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert_eq!(public_input[2], Fp::from(55));
+    let res = MockProver::run(K, &circuit, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let wrong = vec![Fp::one(), Fp::one(), Fp::from(56)];
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 第 0 行的 c 比 a + b 多 1：失败的是这一行的 "add" 门，而不只是 f(n) 与 public input 的拷贝约束
+/// （version_1 只在第 0 行启用 "add" 门的 selector，后面的行只由拷贝约束连接）
+#[test]
+fn tampered_witness_fails_add() {
+    let circuit = FibonacciNCircuit::<Fp> {
+        n: 9,
+        tampered_row: Some(0),
+        _marker: PhantomData,
+    };
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    let prover = MockProver::run(K, &circuit, vec![public_input]).unwrap();
+    dev::assert_constraint_failure(&prover, "add");
+}
+
+/// 两种布局器的验证结果一致
+#[test]
+fn planner_agnostic() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert!(assert_planner_agnostic(K, &circuit, &[public_input]));
+    assert!(!assert_planner_agnostic(
+        K,
+        &circuit,
+        &[vec![Fp::one(), Fp::one(), Fp::from(56)]]
+    ));
+}
+
+/// 随机的初始值同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<FibonacciCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
@@ -0,0 +1,218 @@
+//! version_2 的测试：一列 advice 的 Fibonacci 电路
+
+use fibonacci::{
+    fibonacci_public,
+    version_2::{
+        ChunkedFibonacciCircuit, FibonacciCircuit, FibonacciNCircuit, FibonacciSequenceCircuit,
+    },
+};
+use group::ff::PrimeField;
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{
+    dev,
+    layout::measure,
+    planner::assert_planner_agnostic,
+    prover,
+    prover::{vk_fingerprint, DescribeInstance},
+    SimpleCircuit,
+};
+use std::marker::PhantomData;
+
+const K: u32 = 4;
+
+/// 电路计算 f(9)：a = b = 1 时为 55，公开 56 时验证失败
+#[test]
+fn public_output() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert_eq!(public_input[2], Fp::from(55));
+    let res = MockProver::run(K, &circuit, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let wrong = vec![Fp::one(), Fp::one(), Fp::from(56)];
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// f(3) 比 f(1) + f(2) 多 1：失败的是第 1 行的 "add" 门，而不只是 f(n) 与 public input 的拷贝约束
+#[test]
+fn tampered_witness_fails_add() {
+    let circuit = FibonacciNCircuit::<Fp> {
+        n: 9,
+        tampered_row: Some(3),
+        _marker: PhantomData,
+    };
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    let prover = MockProver::run(K, &circuit, vec![public_input]).unwrap();
+    dev::assert_constraint_failure(&prover, "add");
+}
+
+/// 两种布局器的验证结果一致
+#[test]
+fn planner_agnostic() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert!(assert_planner_agnostic(K, &circuit, &[public_input]));
+    assert!(!assert_planner_agnostic(
+        K,
+        &circuit,
+        &[vec![Fp::one(), Fp::one(), Fp::from(56)]]
+    ));
+}
+
+/// 验证密钥指纹：同一个电路多次生成的指纹相同，不同电路的指纹不同
+#[test]
+fn vk_fingerprints() {
+    let simple_1 = vk_fingerprint::<SimpleCircuit<Fp>>(K);
+    let simple_2 = vk_fingerprint::<SimpleCircuit<Fp>>(K);
+    let fibonacci = vk_fingerprint::<FibonacciCircuit<Fp>>(K);
+    assert_eq!(simple_1, simple_2);
+    assert_ne!(simple_1, fibonacci);
+}
+
+/// 静态测量得到的 k 与反复运行 MockProver 得到的最小 k 一致
+#[test]
+fn measured_k_is_minimal() {
+    let (a, b) = (Fp::one(), Fp::one());
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    assert_eq!(
+        Some(measure(&circuit).k),
+        prover::min_k(&circuit, &[fibonacci_public(a, b, 9)])
+    );
+
+    for n in [10, 50, 100] {
+        let circuit = chunked(n, 16);
+        assert_eq!(
+            Some(measure(&circuit).k),
+            prover::min_k(&circuit, &[fibonacci_public(a, b, n)]),
+            "n = {}",
+            n
+        );
+    }
+}
+
+fn chunked(n: usize, chunk_size: usize) -> ChunkedFibonacciCircuit<Fp> {
+    ChunkedFibonacciCircuit {
+        n,
+        chunk_size,
+        _marker: PhantomData,
+    }
+}
+
+/// 每个 region 最多计算 16 项，证明 f(100)：与电路外用 u128 计算的参考值一致，公开错误的值时验证失败
+#[test]
+fn chunked_f100() {
+    let k = 7;
+    let (mut a, mut b) = (1u128, 1u128);
+    for _ in 0..100 {
+        (a, b) = (b, a + b);
+    }
+    let reference = Fp::from_u128(a);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 100);
+    assert_eq!(public_input[2], reference);
+
+    let circuit = chunked(100, 16);
+    let res = MockProver::run(k, &circuit, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let wrong = vec![Fp::one(), Fp::one(), reference + Fp::one()];
+    let res = MockProver::run(k, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 对不同的 n 和初始值，fibonacci_public 与电路导出的 f(n) 一致
+#[test]
+fn chunked_small_n() {
+    for (a, b, n) in [(1, 1, 1), (1, 1, 2), (2, 3, 7), (0, 1, 20), (5, 8, 33)] {
+        let public_input = fibonacci_public(Fp::from(a), Fp::from(b), n);
+        let res = MockProver::run(7, &chunked(n, 16), vec![public_input])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "n = {}", n);
+    }
+}
+
+/// 分块不减少行数：与整个数列放在一个 region 中相比，多出的 region 只增加行数和拷贝约束
+#[test]
+fn chunking_adds_rows() {
+    let single = measure(&FibonacciNCircuit::<Fp> {
+        n: 100,
+        tampered_row: None,
+        _marker: PhantomData,
+    });
+    let chunked = measure(&chunked(100, 16));
+    assert!(chunked.rows > single.rows);
+    assert!(chunked.copy_constraints > single.copy_constraints);
+    assert!(chunked.k >= single.k);
+}
+
+/// 多个 region 之间只用拷贝约束连接，两种布局器的验证结果一致
+#[test]
+fn chunked_planner_agnostic() {
+    let circuit = chunked(100, 16);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 100);
+    let mut wrong = public_input.clone();
+    wrong[2] += Fp::one();
+    assert!(assert_planner_agnostic(7, &circuit, &[public_input]));
+    assert!(!assert_planner_agnostic(7, &circuit, &[wrong]));
+}
+
+/// f(0) = f(1) = 1 时的数列 [f(0), ..., f(n)]
+fn sequence(n: usize) -> Vec<Fp> {
+    let mut sequence = vec![Fp::one(), Fp::one()];
+    for i in 2..=n {
+        sequence.push(sequence[i - 2] + sequence[i - 1]);
+    }
+    sequence
+}
+
+/// 公开整个数列 [f(0), ..., f(10)] = [1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]；
+/// 形状由 n 决定：一个 instance 列，n + 1 行
+#[test]
+fn full_sequence() {
+    let circuit = FibonacciSequenceCircuit::<Fp> {
+        n: 10,
+        _marker: PhantomData,
+    };
+    let sequence = sequence(10);
+    assert_eq!(sequence[10], Fp::from(89));
+    assert_eq!(fibonacci_public(Fp::one(), Fp::one(), 10)[2], sequence[10]);
+    assert_eq!(circuit.instance_shape(), vec![sequence.len()]);
+    let res = MockProver::run(5, &circuit, vec![sequence])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 修改数列中的任意一项，验证失败
+#[test]
+fn corrupted_sequence_entry_fails() {
+    let circuit = FibonacciSequenceCircuit::<Fp> {
+        n: 10,
+        _marker: PhantomData,
+    };
+    for i in [0, 5, 10] {
+        let mut corrupted = sequence(10);
+        corrupted[i] += Fp::one();
+        let res = MockProver::run(5, &circuit, vec![corrupted])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "entry {}", i);
+    }
+}
+
+/// 随机的初始值同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<FibonacciCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明：a = b = 1，f(9) = 55
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = FibonacciCircuit::<Fp>(PhantomData);
+    let public_input = fibonacci_public(Fp::one(), Fp::one(), 9);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
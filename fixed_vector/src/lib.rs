@@ -0,0 +1,140 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Fixed, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// fixed 列作为逐行的常数向量：证明 y_i = w_i * x_i，x_i 为 private input，y_i 为 public input，
+/// 系数 w_i 放在 fixed 列的第 i 行，每一行可以不同
+///
+/// | x   | w (fixed) | y   | s |
+/// |-----|-----------|-----|---|
+/// | x_0 | w_0       | y_0 | 1 |
+/// | x_1 | w_1       | y_1 | 1 |
+///
+/// 门为 s * (x * w - y) = 0，用 meta.query_fixed 查询当前行的系数（halo2 0.3 的门中 fixed 列只能查询当前行）。
+/// SimpleChip 的 constant 列只通过 assign_advice_from_constant 提供全局常量，每个常量单独占一个单元格并用拷贝约束引用；
+/// 这里的系数直接参与门的计算，不需要拷贝约束。
+/// fixed 列的值在生成密钥时确定，属于验证密钥，所以系数是电路结构的一部分，证明者无法修改。
+#[derive(Clone, Debug)]
+pub struct FixedVectorConfig {
+    x: Column<Advice>,
+    w: Column<Fixed>,
+    y: Column<Advice>,
+    instance: Column<Instance>,
+    s: Selector,
+}
+
+/// 系数向量属于电路结构，x 为 private input
+#[derive(Clone, Debug, Default)]
+pub struct FixedVectorCircuit<F: PrimeField> {
+    pub coeffs: Vec<F>,
+    pub xs: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> FixedVectorCircuit<F> {
+    pub fn new(coeffs: &[u64], xs: &[u64]) -> Self {
+        assert_eq!(coeffs.len(), xs.len(), "need one coefficient per value");
+        Self {
+            coeffs: coeffs.iter().map(|w| F::from(*w)).collect(),
+            xs: xs.iter().map(|x| Value::known(F::from(*x))).collect(),
+        }
+    }
+}
+
+/// 电路外计算 public input：每一行为 w_i * x_i
+pub fn scaled<F: PrimeField>(coeffs: &[F], xs: &[F]) -> Vec<F> {
+    coeffs.iter().zip(xs).map(|(w, x)| *w * x).collect()
+}
+
+impl<F: PrimeField> Circuit<F> for FixedVectorCircuit<F> {
+    type Config = FixedVectorConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 系数在生成密钥时就需要，所以保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            coeffs: self.coeffs.clone(),
+            xs: vec![Value::unknown(); self.xs.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let w = meta.fixed_column();
+        let y = meta.advice_column();
+        let instance = meta.instance_column();
+        let s = meta.selector();
+        meta.enable_equality(y);
+        meta.enable_equality(instance);
+
+        meta.create_gate("mul_fixed", |meta| {
+            let s = meta.query_selector(s);
+            let x = meta.query_advice(x, Rotation::cur());
+            // 当前行的系数
+            let w = meta.query_fixed(w);
+            let y = meta.query_advice(y, Rotation::cur());
+            vec![s * (x * w - y)]
+        });
+
+        FixedVectorConfig {
+            x,
+            w,
+            y,
+            instance,
+            s,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let ys = layouter.assign_region(
+            || "x * w = y",
+            |mut region| {
+                let mut ys = Vec::with_capacity(self.xs.len());
+                for (offset, (x, w)) in self.xs.iter().zip(&self.coeffs).enumerate() {
+                    config.s.enable(&mut region, offset)?;
+                    region.assign_advice(|| "x", config.x, offset, || *x)?;
+                    region.assign_fixed(|| "w", config.w, offset, || Value::known(*w))?;
+                    let y = region.assign_advice(|| "y", config.y, offset, || x.map(|x| x * w))?;
+                    ys.push(y);
+                }
+                Ok(ys)
+            },
+        )?;
+        for (row, y) in ys.iter().enumerate() {
+            layouter.constrain_instance(y.cell(), config.instance, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// 每一行公开一个乘积
+impl<F: PrimeField> DescribeInstance for FixedVectorCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.xs.len()]
+    }
+}
+
+/// 系数为 [2, 3, 5, 7]（与示例 main 相同），x 为随机的域元素
+impl RandomWitness for FixedVectorCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let coeffs = [2, 3, 5, 7].map(Fp::from).to_vec();
+        let xs: Vec<Fp> = (0..coeffs.len()).map(|_| Fp::random(&mut *rng)).collect();
+        let public = scaled(&coeffs, &xs);
+        let circuit = Self {
+            coeffs,
+            xs: xs.into_iter().map(Value::known).collect(),
+        };
+        (circuit, vec![public])
+    }
+}
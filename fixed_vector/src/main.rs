@@ -0,0 +1,43 @@
+use fixed_vector::{scaled, FixedVectorCircuit};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{dev, prover, report};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    // w = [2, 3, 5, 7]，x = [10, 20, 30, 40]，y = [20, 60, 150, 280]
+    let coeffs = [2, 3, 5, 7];
+    let xs = [10, 20, 30, 40];
+    let circuit = FixedVectorCircuit::<Fp>::new(&coeffs, &xs);
+    let public_input = scaled(&coeffs.map(Fp::from), &xs.map(Fp::from));
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: fixed column as a per-row vector...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1 (20, 60, 150, 280): {:?}", res);
+
+    // 门中查询的是 fixed 列的当前行
+    let gates = dev::dump_gates::<FixedVectorCircuit<Fp>>().unwrap();
+    println!("gates: {:?}", gates);
+
+    // 第 2 行的输出错误：y_2 = 150 被公开为 151
+    let mut wrong = public_input.clone();
+    wrong[2] += Fp::one();
+    let prover_2 = MockProver::run(row, &circuit, vec![wrong]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2 (y_2 = 151): {:?}", res_2);
+
+    // 系数写在 fixed 列中，属于验证密钥：换一组系数，验证密钥也不同
+    let other = FixedVectorCircuit::<Fp>::new(&[7, 5, 3, 2], &xs);
+    println!(
+        "vk fingerprints: {:02x?} vs {:02x?}",
+        prover::vk_fingerprint_of(row, &circuit),
+        prover::vk_fingerprint_of(row, &other)
+    );
+}
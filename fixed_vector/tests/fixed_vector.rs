@@ -0,0 +1,76 @@
+//! fixed_vector 示例电路的测试
+
+use fixed_vector::{scaled, FixedVectorCircuit};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{dev, layout, prover};
+
+const K: u32 = 4;
+
+const COEFFS: [u64; 4] = [2, 3, 5, 7];
+const XS: [u64; 4] = [10, 20, 30, 40];
+
+/// w = [2, 3, 5, 7]，x = [10, 20, 30, 40]，y = [20, 60, 150, 280]
+fn example() -> (FixedVectorCircuit<Fp>, Vec<Fp>) {
+    let circuit = FixedVectorCircuit::<Fp>::new(&COEFFS, &XS);
+    let public_input = scaled(&COEFFS.map(Fp::from), &XS.map(Fp::from));
+    (circuit, public_input)
+}
+
+/// 正确的输出验证成功；第 2 行的输出错误（y_2 = 150 被公开为 151）时验证失败
+#[test]
+fn scaled_output() {
+    let (circuit, public_input) = example();
+    assert_eq!(public_input, [20, 60, 150, 280].map(Fp::from));
+    assert!(layout::measure(&circuit).k <= K);
+    let res = MockProver::run(K, &circuit, vec![public_input.clone()])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+
+    let mut wrong = public_input;
+    wrong[2] += Fp::one();
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 用同一个系数 5 算出的输出不对：每一行的系数不同
+#[test]
+fn uniform_coefficient_fails() {
+    let (circuit, _) = example();
+    let uniform = scaled(&[5; 4].map(Fp::from), &XS.map(Fp::from));
+    let res = MockProver::run(K, &circuit, vec![uniform])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 门中查询的是 fixed 列的当前行
+#[test]
+fn gate_queries_current_fixed_row() {
+    let gates = dev::dump_gates::<FixedVectorCircuit<Fp>>().unwrap();
+    assert!(gates[0].contains("F0@0"));
+}
+
+/// 系数写在 fixed 列中，属于验证密钥：换一组系数，验证密钥也不同
+#[test]
+fn coefficients_change_vk() {
+    let (circuit, _) = example();
+    let other = FixedVectorCircuit::<Fp>::new(&[7, 5, 3, 2], &XS);
+    assert_ne!(
+        prover::vk_fingerprint_of(K, &circuit),
+        prover::vk_fingerprint_of(K, &other)
+    );
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<FixedVectorCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (circuit, public_input) = example();
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
@@ -0,0 +1,25 @@
+#![no_main]
+
+use halo2_proofs::pasta::Fp;
+use libfuzzer_sys::fuzz_target;
+use simple::{prover::Prover, SimpleCircuit};
+use std::sync::OnceLock;
+
+/// 与种子语料（corpus/verify_proof/valid_proof）生成时相同的电路和参数：
+/// SimpleCircuit，constant = 2，k = 5，public input 为 2 * 2^2 * 3^2 = 72
+const K: u32 = 5;
+
+fn prover() -> &'static (Prover<SimpleCircuit<Fp>>, Vec<Vec<Fp>>) {
+    static PROVER: OnceLock<(Prover<SimpleCircuit<Fp>>, Vec<Vec<Fp>>)> = OnceLock::new();
+    PROVER.get_or_init(|| {
+        let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+        let prover = Prover::from_circuit(K, &circuit).expect("keygen should not fail");
+        (prover, vec![public])
+    })
+}
+
+// 任意字节作为证明交给验证者，只能返回 Err，不能 panic
+fuzz_target!(|data: &[u8]| {
+    let (prover, public) = prover();
+    let _ = prover.verify(data, public);
+});
@@ -0,0 +1,116 @@
+use group::ff::{Field, PrimeField};
+use halo2_poseidon::{P128Pow5T3, Spec};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, Column, ConstraintSystem, Error, Instance},
+};
+use poseidon::{PoseidonChip, PoseidonConfig, RATE, WIDTH};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 哈希链示例：对 private 的种子连续做 depth 次 Poseidon 哈希，公开最终的摘要
+/// h_0 = seed，h_{i+1} = hash(h_i, 0)，公开 h_depth
+/// 证明者通过电路证明自己知道哈希链第 depth 层的原像，演示电路的顺序组合：每一次哈希的输出是下一次哈希的输入。
+/// Poseidon 芯片每次吸收两个元素，第二个元素用常数 0 填充。
+///
+/// 电路外计算哈希链，与电路中的计算一致
+pub fn hash_chain<F>(seed: F, depth: usize) -> F
+where
+    F: PrimeField,
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    (0..depth).fold(seed, |h, _| poseidon::hash_to_field([h, F::ZERO]))
+}
+
+#[derive(Clone, Debug)]
+pub struct HashChainConfig<F: PrimeField> {
+    poseidon: PoseidonConfig<F>,
+    instance: Column<Instance>,
+}
+
+/// 电路中保存 private 的种子和链的深度，深度决定了电路的结构
+#[derive(Default)]
+pub struct HashChainCircuit<F: PrimeField> {
+    pub seed: Value<F>,
+    pub depth: usize,
+}
+
+impl<F: PrimeField> Circuit<F> for HashChainCircuit<F>
+where
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    type Config = HashChainConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            seed: Value::unknown(),
+            depth: self.depth,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // 3个 advice 列存放 Poseidon 的状态，3个 fixed 列存放轮常数
+        let state = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let rc = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        let constant = meta.fixed_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        HashChainConfig {
+            poseidon: PoseidonChip::configure::<P128Pow5T3>(meta, state, rc, constant),
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = PoseidonChip::construct(config.poseidon);
+
+        let mut digest = chip.load_private(layouter.namespace(|| "load seed"), self.seed)?;
+        let zero = chip.load_constant(layouter.namespace(|| "load padding"), F::ZERO)?;
+        for i in 0..self.depth {
+            digest = chip.hash(
+                layouter.namespace(|| format!("h{} = hash(h{}, 0)", i + 1, i)),
+                [digest, zero.clone()],
+            )?;
+        }
+
+        // 把最终的摘要约束为电路的 public input
+        layouter.constrain_instance(digest.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为最终的摘要
+impl<F: PrimeField> DescribeInstance for HashChainCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 深度为 4（与示例 main 相同），种子为随机的域元素
+impl RandomWitness for HashChainCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let seed = Fp::random(rng);
+        let depth = 4;
+        let circuit = Self {
+            seed: Value::known(seed),
+            depth,
+        };
+        (circuit, vec![vec![hash_chain(seed, depth)]])
+    }
+}
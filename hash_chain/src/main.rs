@@ -0,0 +1,41 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use hash_chain::{hash_chain, HashChainCircuit};
+use simple::report;
+
+fn main() {
+    // 每次哈希约 65 行，depth = 4 时需要 2^9 行
+    let row = 9;
+
+    let seed = Fp::from(42);
+    let depth = 4;
+    let digest = hash_chain(seed, depth);
+
+    // 用正确的种子计算 4 次哈希，与电路外的哈希链一致，验证成功
+    let circuit = HashChainCircuit {
+        seed: Value::known(seed),
+        depth,
+    };
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![digest]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: hash chain...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![digest]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 公开的是第 3 层的摘要，与第 4 层不一致，验证失败
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![hash_chain(seed, depth - 1)]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // 使用错误的种子，验证失败
+    let circuit_3 = HashChainCircuit {
+        seed: Value::known(seed + Fp::one()),
+        depth,
+    };
+    let prover_3 = MockProver::run(row, &circuit_3, vec![vec![digest]]).unwrap();
+    let res_3 = prover_3.verify();
+    println!("res3: {:?}", res_3);
+}
@@ -0,0 +1,54 @@
+//! hash_chain 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use hash_chain::{hash_chain, HashChainCircuit};
+use simple::{dev, prover};
+
+/// 每次哈希约 65 行，depth = 4 时需要 2^9 行
+const K: u32 = 9;
+
+/// 用正确的种子计算 4 次哈希，与电路外的哈希链一致，验证成功；公开第 3 层的摘要或使用错误的种子时验证失败
+#[test]
+fn chain_of_four() {
+    let seed = Fp::from(42);
+    let digest = hash_chain(seed, 4);
+    let circuit = HashChainCircuit {
+        seed: Value::known(seed),
+        depth: 4,
+    };
+    let res = MockProver::run(K, &circuit, vec![vec![digest]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+
+    let res = MockProver::run(K, &circuit, vec![vec![hash_chain(seed, 3)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+
+    let wrong_seed = HashChainCircuit {
+        seed: Value::known(seed + Fp::one()),
+        depth: 4,
+    };
+    let res = MockProver::run(K, &wrong_seed, vec![vec![digest]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<HashChainCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let seed = Fp::from(42);
+    let circuit = HashChainCircuit {
+        seed: Value::known(seed),
+        depth: 4,
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![hash_chain(seed, 4)]]).is_ok());
+}
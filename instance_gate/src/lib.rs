@@ -0,0 +1,125 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 在门中直接查询 instance 列：证明 a_i * b_i = c_i，a_i、b_i 为 private input，c_i 为 instance 列第 i 行的 public input
+///
+/// | a   | b   | s | instance |
+/// |-----|-----|---|----------|
+/// | a_0 | b_0 | 1 | c_0      |
+/// | a_1 | b_1 | 1 | c_1      |
+///
+/// 门为 s * (a * b - instance) = 0，instance 与 advice 一样用 meta.query_instance 按行偏移查询，不需要拷贝约束，
+/// instance 列也不需要 enable_equality。其它示例都是先把结果赋值到 advice 单元格，再用 constrain_instance
+/// 添加 advice 与 instance 之间的拷贝约束。
+///
+/// 代价是门查询的是绝对行：region 被放在第几行，就与 instance 列的第几行比较，
+/// 所以 public input 的位置依赖布局（这里只有一个 region，从第 0 行开始）。
+/// constrain_instance 则可以把任意位置的单元格连接到 instance 列的任意一行，与布局无关。
+#[derive(Clone, Debug)]
+pub struct InstanceGateConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    s: Selector,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct InstanceGateCircuit<F: PrimeField> {
+    pub rows: Vec<(Value<F>, Value<F>)>,
+}
+
+impl<F: PrimeField> InstanceGateCircuit<F> {
+    pub fn new(pairs: &[(u64, u64)]) -> Self {
+        Self {
+            rows: pairs
+                .iter()
+                .map(|(a, b)| (Value::known(F::from(*a)), Value::known(F::from(*b))))
+                .collect(),
+        }
+    }
+}
+
+/// 电路外计算 public input：每一行为 a_i * b_i
+pub fn products<F: PrimeField>(pairs: &[(u64, u64)]) -> Vec<F> {
+    pairs.iter().map(|(a, b)| F::from(a * b)).collect()
+}
+
+impl<F: PrimeField> Circuit<F> for InstanceGateCircuit<F> {
+    type Config = InstanceGateConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            rows: vec![(Value::unknown(), Value::unknown()); self.rows.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let instance = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("mul_instance", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            // 直接查询 instance 列的当前行
+            let c = meta.query_instance(instance, Rotation::cur());
+            vec![s * (a * b - c)]
+        });
+
+        // instance 列只在门中使用，synthesize 中不需要它
+        InstanceGateConfig { a, b, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "a * b = instance",
+            |mut region| {
+                for (offset, (a, b)) in self.rows.iter().enumerate() {
+                    config.s.enable(&mut region, offset)?;
+                    region.assign_advice(|| "a", config.a, offset, || *a)?;
+                    region.assign_advice(|| "b", config.b, offset, || *b)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// 每一行公开一个乘积
+impl<F: PrimeField> DescribeInstance for InstanceGateCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.rows.len()]
+    }
+}
+
+/// 3 对随机的域元素（与示例 main 的行数相同）
+impl RandomWitness for InstanceGateCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let pairs: Vec<(Fp, Fp)> = (0..3)
+            .map(|_| (Fp::random(&mut *rng), Fp::random(&mut *rng)))
+            .collect();
+        let circuit = Self {
+            rows: pairs
+                .iter()
+                .map(|(a, b)| (Value::known(*a), Value::known(*b)))
+                .collect(),
+        };
+        let public = pairs.iter().map(|(a, b)| *a * b).collect();
+        (circuit, vec![public])
+    }
+}
@@ -0,0 +1,35 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use instance_gate::{products, InstanceGateCircuit};
+use simple::{dev, prover, report};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    let pairs = [(2, 3), (4, 5), (6, 7)];
+    let circuit = InstanceGateCircuit::<Fp>::new(&pairs);
+    let public_input = products::<Fp>(&pairs);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: instance column in a gate...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1 (6, 20, 42): {:?}", res);
+
+    // 门中出现了 instance 列的查询
+    let gates = dev::dump_gates::<InstanceGateCircuit<Fp>>().unwrap();
+    println!("gates: {:?}", gates);
+
+    // 第 1 行的 public input 错误：失败的是门约束（而不是拷贝约束），位置在第 1 行
+    let mut wrong = public_input.clone();
+    wrong[1] = Fp::from(21);
+    let prover_2 = MockProver::run(row, &circuit, vec![wrong]).unwrap();
+    println!("res2 (public c_1 = 21): {:?}", prover_2.verify());
+
+    let (_, _, verified) = prover::prove_and_verify_timed(row, &circuit, &[public_input]);
+    println!("res3 (real proof): {}", verified);
+}
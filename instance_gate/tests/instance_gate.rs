@@ -0,0 +1,68 @@
+//! instance_gate 示例电路的测试
+
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use instance_gate::{products, InstanceGateCircuit};
+use simple::{dev, layout, prover};
+
+const K: u32 = 4;
+
+const PAIRS: [(u64, u64); 3] = [(2, 3), (4, 5), (6, 7)];
+
+/// c_i = a_i * b_i 直接与 instance 列比较，没有拷贝约束
+#[test]
+fn products_match_instance() {
+    let circuit = InstanceGateCircuit::<Fp>::new(&PAIRS);
+    let measurements = layout::measure(&circuit);
+    assert!(measurements.k <= K);
+    assert_eq!(measurements.copy_constraints, 0);
+    let res = MockProver::run(K, &circuit, vec![products::<Fp>(&PAIRS)])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 门中出现了 instance 列的查询
+#[test]
+fn gate_queries_instance() {
+    let gates = dev::dump_gates::<InstanceGateCircuit<Fp>>().unwrap();
+    assert!(gates[0].contains("I0@0"));
+}
+
+/// 第 1 行的 public input 错误：失败的是门约束（而不是拷贝约束），位置在第 1 行
+#[test]
+fn wrong_row_fails_gate() {
+    let circuit = InstanceGateCircuit::<Fp>::new(&PAIRS);
+    let mut wrong = products::<Fp>(&PAIRS);
+    wrong[1] = Fp::from(21);
+    let prover = MockProver::run(K, &circuit, vec![wrong]).unwrap();
+    let failures = prover.verify().unwrap_err();
+    assert_eq!(failures.len(), 1);
+    dev::assert_constraint_failure(&prover, "mul_instance");
+    assert!(failures[0].to_string().contains("at offset 1"));
+}
+
+/// public input 的顺序与行对应，交换后两行都失败
+#[test]
+fn swapped_rows_fail() {
+    let circuit = InstanceGateCircuit::<Fp>::new(&PAIRS);
+    let public_input = products::<Fp>(&PAIRS);
+    let swapped = vec![public_input[1], public_input[0], public_input[2]];
+    let failures = MockProver::run(K, &circuit, vec![swapped])
+        .unwrap()
+        .verify()
+        .unwrap_err();
+    assert_eq!(failures.len(), 2);
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<InstanceGateCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = InstanceGateCircuit::<Fp>::new(&PAIRS);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[products::<Fp>(&PAIRS)]).is_ok());
+}
@@ -0,0 +1,163 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::{self, DescribeInstance};
+use simple::transcript::TranscriptKind;
+use simple::witness::RandomWitness;
+use simple::{NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 两个电路之间的一致性示例：计算 out = (a * b) * c，分成两个独立的证明
+/// - 第一个电路（ProducerCircuit）证明 m = a * b，把中间值 m 作为 public output 公开（instance 第 0 行）；
+/// - 第二个电路（ConsumerCircuit）从 instance 第 0 行加载 m 作为输入，证明 out = m * c，out 公开在第 1 行。
+///
+/// 两个证明各自独立，电路之间没有拷贝约束；一致性来自验证者：验证第二个证明时，
+/// 必须把第一个证明中公开的 m 原样作为第二个证明的 public input。
+/// 第二个证明者如果用了不同的中间值 m'，它的 out' = m' * c 与验证者传入的 m 对不上，第二个证明验证失败。
+///
+/// 两个电路都使用 SimpleChip，配置与 SimpleCircuit 相同
+fn configure_simple<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> SimpleConfig {
+    let advice = [meta.advice_column(), meta.advice_column()];
+    let instance = meta.instance_column();
+    let constant = meta.fixed_column();
+    SimpleChip::configure(meta, advice, instance, constant)
+}
+
+/// 第一个电路：m = a * b，公开 m
+#[derive(Clone, Debug, Default)]
+pub struct ProducerCircuit<F: PrimeField> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for ProducerCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        configure_simple(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+        let m = chip.mul(layouter.namespace(|| "m = a * b"), a, b)?;
+        chip.expose_public(layouter.namespace(|| "expose m"), m, 0)
+    }
+}
+
+/// public input 为中间值 m
+impl<F: PrimeField> DescribeInstance for ProducerCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 第二个电路：从 public input 加载 m，out = m * c，公开 out
+#[derive(Clone, Debug, Default)]
+pub struct ConsumerCircuit<F: PrimeField> {
+    pub c: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for ConsumerCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        configure_simple(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+        let m = chip.load_instance(layouter.namespace(|| "load m"), 0)?;
+        let c = chip.load_private(layouter.namespace(|| "load c"), self.c)?;
+        let out = chip.mul(layouter.namespace(|| "out = m * c"), m, c)?;
+        chip.expose_public(layouter.namespace(|| "expose out"), out, 1)
+    }
+}
+
+/// public input 依次为 m、out
+impl<F: PrimeField> DescribeInstance for ConsumerCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![2]
+    }
+}
+
+/// 两个电路使用的 k
+pub const K: u32 = 4;
+
+/// 为两个电路分别生成并验证真实的证明，返回两个证明是否验证成功
+/// 第一个证明者用 a、b 计算 m；第二个证明者认为中间值是 second_intermediate，用它计算 out = second_intermediate * c。
+/// 验证第二个证明时，public input 中的 m 取自第一个证明，所以只有 second_intermediate = a * b 时两个证明都能通过
+pub fn link_circuits_demo(a: u64, b: u64, c: u64, second_intermediate: u64) -> (bool, bool) {
+    let transcript = TranscriptKind::default();
+
+    let first = ProducerCircuit {
+        a: Value::known(Fp::from(a)),
+        b: Value::known(Fp::from(b)),
+    };
+    let m = Fp::from(a) * Fp::from(b);
+    let first_public = [vec![m]];
+    let first_ok = prover::prove_simple(K, &first, &first_public, transcript)
+        .and_then(|proof| prover::verify_simple(K, &first, &proof, &first_public, transcript))
+        .is_ok();
+
+    let second = ConsumerCircuit {
+        c: Value::known(Fp::from(c)),
+    };
+    let out = Fp::from(second_intermediate) * Fp::from(c);
+    // 证明者用自己认为的中间值生成证明，验证者用第一个证明公开的 m 验证
+    let second_ok = prover::prove_simple(
+        K,
+        &second,
+        &[vec![Fp::from(second_intermediate), out]],
+        transcript,
+    )
+    .and_then(|proof| prover::verify_simple(K, &second, &proof, &[vec![m, out]], transcript))
+    .is_ok();
+
+    (first_ok, second_ok)
+}
+
+/// a、b 为随机的域元素
+impl RandomWitness for ProducerCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = Fp::random(&mut *rng);
+        let b = Fp::random(&mut *rng);
+        let circuit = Self {
+            a: Value::known(a),
+            b: Value::known(b),
+        };
+        (circuit, vec![vec![a * b]])
+    }
+}
+
+/// 中间值 m 和 c 为随机的域元素
+impl RandomWitness for ConsumerCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let m = Fp::random(&mut *rng);
+        let c = Fp::random(&mut *rng);
+        (Self { c: Value::known(c) }, vec![vec![m, m * c]])
+    }
+}
@@ -0,0 +1,24 @@
+use halo2_proofs::{circuit::Value, pasta::Fp};
+use linked_circuits::{link_circuits_demo, ProducerCircuit, K};
+use simple::report;
+
+fn main() {
+    let first = ProducerCircuit {
+        a: Value::known(Fp::from(3)),
+        b: Value::known(Fp::from(4)),
+    };
+
+    // --json：只输出第一个电路的 JSON 报告
+    if report::maybe_emit_json(K, &first, vec![vec![Fp::from(12)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: linked circuits...");
+
+    // 3 * 4 = 12，12 * 5 = 60：两个证明者使用同一个中间值，两个证明都通过
+    let consistent = link_circuits_demo(3, 4, 5, 12);
+    println!("res1 (consistent m = 12): {:?}", consistent);
+
+    // 第二个证明者使用 m' = 13，out' = 65：第一个证明仍然通过，第二个证明用 m = 12 验证时失败
+    let inconsistent = link_circuits_demo(3, 4, 5, 13);
+    println!("res2 (second uses m' = 13): {:?}", inconsistent);
+}
@@ -0,0 +1,60 @@
+//! linked_circuits 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use linked_circuits::{link_circuits_demo, ConsumerCircuit, ProducerCircuit, K};
+use simple::{dev, layout, prover};
+
+/// 3 * 4 = 12，12 * 5 = 60 的两个电路
+fn circuits() -> (ProducerCircuit<Fp>, ConsumerCircuit<Fp>) {
+    let first = ProducerCircuit {
+        a: Value::known(Fp::from(3)),
+        b: Value::known(Fp::from(4)),
+    };
+    let second = ConsumerCircuit {
+        c: Value::known(Fp::from(5)),
+    };
+    (first, second)
+}
+
+/// 第一个电路公开 12，第二个电路以 12 为输入；m = 12 时 out = 65 不满足第二个电路
+#[test]
+fn mock_prover() {
+    let (first, second) = circuits();
+    assert!(layout::measure(&first).k <= K);
+    assert!(layout::measure(&second).k <= K);
+    let res = MockProver::run(K, &first, vec![vec![Fp::from(12)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &second, vec![vec![Fp::from(12), Fp::from(60)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &second, vec![vec![Fp::from(12), Fp::from(65)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 两个证明者使用同一个中间值时两个证明都通过；第二个证明者使用 m' = 13 时，
+/// 第一个证明仍然通过，第二个证明用 m = 12 验证时失败
+#[test]
+fn linked_proofs() {
+    assert_eq!(link_circuits_demo(3, 4, 5, 12), (true, true));
+    assert_eq!(link_circuits_demo(3, 4, 5, 13), (true, false));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ProducerCircuit<Fp>>(K, 4), Ok(()));
+    assert_eq!(dev::check_random::<ConsumerCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中为两个电路分别生成并验证真实的证明：3 * 4 = 12，12 * 5 = 60
+#[test]
+fn roundtrip_in_memory() {
+    let (first, second) = circuits();
+    assert!(prover::roundtrip_in_memory(K, &first, &[vec![Fp::from(12)]]).is_ok());
+    assert!(prover::roundtrip_in_memory(K, &second, &[vec![Fp::from(12), Fp::from(60)]]).is_ok());
+}
@@ -0,0 +1,113 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 线性回归预测示例：证明 y = w · x + b
+/// 模型的权重 w 和偏置 b 在构造电路时传入，作为常量写入 fixed 列，属于验证密钥的一部分（对验证者公开）；
+/// 特征 x 为 private input，预测值 y 由 linear_combination 指令计算并作为 public input 公开。
+///
+/// 线性模型
+#[derive(Clone, Debug)]
+pub struct LinearModel<F: PrimeField> {
+    pub weights: Vec<F>,
+    pub bias: F,
+}
+
+impl<F: PrimeField> LinearModel<F> {
+    /// 电路外的参考实现：w · x + b
+    pub fn predict(&self, x: &[F]) -> F {
+        self.weights
+            .iter()
+            .zip(x)
+            .fold(self.bias, |acc, (w, x)| acc + *w * x)
+    }
+}
+
+pub struct LinRegCircuit<F: PrimeField> {
+    pub model: LinearModel<F>,
+    pub x: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> LinRegCircuit<F> {
+    pub fn new(model: LinearModel<F>, x: Vec<Value<F>>) -> Self {
+        assert_eq!(
+            model.weights.len(),
+            x.len(),
+            "the number of features must match the number of weights"
+        );
+        Self { model, x }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for LinRegCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 模型是电路结构的一部分，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            model: self.model.clone(),
+            x: vec![Value::unknown(); self.x.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let x = self
+            .x
+            .iter()
+            .map(|x| chip.load_private(layouter.namespace(|| "load feature"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let y = chip.linear_combination(
+            layouter.namespace(|| "y = w · x + b"),
+            &x,
+            &self.model.weights,
+            self.model.bias,
+        )?;
+        chip.expose_public(layouter.namespace(|| "expose y"), y, 0)
+    }
+}
+
+/// public input 为预测值 y
+impl<F: PrimeField> DescribeInstance for LinRegCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 模型与示例 main 相同（w = [2, 3, 5]，b = 7），特征为随机的域元素
+impl RandomWitness for LinRegCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let model = LinearModel {
+            weights: [2, 3, 5].map(Fp::from).to_vec(),
+            bias: Fp::from(7),
+        };
+        let x: Vec<Fp> = (0..model.weights.len())
+            .map(|_| Fp::random(&mut *rng))
+            .collect();
+        let y = model.predict(&x);
+        let circuit = Self::new(model, x.into_iter().map(Value::known).collect());
+        (circuit, vec![vec![y]])
+    }
+}
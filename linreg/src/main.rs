@@ -0,0 +1,33 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use linreg::{LinRegCircuit, LinearModel};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 6;
+
+    // 3 个特征的模型：w = [2, 3, 5]，b = 7
+    let model = LinearModel {
+        weights: vec![Fp::from(2), Fp::from(3), Fp::from(5)],
+        bias: Fp::from(7),
+    };
+    let x = vec![Fp::from(1), Fp::from(4), Fp::from(6)];
+    let circuit = LinRegCircuit::new(model.clone(), x.iter().map(|x| Value::known(*x)).collect());
+
+    // 2 * 1 + 3 * 4 + 5 * 6 + 7 = 51
+    let y = model.predict(&x);
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![y]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: linear regression...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![y]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 公开错误的预测值，验证失败
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![y + Fp::one()]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
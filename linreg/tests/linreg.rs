@@ -0,0 +1,47 @@
+//! linreg 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use linreg::{LinRegCircuit, LinearModel};
+use simple::{dev, prover};
+
+const K: u32 = 6;
+
+/// 3 个特征的模型：w = [2, 3, 5]，b = 7，输入 x = [1, 4, 6]
+fn example() -> (LinRegCircuit<Fp>, Fp) {
+    let model = LinearModel {
+        weights: vec![Fp::from(2), Fp::from(3), Fp::from(5)],
+        bias: Fp::from(7),
+    };
+    let x = vec![Fp::from(1), Fp::from(4), Fp::from(6)];
+    let y = model.predict(&x);
+    let circuit = LinRegCircuit::new(model, x.iter().map(|x| Value::known(*x)).collect());
+    (circuit, y)
+}
+
+/// 2 * 1 + 3 * 4 + 5 * 6 + 7 = 51；公开错误的预测值时验证失败
+#[test]
+fn prediction() {
+    let (circuit, y) = example();
+    assert_eq!(y, Fp::from(51));
+    let res = MockProver::run(K, &circuit, vec![vec![y]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &circuit, vec![vec![y + Fp::one()]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<LinRegCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (circuit, y) = example();
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![y]]).is_ok());
+}
@@ -0,0 +1,161 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::{FailureLocation, VerifyFailure},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector, TableColumn},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// lookup 教学示例：检查一组 private 的值都是 4 bit 的数（0 <= v < 16）
+/// 所有值放在同一个 region 中，第 i 个值在 region 的第 i 行，
+/// 这样 MockProver 报告 VerifyFailure::Lookup 时，可以从失败位置的 offset 找回是哪个值不在表中。
+///
+/// 范围检查表的位数
+pub const TABLE_BITS: usize = 4;
+
+/// lookup 的名字，按 configure 中调用 meta.lookup 的顺序排列（halo2 0.3 中 lookup 本身没有名字）
+pub const LOOKUP_TABLES: &[&str] = &["u4 range table"];
+
+#[derive(Clone, Debug)]
+pub struct LookupDemoConfig {
+    value: Column<Advice>,
+    s_lookup: Selector,
+    table: TableColumn,
+}
+
+impl LookupDemoConfig {
+    pub fn configure<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> Self {
+        let value = meta.advice_column();
+        // lookup 中使用的选择器必须是 complex selector
+        let s_lookup = meta.complex_selector();
+        let table = meta.lookup_table_column();
+
+        // 未启用的行输入为 0，0 在表中，总能通过
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_lookup);
+            let v = meta.query_advice(value, Rotation::cur());
+            vec![(s * v, table)]
+        });
+
+        Self {
+            value,
+            s_lookup,
+            table,
+        }
+    }
+
+    pub fn load_table<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || LOOKUP_TABLES[0],
+            |mut table| {
+                for i in 0..(1 << TABLE_BITS) {
+                    table.assign_cell(
+                        || "value",
+                        self.table,
+                        i,
+                        || Value::known(F::from(i as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// 所有待检查的值，在电路外以 u64 给出，便于在错误信息中打印
+#[derive(Default)]
+pub struct LookupDemoCircuit {
+    pub values: Vec<u64>,
+}
+
+impl<F: PrimeField> Circuit<F> for LookupDemoCircuit {
+    type Config = LookupDemoConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 这里为了简单直接在电路中保存 u64，值的个数决定了电路结构
+    fn without_witnesses(&self) -> Self {
+        Self {
+            values: vec![0; self.values.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        LookupDemoConfig::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.load_table(layouter.namespace(|| "load table"))?;
+        layouter.assign_region(
+            || "check values",
+            |mut region| {
+                for (offset, v) in self.values.iter().enumerate() {
+                    config.s_lookup.enable(&mut region, offset)?;
+                    region.assign_advice(
+                        || format!("value {}", offset),
+                        config.value,
+                        offset,
+                        || Value::known(F::from(*v)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// 把 MockProver 的验证结果中的 lookup 失败格式化为可读的信息：哪个表、哪一行、哪个值不在表中
+/// values 为电路中按行排列的值，用失败位置的 offset 取出对应的值；其它类型的失败会被忽略
+pub fn format_lookup_failures(failures: &[VerifyFailure], values: &[u64]) -> Vec<String> {
+    failures
+        .iter()
+        .filter_map(|failure| match failure {
+            VerifyFailure::Lookup {
+                lookup_index,
+                location,
+            } => {
+                let table = LOOKUP_TABLES
+                    .get(*lookup_index)
+                    .copied()
+                    .unwrap_or("unknown table");
+                let message = match location {
+                    FailureLocation::InRegion { offset, .. } => match values.get(*offset) {
+                        Some(v) => format!(
+                            "value {} at row {} not found in lookup table '{}' ({})",
+                            v, offset, table, location
+                        ),
+                        None => format!("lookup into table '{}' failed {}", table, location),
+                    },
+                    FailureLocation::OutsideRegion { .. } => {
+                        format!("lookup into table '{}' failed {}", table, location)
+                    }
+                };
+                Some(message)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// 没有 public input
+impl DescribeInstance for LookupDemoCircuit {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// 4 个随机的 TABLE_BITS 位整数（与示例 main 的个数相同），没有 public input
+impl RandomWitness for LookupDemoCircuit {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let values = (0..4).map(|_| rng.next_u64() % (1 << TABLE_BITS)).collect();
+        (Self { values }, vec![])
+    }
+}
@@ -0,0 +1,33 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use lookup_demo::{format_lookup_failures, LookupDemoCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // 所有值都在 0..16 中，lookup 满足
+    let circuit = LookupDemoCircuit {
+        values: vec![1, 5, 15, 3],
+    };
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: lookup diagnostics...");
+
+    let prover = MockProver::<Fp>::run(row, &circuit, vec![]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 20 不在表中：错误信息指出表名、行号和值
+    let circuit_2 = LookupDemoCircuit {
+        values: vec![1, 5, 20, 3],
+    };
+    let prover_2 = MockProver::<Fp>::run(row, &circuit_2, vec![]).unwrap();
+    let failures = prover_2.verify().unwrap_err();
+    let messages = format_lookup_failures(&failures, &circuit_2.values);
+    for message in &messages {
+        println!("res2: {}", message);
+    }
+}
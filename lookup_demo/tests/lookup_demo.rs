@@ -0,0 +1,48 @@
+//! lookup_demo 示例电路的测试
+
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use lookup_demo::{format_lookup_failures, LookupDemoCircuit};
+use simple::{dev, prover};
+
+const K: u32 = 5;
+
+/// 所有值都在 0..16 中，lookup 满足
+#[test]
+fn values_in_table() {
+    let circuit = LookupDemoCircuit {
+        values: vec![1, 5, 15, 3],
+    };
+    let res = MockProver::<Fp>::run(K, &circuit, vec![]).unwrap().verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 20 不在表中：错误信息指出表名、行号和值
+#[test]
+fn lookup_failure_message() {
+    let circuit = LookupDemoCircuit {
+        values: vec![1, 5, 20, 3],
+    };
+    let failures = MockProver::<Fp>::run(K, &circuit, vec![])
+        .unwrap()
+        .verify()
+        .unwrap_err();
+    let messages = format_lookup_failures(&failures, &circuit.values);
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("u4 range table"));
+    assert!(messages[0].contains("value 20 at row 2"));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<LookupDemoCircuit>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = LookupDemoCircuit {
+        values: vec![1, 5, 15, 3],
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[]).is_ok());
+}
@@ -0,0 +1,103 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 矩阵-向量乘法示例：证明 y = M * x
+/// M 为电路外固定的小矩阵（构造电路时传入，作为常量写入 fixed 列），x 为 private 的向量，
+/// y 的每个分量 y_i = M_i · x 由 dot 指令计算，依次放在 instance 列中。
+///
+/// 电路外计算 M * x，用于得到正确的 public input
+pub fn mat_vec_mul<F: PrimeField>(matrix: &[Vec<F>], x: &[F]) -> Vec<F> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(x).fold(F::ZERO, |acc, (m, x)| acc + *m * x))
+        .collect()
+}
+
+/// 电路中保存固定的矩阵和 private 的向量
+pub struct MatVecCircuit<F: PrimeField> {
+    pub matrix: Vec<Vec<F>>,
+    pub x: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> MatVecCircuit<F> {
+    pub fn new(matrix: Vec<Vec<F>>, x: Vec<Value<F>>) -> Self {
+        assert!(
+            matrix.iter().all(|row| row.len() == x.len()),
+            "matrix width must match the vector length"
+        );
+        Self { matrix, x }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for MatVecCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 矩阵是电路结构的一部分，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            matrix: self.matrix.clone(),
+            x: vec![Value::unknown(); self.x.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let x = self
+            .x
+            .iter()
+            .map(|x| chip.load_private(layouter.namespace(|| "load x"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (i, row) in self.matrix.iter().enumerate() {
+            let y = chip.dot(
+                layouter.namespace(|| format!("y{} = M{} · x", i, i)),
+                &x,
+                row,
+            )?;
+            chip.expose_public(layouter.namespace(|| format!("expose y{}", i)), y, i)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// y 的每个分量占一行
+impl<F: PrimeField> DescribeInstance for MatVecCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.matrix.len()]
+    }
+}
+
+/// 矩阵与示例 main 相同（[[1, 2], [3, 4]]），x 为随机的域元素
+impl RandomWitness for MatVecCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let matrix = vec![[1, 2].map(Fp::from).to_vec(), [3, 4].map(Fp::from).to_vec()];
+        let x: Vec<Fp> = (0..2).map(|_| Fp::random(&mut *rng)).collect();
+        let y = mat_vec_mul(&matrix, &x);
+        let circuit = Self::new(matrix, x.into_iter().map(Value::known).collect());
+        (circuit, vec![y])
+    }
+}
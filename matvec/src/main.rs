@@ -0,0 +1,33 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use matvec::{mat_vec_mul, MatVecCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 6;
+
+    // M = [[1, 2], [3, 4]]，x = [5, 6]，y = M * x = [17, 39]
+    let matrix: Vec<Vec<Fp>> = vec![
+        vec![Fp::from(1), Fp::from(2)],
+        vec![Fp::from(3), Fp::from(4)],
+    ];
+    let x = vec![Fp::from(5), Fp::from(6)];
+    let circuit = MatVecCircuit::new(matrix.clone(), x.iter().map(|x| Value::known(*x)).collect());
+
+    let public_input = mat_vec_mul(&matrix, &x);
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: matrix-vector multiplication...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 输入错误的 public input，验证失败
+    let public_input_2 = vec![Fp::from(17), Fp::from(40)];
+    let prover_2 = MockProver::run(row, &circuit, vec![public_input_2]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
@@ -0,0 +1,47 @@
+//! matvec 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use matvec::{mat_vec_mul, MatVecCircuit};
+use simple::{dev, prover, prover::DescribeInstance};
+
+const K: u32 = 6;
+
+/// M = [[1, 2], [3, 4]]，x = [5, 6]，y = M * x = [17, 39]
+fn example() -> (MatVecCircuit<Fp>, Vec<Fp>) {
+    let matrix = vec![
+        vec![Fp::from(1), Fp::from(2)],
+        vec![Fp::from(3), Fp::from(4)],
+    ];
+    let x = vec![Fp::from(5), Fp::from(6)];
+    let circuit = MatVecCircuit::new(matrix.clone(), x.iter().map(|x| Value::known(*x)).collect());
+    (circuit, mat_vec_mul(&matrix, &x))
+}
+
+/// 正确的乘积验证成功，输入错误的 public input 时验证失败；矩阵有几行，instance 列就用几行
+#[test]
+fn product() {
+    let (circuit, public_input) = example();
+    assert_eq!(public_input, vec![Fp::from(17), Fp::from(39)]);
+    assert_eq!(circuit.instance_shape(), vec![public_input.len()]);
+    let res = MockProver::run(K, &circuit, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::from(17), Fp::from(40)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<MatVecCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (circuit, public_input) = example();
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
@@ -0,0 +1,103 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 滑动窗口平均值（moving average）示例
+/// 输入为 private 的序列 x_0, x_1, ..., x_{n-1}，窗口大小为 w，
+/// 第 i 个输出 y_i = (x_i + x_{i+1} + ... + x_{i+w-1}) / w，共 n - w + 1 个输出，依次放在 instance 列中。
+/// 域上没有除法，这里用乘以 w^{-1} 来代替除以 w（mul_by_constant 指令）。
+///
+/// 电路外计算滑动平均值，用于得到正确的 public input
+pub fn moving_average<F: PrimeField>(inputs: &[F], window: usize) -> Vec<F> {
+    let w_inv = F::from(window as u64).invert().unwrap();
+    inputs
+        .windows(window)
+        .map(|w| w.iter().fold(F::ZERO, |acc, x| acc + x) * w_inv)
+        .collect()
+}
+
+/// 电路中保存 private 的输入序列和窗口大小
+pub struct MovingAvgCircuit<F: PrimeField> {
+    pub inputs: Vec<Value<F>>,
+    pub window: usize,
+}
+
+impl<F: PrimeField> Circuit<F> for MovingAvgCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 序列长度和窗口大小决定了电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            inputs: vec![Value::unknown(); self.inputs.len()],
+            window: self.window,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        assert!(self.window > 0 && self.window <= self.inputs.len());
+        let chip = SimpleChip::<F>::construct(config);
+
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|x| chip.load_private(layouter.namespace(|| "load input"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // 乘以 w^{-1} 代替除以 w
+        let w_inv = F::from(self.window as u64).invert().unwrap();
+
+        for (row, window) in inputs.windows(self.window).enumerate() {
+            // 窗口内的元素依次相加
+            let mut sum = window[0].clone();
+            for x in &window[1..] {
+                sum = chip.add(layouter.namespace(|| "window sum"), sum, x.clone())?;
+            }
+            let avg = chip.mul_by_constant(layouter.namespace(|| "sum / w"), sum, w_inv)?;
+            chip.expose_public(layouter.namespace(|| "expose average"), avg, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 每个窗口一个输出，共 n - w + 1 个；窗口比输入长时没有输出
+impl<F: PrimeField> DescribeInstance for MovingAvgCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![(self.inputs.len() + 1).saturating_sub(self.window)]
+    }
+}
+
+/// 4 个随机的输入，窗口大小为 2（与示例 main 相同）
+impl RandomWitness for MovingAvgCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let window = 2;
+        let inputs: Vec<Fp> = (0..4).map(|_| Fp::random(&mut *rng)).collect();
+        let averages = moving_average(&inputs, window);
+        let circuit = Self {
+            inputs: inputs.into_iter().map(Value::known).collect(),
+            window,
+        };
+        (circuit, vec![averages])
+    }
+}
@@ -0,0 +1,34 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use moving_avg::{moving_average, MovingAvgCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // 输入序列 [2, 4, 6, 8]，窗口大小为 2
+    let inputs: Vec<Fp> = [2u64, 4, 6, 8].iter().map(|x| Fp::from(*x)).collect();
+    let window = 2;
+    let circuit = MovingAvgCircuit {
+        inputs: inputs.iter().map(|x| Value::known(*x)).collect(),
+        window,
+    };
+
+    // 滑动平均值为 [3, 5, 7]
+    let public_input = moving_average(&inputs, window);
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: moving average...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 输入错误的 public input，验证失败
+    let public_input_2 = vec![Fp::from(3), Fp::from(5), Fp::from(8)];
+    let prover_2 = MockProver::run(row, &circuit, vec![public_input_2]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
@@ -0,0 +1,61 @@
+//! moving_avg 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use moving_avg::{moving_average, MovingAvgCircuit};
+use simple::{dev, prover, prover::ProofError};
+
+const K: u32 = 5;
+
+/// 输入序列 [2, 4, 6, 8]，窗口大小为 2
+fn example() -> (MovingAvgCircuit<Fp>, Vec<Fp>) {
+    let inputs = [2, 4, 6, 8].map(Fp::from);
+    let circuit = MovingAvgCircuit {
+        inputs: inputs.iter().map(|x| Value::known(*x)).collect(),
+        window: 2,
+    };
+    (circuit, moving_average(&inputs, 2))
+}
+
+/// 滑动平均值为 [3, 5, 7]；输入错误的 public input 时验证失败
+#[test]
+fn averages() {
+    let (circuit, public_input) = example();
+    assert_eq!(public_input, vec![Fp::from(3), Fp::from(5), Fp::from(7)]);
+    let res = MockProver::run(K, &circuit, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let wrong = vec![Fp::from(3), Fp::from(5), Fp::from(8)];
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 窗口比输入长时没有输出：prover::run 在检查 public input 时返回 BadPublicInput，不会 panic
+#[test]
+fn window_longer_than_inputs() {
+    let circuit = MovingAvgCircuit {
+        inputs: vec![Value::known(Fp::one()); 2],
+        window: 3,
+    };
+    assert!(matches!(
+        prover::run(K, &circuit, &[vec![Fp::one()]]),
+        Err(ProofError::BadPublicInput {
+            column: Some(0),
+            expected: 0,
+            got: 1
+        })
+    ));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<MovingAvgCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (circuit, public_input) = example();
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[public_input]).is_ok());
+}
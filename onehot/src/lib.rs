@@ -0,0 +1,192 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// one-hot 编码示例：证明 witness 的向量 e 是下标 i 的 one-hot 编码，i 为 private input
+///
+/// 三个条件：每个 e_j 都是布尔值；Σ e_j = 1（恰好一个 1）；Σ j * e_j = i（1 在第 i 个位置）。
+/// 前两个条件保证只有一个 e_j = 1，此时 Σ j * e_j 就是它的位置 j，所以第三个条件把位置固定为 i。
+/// 求和用 running sum 在相邻行之间累积，位置 j 也用一列计数器逐行加 1 得到：
+///
+/// | e       | sum     | pos     | dot     | index | s_first | s_step | s_last |
+/// |---------|---------|---------|---------|-------|---------|--------|--------|
+/// | e_0     | e_0     | 0       | 0       |       | 1       | 0      | 0      |
+/// | e_1     | sum_1   | 1       | dot_1   |       | 0       | 1      | 0      |
+/// | ...     | ...     | ...     | ...     |       | 0       | 1      | 0      |
+/// | e_{n-1} | 1       | n - 1   | i       | i     | 0       | 1      | 1      |
+///
+/// 其中 sum_j = sum_{j-1} + e_j，pos_j = pos_{j-1} + 1，dot_j = dot_{j-1} + pos_j * e_j
+///
+/// 示例中向量的长度
+pub const LEN: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct OneHotConfig {
+    e: Column<Advice>,
+    sum: Column<Advice>,
+    pos: Column<Advice>,
+    dot: Column<Advice>,
+    index: Column<Advice>,
+    s_first: Selector,
+    s_step: Selector,
+    s_last: Selector,
+}
+
+impl OneHotConfig {
+    pub fn configure<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> Self {
+        let [e, sum, pos, dot, index] = [(); 5].map(|_| meta.advice_column());
+        let s_first = meta.selector();
+        let s_step = meta.selector();
+        let s_last = meta.selector();
+        let one = || Expression::Constant(F::ONE);
+
+        meta.create_gate("first", |meta| {
+            let s = meta.query_selector(s_first);
+            let e = meta.query_advice(e, Rotation::cur());
+            let sum = meta.query_advice(sum, Rotation::cur());
+            let pos = meta.query_advice(pos, Rotation::cur());
+            let dot = meta.query_advice(dot, Rotation::cur());
+            vec![
+                s.clone() * e.clone() * (one() - e.clone()),
+                s.clone() * (sum - e),
+                s.clone() * pos,
+                s * dot,
+            ]
+        });
+
+        meta.create_gate("step", |meta| {
+            let s = meta.query_selector(s_step);
+            let e = meta.query_advice(e, Rotation::cur());
+            let sum_prev = meta.query_advice(sum, Rotation::prev());
+            let sum = meta.query_advice(sum, Rotation::cur());
+            let pos_prev = meta.query_advice(pos, Rotation::prev());
+            let pos = meta.query_advice(pos, Rotation::cur());
+            let dot_prev = meta.query_advice(dot, Rotation::prev());
+            let dot = meta.query_advice(dot, Rotation::cur());
+            vec![
+                s.clone() * e.clone() * (one() - e.clone()),
+                s.clone() * (sum - sum_prev - e.clone()),
+                s.clone() * (pos.clone() - pos_prev - one()),
+                s * (dot - dot_prev - pos * e),
+            ]
+        });
+
+        meta.create_gate("last", |meta| {
+            let s = meta.query_selector(s_last);
+            let sum = meta.query_advice(sum, Rotation::cur());
+            let dot = meta.query_advice(dot, Rotation::cur());
+            let index = meta.query_advice(index, Rotation::cur());
+            vec![s.clone() * (sum - one()), s * (dot - index)]
+        });
+
+        Self {
+            e,
+            sum,
+            pos,
+            dot,
+            index,
+            s_first,
+            s_step,
+            s_last,
+        }
+    }
+}
+
+/// private 的下标 i 和证明者给出的向量 e；new 生成正确的 one-hot，from_entries 可以构造错误的向量
+#[derive(Clone, Debug, Default)]
+pub struct OneHotCircuit<F: PrimeField> {
+    pub index: Value<F>,
+    pub entries: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> OneHotCircuit<F> {
+    pub fn new(index: usize, len: usize) -> Self {
+        assert!(
+            index < len,
+            "index {} out of range for length {}",
+            index,
+            len
+        );
+        let entries: Vec<u64> = (0..len).map(|j| (j == index) as u64).collect();
+        Self::from_entries(index as u64, &entries)
+    }
+
+    pub fn from_entries(index: u64, entries: &[u64]) -> Self {
+        assert!(!entries.is_empty(), "need at least one entry");
+        Self {
+            index: Value::known(F::from(index)),
+            entries: entries.iter().map(|e| Value::known(F::from(*e))).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for OneHotCircuit<F> {
+    type Config = OneHotConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 向量的长度决定了电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            index: Value::unknown(),
+            entries: vec![Value::unknown(); self.entries.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        OneHotConfig::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "one-hot",
+            |mut region| {
+                let (mut sum, mut dot) = (Value::known(F::ZERO), Value::known(F::ZERO));
+                for (j, e) in self.entries.iter().enumerate() {
+                    let pos = F::from(j as u64);
+                    sum = sum + e;
+                    dot = dot + e.map(|e| e * pos);
+                    if j == 0 {
+                        config.s_first.enable(&mut region, j)?;
+                    } else {
+                        config.s_step.enable(&mut region, j)?;
+                    }
+                    region.assign_advice(|| "e", config.e, j, || *e)?;
+                    region.assign_advice(|| "sum", config.sum, j, || sum)?;
+                    region.assign_advice(|| "pos", config.pos, j, || Value::known(pos))?;
+                    region.assign_advice(|| "dot", config.dot, j, || dot)?;
+                }
+                let last = self.entries.len() - 1;
+                config.s_last.enable(&mut region, last)?;
+                region.assign_advice(|| "index", config.index, last, || self.index)?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// 没有 public input
+impl<F: PrimeField> DescribeInstance for OneHotCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// 长度为 LEN（与示例 main 相同），下标随机
+impl RandomWitness for OneHotCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let index = rng.next_u32() as usize % LEN;
+        (Self::new(index, LEN), vec![])
+    }
+}
@@ -0,0 +1,40 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use onehot::{OneHotCircuit, LEN};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    // i = 5：e = [0, 0, 0, 0, 0, 1, 0, 0]
+    let circuit = OneHotCircuit::<Fp>::new(5, LEN);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: one-hot encoding...");
+
+    let prover = MockProver::run(row, &circuit, vec![]).unwrap();
+    let res = prover.verify();
+    println!("res1 (one-hot of 5): {:?}", res);
+
+    // 两个 1：和为 2，"last" 门失败
+    let two_hots = OneHotCircuit::<Fp>::from_entries(1, &[0, 1, 0, 1, 0, 0, 0, 0]);
+    let prover_2 = MockProver::run(row, &two_hots, vec![]).unwrap();
+    println!("res2 (two hots): {:?}", prover_2.verify());
+
+    // 位置错误：1 在第 3 个位置，但声称 i = 2
+    let wrong_position = OneHotCircuit::<Fp>::from_entries(2, &[0, 0, 0, 1, 0, 0, 0, 0]);
+    let prover_3 = MockProver::run(row, &wrong_position, vec![]).unwrap();
+    println!("res3 (hot at 3, index 2): {:?}", prover_3.verify());
+
+    // 不是布尔值的向量：e_1 = 2，e_2 = -1，和为 1，Σ j * e_j = 2 - 2 = 0，
+    // 只检查和与点积会误认为它是 0 的 one-hot 编码，布尔约束让 "step" 门失败
+    let mut cheat = OneHotCircuit::<Fp>::new(0, LEN);
+    cheat.entries[0] = Value::known(Fp::zero());
+    cheat.entries[1] = Value::known(Fp::from(2));
+    cheat.entries[2] = Value::known(-Fp::one());
+    let prover_4 = MockProver::run(row, &cheat, vec![]).unwrap();
+    println!("res4 (e = [0, 2, -1, 0, ...]): {:?}", prover_4.verify());
+}
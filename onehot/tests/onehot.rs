@@ -0,0 +1,63 @@
+//! onehot 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use onehot::{OneHotCircuit, LEN};
+use simple::{dev, layout, prover};
+
+const K: u32 = 4;
+
+/// 中间和两端的下标都能通过验证
+#[test]
+fn valid_indices() {
+    assert!(layout::measure(&OneHotCircuit::<Fp>::new(5, LEN)).k <= K);
+    for i in [5, 0, LEN - 1] {
+        let res = MockProver::run(K, &OneHotCircuit::<Fp>::new(i, LEN), vec![])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "index {}", i);
+    }
+}
+
+/// 两个 1、位置错误（1 在第 3 个位置，但声称 i = 2）或全为 0 时，"last" 门失败
+#[test]
+fn wrong_entries_fail_last() {
+    for (index, entries) in [
+        (1, [0, 1, 0, 1, 0, 0, 0, 0]),
+        (2, [0, 0, 0, 1, 0, 0, 0, 0]),
+        (0, [0; LEN]),
+    ] {
+        let circuit = OneHotCircuit::<Fp>::from_entries(index, &entries);
+        let prover = MockProver::run(K, &circuit, vec![]).unwrap();
+        dev::assert_constraint_failure(&prover, "last");
+    }
+}
+
+/// 不是布尔值的向量：e_1 = 2，e_2 = -1，和为 1，Σ j * e_j = 2 - 2 = 0，
+/// 只检查和与点积会误认为它是 0 的 one-hot 编码；只有布尔约束（"step" 门）失败
+#[test]
+fn non_boolean_entries_fail_step() {
+    let mut cheat = OneHotCircuit::<Fp>::new(0, LEN);
+    cheat.entries[0] = Value::known(Fp::zero());
+    cheat.entries[1] = Value::known(Fp::from(2));
+    cheat.entries[2] = Value::known(-Fp::one());
+    let prover = MockProver::run(K, &cheat, vec![]).unwrap();
+    dev::assert_constraint_failure(&prover, "step");
+    assert!(prover
+        .verify()
+        .unwrap_err()
+        .iter()
+        .all(|failure| failure.to_string().contains("in gate 1 ('step')")));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<OneHotCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = OneHotCircuit::<Fp>::new(5, LEN);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[]).is_ok());
+}
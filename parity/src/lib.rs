@@ -0,0 +1,75 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{BitInstructions, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 奇偶性示例：证明 private 的 x 的奇偶性
+/// x 按二进制分解为 NUM_BITS 个布尔值（低位在前），is_even = 1 - b_0 作为 public input 公开：
+/// x 为偶数时公开 1，为奇数时公开 0。分解同时约束了 x < 2^NUM_BITS。
+///
+/// x 的位数
+pub const NUM_BITS: usize = 8;
+
+/// 电路外计算奇偶性，用于得到正确的 public input
+pub fn parity<F: PrimeField>(x: u64) -> F {
+    F::from(x.is_multiple_of(2) as u64)
+}
+
+#[derive(Default)]
+pub struct ParityCircuit<F: PrimeField> {
+    pub x: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for ParityCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let even = chip.is_even(layouter.namespace(|| "is_even(x)"), x, NUM_BITS)?;
+        chip.expose_public(layouter.namespace(|| "expose parity"), even, 0)
+    }
+}
+
+/// public input 为 x 的奇偶性
+impl<F: PrimeField> DescribeInstance for ParityCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// x 为随机的 NUM_BITS 位整数
+impl RandomWitness for ParityCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let x = rng.next_u64() % (1 << NUM_BITS);
+        let circuit = Self {
+            x: Value::known(Fp::from(x)),
+        };
+        (circuit, vec![vec![parity(x)]])
+    }
+}
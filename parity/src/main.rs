@@ -0,0 +1,39 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use parity::{parity, ParityCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 6;
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(
+        row,
+        &ParityCircuit {
+            x: Value::known(Fp::from(42)),
+        },
+        vec![vec![parity::<Fp>(42)]],
+    ) {
+        return;
+    }
+    println!("Hello, this is halo2 example: parity...");
+
+    // 偶数、奇数，以及边界情况 0（偶数）和 255（8 bit 的最大值）
+    for x in [42u64, 7, 0, 255] {
+        let circuit = ParityCircuit {
+            x: Value::known(Fp::from(x)),
+        };
+        let expected = parity::<Fp>(x);
+        let prover = MockProver::run(row, &circuit, vec![vec![expected]]).unwrap();
+        let res = prover.verify();
+        println!("res (x = {}, even = {:?}): {:?}", x, expected, res);
+    }
+
+    // 256 超出 8 bit，分解的约束无法满足
+    let circuit_2 = ParityCircuit {
+        x: Value::known(Fp::from(256)),
+    };
+    let prover_2 = MockProver::run(row, &circuit_2, vec![vec![Fp::one()]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2 (x = 256): {:?}", res_2);
+}
@@ -0,0 +1,56 @@
+//! parity 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use parity::{parity, ParityCircuit};
+use simple::{dev, prover};
+
+const K: u32 = 6;
+
+/// 偶数、奇数，以及边界情况 0（偶数）和 255（8 bit 的最大值）；公开相反的奇偶性时验证失败
+#[test]
+fn even_and_odd() {
+    assert_eq!(parity::<Fp>(0), Fp::one());
+    for x in [42u64, 7, 0, 255] {
+        let circuit = ParityCircuit {
+            x: Value::known(Fp::from(x)),
+        };
+        let expected = parity::<Fp>(x);
+        let res = MockProver::run(K, &circuit, vec![vec![expected]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "x = {}", x);
+
+        let wrong = Fp::one() - expected;
+        let res = MockProver::run(K, &circuit, vec![vec![wrong]])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "x = {}", x);
+    }
+}
+
+/// 256 超出 8 bit，分解的约束无法满足
+#[test]
+fn out_of_range_fails() {
+    let circuit = ParityCircuit {
+        x: Value::known(Fp::from(256)),
+    };
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::one()]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ParityCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = ParityCircuit {
+        x: Value::known(Fp::from(42)),
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![parity::<Fp>(42)]]).is_ok());
+}
@@ -0,0 +1,380 @@
+use group::ff::{FromUniformBytes, PrimeField};
+use halo2_poseidon::{generate_constants, ConstantLength, Hash, Mds, P128Pow5T3, Spec};
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Instance, Selector,
+    },
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// 默认的置换宽度（状态元素个数）
+pub const WIDTH: usize = 3;
+/// 默认的 rate：每次置换可以吸收的元素个数，容量（capacity）为 WIDTH - RATE
+pub const RATE: usize = 2;
+
+///////////////////////////////////////////////////////////////////////
+/// 电路外的参考实现：把两个域元素哈希为一个域元素（P128Pow5T3）
+/// 等价于 halo2_poseidon 中的 ConstantLength<2> 哈希，电路中的 hash 指令与它的结果一致
+pub fn hash_to_field<F>(message: [F; RATE]) -> F
+where
+    F: PrimeField,
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    hash_with::<F, P128Pow5T3, WIDTH, RATE>(message)
+}
+
+/// 任意宽度和参数的电路外参考实现：用 halo2_poseidon 的 ConstantLength<R> 哈希把 R 个域元素哈希为一个
+pub fn hash_with<F, S, const W: usize, const R: usize>(message: [F; R]) -> F
+where
+    F: PrimeField,
+    S: Spec<F, W, R>,
+{
+    Hash::<F, S, ConstantLength<R>, W, R>::init().hash(message)
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 可配置的 Poseidon 参数：宽度 W、rate R、全轮数 FULL 和部分轮数 PARTIAL，S-box 固定为 x^5
+/// 轮常数和 MDS 矩阵用 halo2_poseidon 的 generate_constants 按 Poseidon 论文的方法（Grain LFSR）生成。
+/// FULL 必须为偶数（前后各一半），R 必须小于 W。
+///
+/// 安全的参数（x^5，约 255 bit 的 Pasta 域，128 bit 安全并留有余量，见 Poseidon 论文表 2 与 halo2_poseidon）：
+/// - W = 3，R = 2：FULL = 8，PARTIAL = 56（即 P128Pow5T3）
+/// - W = 2，R = 1：FULL = 8，PARTIAL = 56
+///
+/// 减少轮数只能用于实验（例如观察行数的变化），得到的哈希函数不安全。
+#[derive(Debug)]
+pub struct Pow5Spec<const W: usize, const R: usize, const FULL: usize, const PARTIAL: usize>;
+
+/// 宽度 2（rate 1）的 128 bit 安全参数
+pub type P128Pow5T2 = Pow5Spec<2, 1, 8, 56>;
+
+impl<F, const W: usize, const R: usize, const FULL: usize, const PARTIAL: usize> Spec<F, W, R>
+    for Pow5Spec<W, R, FULL, PARTIAL>
+where
+    F: FromUniformBytes<64> + Ord,
+{
+    fn full_rounds() -> usize {
+        FULL
+    }
+
+    fn partial_rounds() -> usize {
+        PARTIAL
+    }
+
+    fn sbox(val: F) -> F {
+        val.pow_vartime([5])
+    }
+
+    /// 使用生成的第一个安全的 MDS 矩阵
+    fn secure_mds() -> usize {
+        0
+    }
+
+    fn constants() -> (Vec<[F; W]>, Mds<F, W>, Mds<F, W>) {
+        generate_constants::<F, Self, W, R>()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 芯片配置：状态列、轮常数列、全轮和部分轮的选择器，以及置换需要的常数
+/// W 为置换的宽度，R 为 rate，默认为 P128Pow5T3 的宽度 3、rate 2
+#[derive(Clone, Debug)]
+pub struct PoseidonConfig<F: PrimeField, const W: usize = WIDTH, const R: usize = RATE> {
+    state: [Column<Advice>; W],
+    rc: [Column<Fixed>; W],
+    s_full: Selector,
+    s_partial: Selector,
+    round_constants: Vec<[F; W]>,
+    mds: Mds<F, W>,
+    full_rounds: usize,
+    partial_rounds: usize,
+}
+
+/// Poseidon 哈希芯片，宽度为 W，rate 为 R，S-box 为 x^5，轮数和常数由 configure 时的 Spec 决定
+/// 默认参数为 P128Pow5T3（宽度 3，rate 2），常数（轮常数、MDS 矩阵）直接取自 halo2_poseidon，
+/// 电路外的参考实现也使用同一个库，从而可以把电路的输出与参考实现逐一比对。
+///
+/// 每一轮占用一行，状态的 W 个元素放在 W 个 advice 列中，轮常数放在 W 个 fixed 列中（以 W = 3 为例）：
+///
+/// | state0 | state1 | state2 | rc0 | rc1 | rc2 | s_full | s_partial |
+/// |--------|--------|--------|-----|-----|-----|--------|-----------|
+/// | x0     | x1     | x2     | c0  | c1  | c2  | 1      | 0         |
+/// | y0     | y1     | y2     | ... | ... | ... | ...    | ...       |
+///
+/// 下一行的状态 = MDS * sbox(当前行状态 + 轮常数)
+#[derive(Clone, Debug)]
+pub struct PoseidonChip<F: PrimeField, const W: usize = WIDTH, const R: usize = RATE> {
+    config: PoseidonConfig<F, W, R>,
+    _marker: PhantomData<F>,
+}
+
+/// S-box：x^5
+fn pow5<F: PrimeField>(x: Expression<F>) -> Expression<F> {
+    let x2 = x.clone() * x.clone();
+    x2.clone() * x2 * x
+}
+
+impl<F: PrimeField, const W: usize, const R: usize> PoseidonChip<F, W, R> {
+    pub fn construct(config: PoseidonConfig<F, W, R>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// 配置置换的约束，S 决定轮数和常数（这里固定使用 x^5 作为 S-box）
+    /// constant 列用于加载容量元素（capacity），会被设置为全局常量列
+    pub fn configure<S: Spec<F, W, R>>(
+        meta: &mut ConstraintSystem<F>,
+        state: [Column<Advice>; W],
+        rc: [Column<Fixed>; W],
+        constant: Column<Fixed>,
+    ) -> PoseidonConfig<F, W, R> {
+        assert!(R < W, "rate must be smaller than the width");
+        for c in &state {
+            meta.enable_equality(*c);
+        }
+        meta.enable_constant(constant);
+
+        let s_full = meta.selector();
+        let s_partial = meta.selector();
+        let (round_constants, mds, _) = S::constants();
+
+        // 全轮：每个状态元素都经过 S-box
+        meta.create_gate("full round", |meta| {
+            let s = meta.query_selector(s_full);
+            let sboxed: Vec<Expression<F>> = (0..W)
+                .map(|j| {
+                    let cur = meta.query_advice(state[j], Rotation::cur());
+                    let rc = meta.query_fixed(rc[j]);
+                    pow5(cur + rc)
+                })
+                .collect();
+            (0..W)
+                .map(|i| {
+                    let next = meta.query_advice(state[i], Rotation::next());
+                    let mixed = (0..W).fold(Expression::Constant(F::ZERO), |acc, j| {
+                        acc + Expression::Constant(mds[i][j]) * sboxed[j].clone()
+                    });
+                    s.clone() * (mixed - next)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // 部分轮：只有第一个状态元素经过 S-box
+        meta.create_gate("partial round", |meta| {
+            let s = meta.query_selector(s_partial);
+            let sboxed: Vec<Expression<F>> = (0..W)
+                .map(|j| {
+                    let cur = meta.query_advice(state[j], Rotation::cur());
+                    let rc = meta.query_fixed(rc[j]);
+                    if j == 0 {
+                        pow5(cur + rc)
+                    } else {
+                        cur + rc
+                    }
+                })
+                .collect();
+            (0..W)
+                .map(|i| {
+                    let next = meta.query_advice(state[i], Rotation::next());
+                    let mixed = (0..W).fold(Expression::Constant(F::ZERO), |acc, j| {
+                        acc + Expression::Constant(mds[i][j]) * sboxed[j].clone()
+                    });
+                    s.clone() * (mixed - next)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        PoseidonConfig {
+            state,
+            rc,
+            s_full,
+            s_partial,
+            round_constants,
+            mds,
+            full_rounds: S::full_rounds(),
+            partial_rounds: S::partial_rounds(),
+        }
+    }
+
+    /// 加载私有变量
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "load_private",
+            |mut region| region.assign_advice(|| "private input", self.config.state[0], 0, || a),
+        )
+    }
+
+    /// 加载常量，常量放在 fixed 列中并通过拷贝约束固定
+    pub fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "load_constant",
+            |mut region| {
+                region.assign_advice_from_constant(|| "constant", self.config.state[0], 0, constant)
+            },
+        )
+    }
+
+    /// 第 round 轮是否为全轮：前后各 full_rounds / 2 轮为全轮，中间为部分轮
+    fn is_full_round(&self, round: usize) -> bool {
+        let half = self.config.full_rounds / 2;
+        round < half || round >= half + self.config.partial_rounds
+    }
+
+    /// 电路外计算一轮置换，用于给下一行的 advice 赋值
+    fn round(&self, round: usize, state: [F; W]) -> [F; W] {
+        let rc = self.config.round_constants[round];
+        let mut sboxed = [F::ZERO; W];
+        for j in 0..W {
+            sboxed[j] = state[j] + rc[j];
+            if j == 0 || self.is_full_round(round) {
+                sboxed[j] = sboxed[j].pow_vartime([5]);
+            }
+        }
+        let mut next = [F::ZERO; W];
+        for (i, out) in next.iter_mut().enumerate() {
+            for (j, x) in sboxed.iter().enumerate() {
+                *out += self.config.mds[i][j] * x;
+            }
+        }
+        next
+    }
+
+    /// 哈希指令：计算 hash(message[0], ..., message[R - 1])，与 hash_with（默认参数时为 hash_to_field）的结果一致
+    pub fn hash(
+        &self,
+        mut layouter: impl Layouter<F>,
+        message: [AssignedCell<F, F>; R],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        let rounds = config.full_rounds + config.partial_rounds;
+        // ConstantLength<L> 的第一个容量元素为 L * 2^64，其余容量元素为 0
+        let capacity = F::from_u128((R as u128) << 64);
+
+        layouter.assign_region(
+            || "poseidon hash",
+            |mut region| {
+                // 第 0 行：吸收消息，容量元素从常量列加载
+                let mut cells = Vec::with_capacity(W);
+                for (i, m) in message.iter().enumerate() {
+                    cells.push(m.copy_advice(|| "message", &mut region, config.state[i], 0)?);
+                }
+                for i in R..W {
+                    let value = if i == R { capacity } else { F::ZERO };
+                    cells.push(region.assign_advice_from_constant(
+                        || "capacity",
+                        config.state[i],
+                        0,
+                        value,
+                    )?);
+                }
+
+                let mut state = Value::known([F::ZERO; W]);
+                for (i, cell) in cells.iter().enumerate() {
+                    state = state.zip(cell.value()).map(|(mut s, v)| {
+                        s[i] = *v;
+                        s
+                    });
+                }
+
+                for round in 0..rounds {
+                    if self.is_full_round(round) {
+                        config.s_full.enable(&mut region, round)?;
+                    } else {
+                        config.s_partial.enable(&mut region, round)?;
+                    }
+                    for j in 0..W {
+                        let rc = config.round_constants[round][j];
+                        region.assign_fixed(
+                            || "round constant",
+                            config.rc[j],
+                            round,
+                            || Value::known(rc),
+                        )?;
+                    }
+
+                    state = state.map(|s| self.round(round, s));
+                    cells.clear();
+                    for j in 0..W {
+                        cells.push(region.assign_advice(
+                            || "state",
+                            config.state[j],
+                            round + 1,
+                            || state.map(|s| s[j]),
+                        )?);
+                    }
+                }
+
+                // 输出为置换后状态的第一个元素
+                Ok(cells.swap_remove(0))
+            },
+        )
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 哈希 R 个 private 的域元素，摘要作为 public input 公开，用来检查不同宽度的芯片
+pub struct HashCircuit<S, const W: usize, const R: usize> {
+    message: [Value<Fp>; R],
+    _spec: PhantomData<S>,
+}
+
+impl<S, const W: usize, const R: usize> HashCircuit<S, W, R> {
+    pub fn new(message: [Fp; R]) -> Self {
+        Self {
+            message: message.map(Value::known),
+            _spec: PhantomData,
+        }
+    }
+}
+
+impl<S: Spec<Fp, W, R>, const W: usize, const R: usize> Circuit<Fp> for HashCircuit<S, W, R> {
+    type Config = (PoseidonConfig<Fp, W, R>, Column<Instance>);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            message: [Value::unknown(); R],
+            _spec: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let state = std::array::from_fn(|_| meta.advice_column());
+        let rc = std::array::from_fn(|_| meta.fixed_column());
+        let constant = meta.fixed_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+        (
+            PoseidonChip::configure::<S>(meta, state, rc, constant),
+            instance,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        (config, instance): Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = PoseidonChip::construct(config);
+        let mut message = Vec::with_capacity(R);
+        for m in self.message {
+            message.push(chip.load_private(layouter.namespace(|| "load message"), m)?);
+        }
+        let message = message.try_into().unwrap();
+        let digest = chip.hash(layouter.namespace(|| "hash"), message)?;
+        layouter.constrain_instance(digest.cell(), instance, 0)
+    }
+}
@@ -0,0 +1,40 @@
+use group::ff::PrimeField;
+use halo2_poseidon::{test_vectors, P128Pow5T3};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use poseidon::{hash_with, HashCircuit, P128Pow5T2};
+
+fn from_bytes(bytes: [u8; 32]) -> Fp {
+    Fp::from_repr(bytes).unwrap()
+}
+
+fn main() {
+    println!("Hello, this is halo2 example: poseidon...");
+    // 每一轮一行，共 64 轮
+    let row = 7;
+
+    // 宽度 3：zcash 的 Poseidon 测试向量
+    let vectors = test_vectors::fp::hash();
+    let verified = vectors
+        .iter()
+        .filter(|vector| {
+            let circuit = HashCircuit::<P128Pow5T3, 3, 2>::new(vector.input.map(from_bytes));
+            let digest = from_bytes(vector.output);
+            MockProver::run(row, &circuit, vec![vec![digest]])
+                .unwrap()
+                .verify()
+                .is_ok()
+        })
+        .count();
+    println!("width 3: {} of {} test vectors ok", verified, vectors.len());
+
+    // 宽度 2（rate 1）：没有公开的测试向量，与 halo2_poseidon 的电路外实现比对
+    let message = [Fp::from(42)];
+    let digest = hash_with::<Fp, P128Pow5T2, 2, 1>(message);
+    let circuit = HashCircuit::<P128Pow5T2, 2, 1>::new(message);
+    let prover = MockProver::run(row, &circuit, vec![vec![digest]]).unwrap();
+    println!("width 2: {:?}", prover.verify());
+
+    // 错误的摘要，验证失败
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![digest + Fp::one()]]).unwrap();
+    println!("width 2, wrong digest: {:?}", prover_2.verify().is_ok());
+}
@@ -0,0 +1,67 @@
+//! poseidon 示例电路的测试
+
+use group::ff::PrimeField;
+use halo2_poseidon::{test_vectors, P128Pow5T3, Spec};
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use poseidon::{hash_to_field, hash_with, HashCircuit, P128Pow5T2, Pow5Spec};
+
+/// 每一轮一行，共 64 轮
+const K: u32 = 7;
+
+fn from_bytes(bytes: [u8; 32]) -> Fp {
+    Fp::from_repr(bytes).unwrap()
+}
+
+/// 宽度 3：与 zcash 的 Poseidon 测试向量一致（电路外和电路中）
+#[test]
+fn width_3_test_vectors() {
+    for vector in test_vectors::fp::hash() {
+        let message = vector.input.map(from_bytes);
+        let digest = from_bytes(vector.output);
+        assert_eq!(hash_to_field(message), digest);
+        let circuit = HashCircuit::<P128Pow5T3, 3, 2>::new(message);
+        let res = MockProver::run(K, &circuit, vec![vec![digest]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()));
+    }
+}
+
+/// 按论文方法生成的宽度 3 常数与 P128Pow5T3 中硬编码的常数相同
+#[test]
+fn generated_constants_match() {
+    let (generated_rc, generated_mds, _) = <Pow5Spec<3, 2, 8, 56> as Spec<Fp, 3, 2>>::constants();
+    let (rc, mds, _) = <P128Pow5T3 as Spec<Fp, 3, 2>>::constants();
+    assert_eq!(generated_rc, rc);
+    assert_eq!(generated_mds, mds);
+}
+
+/// 宽度 2（rate 1）：没有公开的测试向量，与 halo2_poseidon 的电路外实现比对；错误的摘要验证失败
+#[test]
+fn width_2() {
+    let message = [Fp::from(42)];
+    let digest = hash_with::<Fp, P128Pow5T2, 2, 1>(message);
+    assert_ne!(digest, hash_to_field([Fp::from(42), Fp::zero()]));
+    let circuit = HashCircuit::<P128Pow5T2, 2, 1>::new(message);
+    let res = MockProver::run(K, &circuit, vec![vec![digest]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(K, &circuit, vec![vec![digest + Fp::one()]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 实验用的减少轮数的参数（不安全）：行数随轮数减少，仍与电路外实现一致
+#[test]
+fn reduced_rounds() {
+    type Reduced = Pow5Spec<3, 2, 4, 8>;
+    let message = [Fp::from(1), Fp::from(2)];
+    let digest = hash_with::<Fp, Reduced, 3, 2>(message);
+    let circuit = HashCircuit::<Reduced, 3, 2>::new(message);
+    let res = MockProver::run(5, &circuit, vec![vec![digest]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
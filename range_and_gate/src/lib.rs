@@ -0,0 +1,194 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector, TableColumn},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// lookup 与自定义门结合的示例：证明 out = a^2，且 a 是 8 bit 的数（0 <= a < 256）
+/// 同一个芯片中：
+/// - lookup：a 必须出现在 0..256 的表中（范围检查）
+/// - 自定义门 "square"：a * a - out = 0（算术关系）
+///
+/// out 通过 instance 列公开
+///
+/// 范围检查的位数，表中有 2^RANGE_BITS 行
+pub const RANGE_BITS: usize = 8;
+
+/// | a | out | s_square |
+/// |---|-----|----------|
+/// | a | a^2 | 1        |
+///
+/// s_square 同时控制 lookup 和自定义门，lookup 的输入为 s_square * a：
+/// 未启用的行输入为 0，0 在表中，总能通过
+#[derive(Clone, Debug)]
+pub struct RangeAndGateConfig {
+    a: Column<Advice>,
+    out: Column<Advice>,
+    instance: Column<Instance>,
+    s_square: Selector,
+    table: TableColumn,
+}
+
+#[derive(Clone, Debug)]
+pub struct RangeAndGateChip<F: PrimeField> {
+    config: RangeAndGateConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> RangeAndGateChip<F> {
+    pub fn construct(config: RangeAndGateConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        out: Column<Advice>,
+        instance: Column<Instance>,
+    ) -> RangeAndGateConfig {
+        meta.enable_equality(out);
+        meta.enable_equality(instance);
+
+        // lookup 中使用的选择器必须是 complex selector
+        let s_square = meta.complex_selector();
+        let table = meta.lookup_table_column();
+
+        // 范围检查：a 在 0..2^RANGE_BITS 中
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_square);
+            let a = meta.query_advice(a, Rotation::cur());
+            vec![(s * a, table)]
+        });
+
+        // 算术关系：out = a * a
+        meta.create_gate("square", |meta| {
+            let s = meta.query_selector(s_square);
+            let a = meta.query_advice(a, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+            vec![s * (a.clone() * a - out)]
+        });
+
+        RangeAndGateConfig {
+            a,
+            out,
+            instance,
+            s_square,
+            table,
+        }
+    }
+
+    /// 加载范围检查表：0, 1, ..., 2^RANGE_BITS - 1
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = &self.config;
+        layouter.assign_table(
+            || "range table",
+            |mut table| {
+                for i in 0..(1 << RANGE_BITS) {
+                    table.assign_cell(
+                        || "value",
+                        config.table,
+                        i,
+                        || Value::known(F::from(i as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 在同一行中赋值 a 和 out，同时启用范围检查和 "square" 门
+    pub fn assign_square(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        out: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "square",
+            |mut region| {
+                config.s_square.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", config.a, 0, || a)?;
+                region.assign_advice(|| "out", config.out, 0, || out)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: AssignedCell<F, F>,
+        row: usize,
+    ) -> Result<(), Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    }
+}
+
+/// a 和 out 都作为 witness 提供，方便构造算术关系不成立的情况
+/// 正常使用时 out = a^2，可以用 RangeAndGateCircuit::new(a) 构造
+#[derive(Default)]
+pub struct RangeAndGateCircuit<F: PrimeField> {
+    pub a: Value<F>,
+    pub out: Value<F>,
+}
+
+impl<F: PrimeField> RangeAndGateCircuit<F> {
+    pub fn new(a: F) -> Self {
+        Self {
+            a: Value::known(a),
+            out: Value::known(a.square()),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RangeAndGateCircuit<F> {
+    type Config = RangeAndGateConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let out = meta.advice_column();
+        let instance = meta.instance_column();
+        RangeAndGateChip::configure(meta, a, out, instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = RangeAndGateChip::construct(config);
+        chip.load_table(layouter.namespace(|| "load table"))?;
+        let out = chip.assign_square(layouter.namespace(|| "a^2"), self.a, self.out)?;
+        chip.expose_public(layouter.namespace(|| "expose out"), out, 0)
+    }
+}
+
+/// public input 为 out = a^2
+impl<F: PrimeField> DescribeInstance for RangeAndGateCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// a 为随机的 RANGE_BITS 位整数
+impl RandomWitness for RangeAndGateCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = Fp::from(rng.next_u64() % (1 << RANGE_BITS));
+        (Self::new(a), vec![vec![a.square()]])
+    }
+}
@@ -0,0 +1,33 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use range_and_gate::RangeAndGateCircuit;
+use simple::report;
+
+fn main() {
+    // 表中有 256 行，电路至少需要 2^9 行
+    let row = 9;
+
+    // a = 15 在范围内，out = 225，验证成功
+    let circuit = RangeAndGateCircuit::new(Fp::from(15));
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![Fp::from(225)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: range check + custom gate...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![Fp::from(225)]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // a = 15 在范围内，但 out = 224 != 15^2，"square" 门不满足
+    let circuit_2 = RangeAndGateCircuit {
+        a: Value::known(Fp::from(15)),
+        out: Value::known(Fp::from(224)),
+    };
+    let prover_2 = MockProver::run(row, &circuit_2, vec![vec![Fp::from(224)]]).unwrap();
+    println!("res2: {:?}", prover_2.verify());
+
+    // a = 300 超出 8 bit 的范围，即使 out = 300^2 正确，lookup 也不满足
+    let circuit_3 = RangeAndGateCircuit::new(Fp::from(300));
+    let prover_3 = MockProver::run(row, &circuit_3, vec![vec![Fp::from(90000)]]).unwrap();
+    println!("res3: {:?}", prover_3.verify());
+}
@@ -0,0 +1,50 @@
+//! range_and_gate 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use range_and_gate::RangeAndGateCircuit;
+use simple::{dev, prover};
+
+/// 表中有 256 行，电路至少需要 2^9 行
+const K: u32 = 9;
+
+/// a = 15 在范围内，out = 225，验证成功
+#[test]
+fn square_in_range() {
+    let circuit = RangeAndGateCircuit::new(Fp::from(15));
+    let res = MockProver::run(K, &circuit, vec![vec![Fp::from(225)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// a = 15 在范围内，但 out = 224 != 15^2，"square" 门不满足
+#[test]
+fn wrong_square_fails_gate() {
+    let circuit = RangeAndGateCircuit {
+        a: Value::known(Fp::from(15)),
+        out: Value::known(Fp::from(224)),
+    };
+    let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(224)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "square");
+}
+
+/// a = 300 超出 8 bit 的范围，即使 out = 300^2 正确，lookup 也不满足
+#[test]
+fn out_of_range_fails_lookup() {
+    let circuit = RangeAndGateCircuit::new(Fp::from(300));
+    let prover = MockProver::run(K, &circuit, vec![vec![Fp::from(90000)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "lookup 0");
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<RangeAndGateCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = RangeAndGateCircuit::new(Fp::from(15));
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![Fp::from(225)]]).is_ok());
+}
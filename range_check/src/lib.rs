@@ -0,0 +1,192 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Selector, TableColumn},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+pub mod prove;
+
+///////////////////////////////////////////////////////////////////////
+/// 范围检查芯片：证明一个已赋值的数 a 满足 0 <= a < 2^(8 * num_limbs)
+/// 把 a 按 8 bit 拆成若干段（limb），用 running sum 的方式放在一列中：
+///
+/// | z                  | s_limb | s_zero |
+/// |--------------------|--------|--------|
+/// | z_0 = a            | 1      |        |
+/// | z_1 = (z_0 - l_0) / 2^8 | 1 |        |
+/// | ...                | ...    |        |
+/// | z_n                |        | 1      |
+///
+/// 每一段 l_i = z_i - 2^8 * z_{i+1} 通过 lookup 检查在 0..2^8 中，最后一行约束 z_n = 0。
+/// 芯片只需要一个 advice 列，可以与其它芯片共用同一列。
+///
+/// 每一段的位数
+pub const LIMB_BITS: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct RangeCheckConfig {
+    z: Column<Advice>,
+    s_limb: Selector,
+    s_zero: Selector,
+    table: TableColumn,
+}
+
+pub struct RangeCheckChip<F: PrimeField> {
+    config: RangeCheckConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> RangeCheckChip<F> {
+    pub fn construct(config: RangeCheckConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// z 为 running sum 所在的列，需要启用 equality（用于把待检查的数拷贝进来）
+    pub fn configure(meta: &mut ConstraintSystem<F>, z: Column<Advice>) -> RangeCheckConfig {
+        meta.enable_equality(z);
+        // lookup 中使用的选择器必须是 complex selector
+        let s_limb = meta.complex_selector();
+        let s_zero = meta.selector();
+        let table = meta.lookup_table_column();
+
+        // l_i = z_i - 2^8 * z_{i+1} 在 0..2^8 中
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_limb);
+            let cur = meta.query_advice(z, Rotation::cur());
+            let next = meta.query_advice(z, Rotation::next());
+            let limb = cur - next * Expression::Constant(F::from(1 << LIMB_BITS));
+            vec![(s * limb, table)]
+        });
+
+        meta.create_gate("range_check_zero", |meta| {
+            let s = meta.query_selector(s_zero);
+            let z = meta.query_advice(z, Rotation::cur());
+            vec![s * z]
+        });
+
+        RangeCheckConfig {
+            z,
+            s_limb,
+            s_zero,
+            table,
+        }
+    }
+
+    /// 加载 0..2^8 的表
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = &self.config;
+        layouter.assign_table(
+            || "limb table",
+            |mut table| {
+                for i in 0..(1 << LIMB_BITS) {
+                    table.assign_cell(
+                        || "limb",
+                        config.table,
+                        i,
+                        || Value::known(F::from(i as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 检查 a < 2^(8 * num_limbs)
+    pub fn range_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        num_limbs: usize,
+    ) -> Result<(), Error> {
+        let config = &self.config;
+        let inv = F::from(1 << LIMB_BITS).invert().unwrap();
+        layouter.assign_region(
+            || "range check",
+            |mut region| {
+                let mut z = a.copy_advice(|| "z_0", &mut region, config.z, 0)?;
+                for i in 0..num_limbs {
+                    config.s_limb.enable(&mut region, i)?;
+                    // 最低的 8 bit 就是小端表示的第一个字节
+                    let next = z.value().map(|z| {
+                        let limb = F::from(z.to_repr().as_ref()[0] as u64);
+                        (*z - limb) * inv
+                    });
+                    z = region.assign_advice(
+                        || format!("z_{}", i + 1),
+                        config.z,
+                        i + 1,
+                        || next,
+                    )?;
+                }
+                config.s_zero.enable(&mut region, num_limbs)
+            },
+        )
+    }
+}
+
+/// 只做范围检查的电路：private 的 value 必须小于 2^(8 * num_limbs)，没有 public input
+#[derive(Default)]
+pub struct RangeCheckCircuit<F: PrimeField> {
+    pub value: Value<F>,
+    pub num_limbs: usize,
+}
+
+impl<F: PrimeField> Circuit<F> for RangeCheckCircuit<F> {
+    type Config = RangeCheckConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            value: Value::unknown(),
+            num_limbs: self.num_limbs,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let z = meta.advice_column();
+        RangeCheckChip::configure(meta, z)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = RangeCheckChip::construct(config.clone());
+        chip.load_table(layouter.namespace(|| "load table"))?;
+        let value = layouter.assign_region(
+            || "load value",
+            |mut region| region.assign_advice(|| "value", config.z, 0, || self.value),
+        )?;
+        chip.range_check(layouter.namespace(|| "check value"), &value, self.num_limbs)
+    }
+}
+
+/// 没有 public input
+impl<F: PrimeField> DescribeInstance for RangeCheckCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// 两个 limb（与示例 main 相同），value 为随机的 2 * LIMB_BITS 位整数，没有 public input
+impl RandomWitness for RangeCheckCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let num_limbs = 2;
+        let value = rng.next_u64() % (1 << (LIMB_BITS * num_limbs));
+        let circuit = Self {
+            value: Value::known(Fp::from(value)),
+            num_limbs,
+        };
+        (circuit, vec![])
+    }
+}
@@ -0,0 +1,49 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use range_check::{
+    prove::{prove_range, verify_range},
+    RangeCheckCircuit,
+};
+use simple::report;
+
+fn main() {
+    // 表中有 256 行，电路至少需要 2^9 行
+    let row = 9;
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(
+        row,
+        &RangeCheckCircuit {
+            value: Value::known(Fp::from(65535)),
+            num_limbs: 2,
+        },
+        vec![],
+    ) {
+        return;
+    }
+    println!("Hello, this is halo2 example: range check...");
+
+    // 2 段 limb：0 <= value < 2^16
+    for value in [0u64, 65535, 65536, 1 << 20] {
+        let circuit = RangeCheckCircuit {
+            value: Value::known(Fp::from(value)),
+            num_limbs: 2,
+        };
+        let prover = MockProver::run(row, &circuit, vec![]).unwrap();
+        let res = prover.verify();
+        println!("res ({} < 2^16): {:?}", value, res);
+    }
+
+    // 端到端的范围证明：证明者序列化 commitment 和证明，验证者只用这些字节验证
+    let blinding = Fp::from(987654321);
+    let bytes = prove_range(40000, blinding).unwrap();
+    println!("range proof: {} bytes", bytes.len());
+    let commitment = verify_range(&bytes);
+    println!("range proof verified: {:?}", commitment.is_ok());
+
+    // 不在范围中的值无法得到能通过验证的证明
+    let out_of_range = prove_range(1 << 16, blinding).and_then(|bytes| verify_range(&bytes));
+    println!(
+        "range proof (2^16): {:?}",
+        out_of_range.as_ref().map(|_| ())
+    );
+}
@@ -0,0 +1,142 @@
+use group::ff::PrimeField;
+use halo2_poseidon::{P128Pow5T3, Spec};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, Column, ConstraintSystem, Error, Instance},
+};
+use poseidon::{PoseidonChip, PoseidonConfig, RATE, WIDTH};
+use simple::prover::{DescribeInstance, ProofError, Prover};
+
+use crate::{RangeCheckChip, RangeCheckConfig, LIMB_BITS};
+
+///////////////////////////////////////////////////////////////////////
+/// 端到端的范围证明：证明者承诺一个 private 的 value，并证明 0 <= value < 2^16
+/// - 承诺 commitment = Poseidon(value, blinding)，是唯一的 public input
+/// - 范围检查芯片把 value 拆成 2 段 8 bit 的 limb，value 与哈希的输入通过拷贝约束绑定
+///
+/// prove_range 生成真实的证明（IPA），并与 public input 一起序列化为字节：
+///
+/// | 32 字节                 | 其余字节 |
+/// |-------------------------|----------|
+/// | commitment（小端表示）  | 证明     |
+///
+/// 验证者只拿到这些字节，用 verify_range 从中读出承诺并验证证明，验证成功说明承诺的值在范围中。
+///
+/// 范围的位数
+pub const RANGE_BITS: usize = 16;
+
+/// 电路的行数：范围表有 256 行，需要 2^9 行
+pub const RANGE_PROOF_K: u32 = 9;
+
+#[derive(Clone, Debug)]
+pub struct RangeProofConfig<F: PrimeField> {
+    poseidon: PoseidonConfig<F>,
+    range: RangeCheckConfig,
+    instance: Column<Instance>,
+}
+
+#[derive(Default)]
+pub struct RangeProofCircuit<F: PrimeField> {
+    pub value: Value<F>,
+    pub blinding: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for RangeProofCircuit<F>
+where
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    type Config = RangeProofConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let state = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let rc = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        let constant = meta.fixed_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        RangeProofConfig {
+            poseidon: PoseidonChip::configure::<P128Pow5T3>(meta, state, rc, constant),
+            // 范围检查与 Poseidon 共用第一个状态列
+            range: RangeCheckChip::configure(meta, state[0]),
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let poseidon = PoseidonChip::construct(config.poseidon);
+        let range = RangeCheckChip::construct(config.range);
+        range.load_table(layouter.namespace(|| "load table"))?;
+
+        let value = poseidon.load_private(layouter.namespace(|| "load value"), self.value)?;
+        let blinding =
+            poseidon.load_private(layouter.namespace(|| "load blinding"), self.blinding)?;
+        range.range_check(
+            layouter.namespace(|| "value < 2^16"),
+            &value,
+            RANGE_BITS / LIMB_BITS,
+        )?;
+
+        let commitment = poseidon.hash(layouter.namespace(|| "commit"), [value, blinding])?;
+        layouter.constrain_instance(commitment.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为 commitment
+impl<F: PrimeField> DescribeInstance for RangeProofCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 证明者：承诺 value 并生成范围证明，返回序列化的 commitment 和证明
+/// value 不在范围中时，lookup 无法满足，生成证明会失败（或得到无法验证的证明）
+pub fn prove_range(value: u64, blinding: Fp) -> Result<Vec<u8>, ProofError> {
+    let value = Fp::from(value);
+    let circuit = RangeProofCircuit {
+        value: Value::known(value),
+        blinding: Value::known(blinding),
+    };
+    let commitment = commitment::commit(value, blinding);
+    let prover = Prover::from_circuit(RANGE_PROOF_K, &circuit)?;
+    let proof = prover.prove(&circuit, &[vec![commitment]])?;
+
+    let mut bytes = commitment.to_repr().as_ref().to_vec();
+    bytes.extend_from_slice(&proof);
+    Ok(bytes)
+}
+
+/// 验证者：从序列化的字节中读出 commitment 和证明并验证，成功时返回证明所承诺的 commitment
+/// 验证者自己用电路结构生成密钥，不需要证明者的任何数据
+pub fn verify_range(bytes: &[u8]) -> Result<Fp, ProofError> {
+    let malformed = |msg: &str| ProofError::Parse(msg.to_string());
+    if bytes.len() < 32 {
+        return Err(malformed("missing commitment"));
+    }
+    let (repr, proof) = bytes.split_at(32);
+    let mut commitment = <Fp as PrimeField>::Repr::default();
+    commitment.as_mut().copy_from_slice(repr);
+    let commitment = Option::<Fp>::from(Fp::from_repr(commitment))
+        .ok_or_else(|| malformed("commitment is not a field element"))?;
+
+    let verifier = Prover::from_circuit(RANGE_PROOF_K, &RangeProofCircuit::<Fp>::default())?;
+    verifier.verify(proof, &[vec![commitment]])?;
+    Ok(commitment)
+}
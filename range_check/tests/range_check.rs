@@ -0,0 +1,82 @@
+//! range_check 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use range_check::{
+    prove::{prove_range, verify_range},
+    RangeCheckCircuit,
+};
+use simple::{dev, prover, prover::ProofError};
+
+/// 表中有 256 行，电路至少需要 2^9 行
+const K: u32 = 9;
+
+/// 2 段 limb：0 <= value < 2^16；-1 是域中很大的数，不在范围中
+#[test]
+fn two_limbs() {
+    let check = |value: Fp| {
+        let circuit = RangeCheckCircuit {
+            value: Value::known(value),
+            num_limbs: 2,
+        };
+        MockProver::run(K, &circuit, vec![]).unwrap().verify()
+    };
+    for (value, ok) in [
+        (0u64, true),
+        (65535, true),
+        (65536, false),
+        (1 << 20, false),
+    ] {
+        assert_eq!(check(Fp::from(value)).is_ok(), ok, "value = {}", value);
+    }
+    assert!(check(-Fp::one()).is_err());
+}
+
+/// 端到端的范围证明：验证者只用序列化的 commitment 和证明验证；篡改证明或 commitment 时验证失败
+#[test]
+fn serialized_range_proof() {
+    let blinding = Fp::from(987654321);
+    let bytes = prove_range(40000, blinding).unwrap();
+    let commitment = verify_range(&bytes).unwrap();
+    assert_eq!(commitment, commitment::commit(Fp::from(40000), blinding));
+
+    let mut tampered_proof = bytes.clone();
+    *tampered_proof.last_mut().unwrap() ^= 1;
+    assert!(verify_range(&tampered_proof).is_err());
+    let mut tampered_commitment = bytes.clone();
+    tampered_commitment[0] ^= 1;
+    assert!(verify_range(&tampered_commitment).is_err());
+    // 字节太少或 commitment 不是域元素时返回 Parse，不会进行验证
+    assert!(matches!(
+        verify_range(&bytes[..16]),
+        Err(ProofError::Parse(_))
+    ));
+    let mut not_a_field_element = bytes.clone();
+    not_a_field_element[..32].fill(0xff);
+    assert!(matches!(
+        verify_range(&not_a_field_element),
+        Err(ProofError::Parse(_))
+    ));
+}
+
+/// 不在范围中的值无法得到能通过验证的证明
+#[test]
+fn out_of_range_proof_fails() {
+    let res = prove_range(1 << 16, Fp::from(987654321)).and_then(|bytes| verify_range(&bytes));
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<RangeCheckCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = RangeCheckCircuit {
+        value: Value::known(Fp::from(65535)),
+        num_limbs: 2,
+    };
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[]).is_ok());
+}
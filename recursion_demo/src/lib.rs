@@ -0,0 +1,107 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::witness::RandomWitness;
+use simple::{prover::DescribeInstance, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// “模拟递归”的教学示例：外层电路把内层计算的结果当作 witness，重新计算一遍并检查
+///
+/// 注意：这不是密码学意义上的递归证明。真正的递归需要在外层电路中实现内层证明的验证算法
+/// （多标量乘法、transcript 哈希等），外层证明才能代替内层证明；这里外层电路只是重新执行了内层的计算，
+/// 内层证明本身并没有在电路中被验证，电路规模也不会因此变小。
+/// 它演示的是组合的模式：内层的输出作为外层的输入，外层用拷贝约束把“声称的结果”和“重新计算的结果”绑定在一起。
+///
+/// 内层计算为 SimpleCircuit：c = constant * a^2 * b^2。外层电路：
+/// - private：a、b，以及内层给出的结果 inner_result
+/// - 重新计算 c' = constant * (a * b)^2，约束 c' = inner_result
+/// - public：inner_result
+#[derive(Default)]
+pub struct RecursionDemoCircuit<F: PrimeField> {
+    pub constant: F,
+    pub a: Value<F>,
+    pub b: Value<F>,
+    /// 内层计算声称的结果（例如内层证明的 public input）
+    pub inner_result: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for RecursionDemoCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// constant 写入 fixed 列，属于电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            constant: self.constant,
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let claimed = chip.load_private(
+            layouter.namespace(|| "load inner result"),
+            self.inner_result,
+        )?;
+
+        // 重新计算内层的 c = constant * (a * b)^2
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+        let ab = chip.mul(layouter.namespace(|| "a * b"), a, b)?;
+        let ab2 = chip.mul(layouter.namespace(|| "(a * b)^2"), ab.clone(), ab)?;
+        let derived = chip.mul_by_constant(
+            layouter.namespace(|| "constant * (a * b)^2"),
+            ab2,
+            self.constant,
+        )?;
+
+        // 声称的结果必须等于重新计算的结果
+        layouter.assign_region(
+            || "check inner result",
+            |mut region| region.constrain_equal(claimed.inner().cell(), derived.inner().cell()),
+        )?;
+
+        chip.expose_public(layouter.namespace(|| "expose inner result"), claimed, 0)
+    }
+}
+
+/// public input 为内层的结果
+impl<F: PrimeField> DescribeInstance for RecursionDemoCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// constant = 2（与示例 main 相同），a、b 为随机的域元素，内层结果是诚实计算的 constant * (a * b)^2
+impl RandomWitness for RecursionDemoCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let constant = Fp::from(2);
+        let a = Fp::random(&mut *rng);
+        let b = Fp::random(&mut *rng);
+        let c = constant * (a * b).square();
+        let circuit = Self {
+            constant,
+            a: Value::known(a),
+            b: Value::known(b),
+            inner_result: Value::known(c),
+        };
+        (circuit, vec![vec![c]])
+    }
+}
@@ -0,0 +1,49 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use recursion_demo::RecursionDemoCircuit;
+use simple::report;
+use simple::SimpleCircuit;
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(
+        row,
+        &RecursionDemoCircuit {
+            constant: Fp::from(2),
+            a: Value::known(Fp::from(2)),
+            b: Value::known(Fp::from(3)),
+            inner_result: Value::known(Fp::from(72)),
+        },
+        vec![vec![Fp::from(72)]],
+    ) {
+        return;
+    }
+    println!("Hello, this is halo2 example: mock recursion...");
+
+    // 内层：SimpleCircuit 证明 c = 2 * 2^2 * 3^2 = 72
+    let (inner, inner_public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let inner_res = MockProver::run(row, &inner, vec![inner_public.clone()])
+        .unwrap()
+        .verify();
+    println!("inner: {:?}", inner_res);
+    let inner_result = inner_public[0];
+
+    // 外层：把内层的结果作为 witness 重新计算并检查
+    let outer = |claimed: Fp| RecursionDemoCircuit {
+        constant: Fp::from(2),
+        a: Value::known(Fp::from(2)),
+        b: Value::known(Fp::from(3)),
+        inner_result: Value::known(claimed),
+    };
+    let prover = MockProver::run(row, &outer(inner_result), vec![vec![inner_result]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 篡改内层的结果：即使 public input 与篡改后的结果一致，重新计算的检查也不通过
+    let tampered = inner_result + Fp::one();
+    let prover_2 = MockProver::run(row, &outer(tampered), vec![vec![tampered]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
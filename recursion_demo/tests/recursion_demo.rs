@@ -0,0 +1,55 @@
+//! recursion_demo 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use recursion_demo::RecursionDemoCircuit;
+use simple::{dev, prover, SimpleCircuit};
+
+const K: u32 = 5;
+
+/// 外层电路：把内层的结果作为 witness 重新计算并检查
+fn outer(claimed: Fp) -> RecursionDemoCircuit<Fp> {
+    RecursionDemoCircuit {
+        constant: Fp::from(2),
+        a: Value::known(Fp::from(2)),
+        b: Value::known(Fp::from(3)),
+        inner_result: Value::known(claimed),
+    }
+}
+
+/// 内层 SimpleCircuit 证明 c = 2 * 2^2 * 3^2 = 72，外层以 72 为 witness 验证成功
+#[test]
+fn inner_and_outer() {
+    let (inner, inner_public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let res = MockProver::run(K, &inner, vec![inner_public.clone()])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+
+    let inner_result = inner_public[0];
+    let res = MockProver::run(K, &outer(inner_result), vec![vec![inner_result]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 篡改内层的结果：即使 public input 与篡改后的结果一致，重新计算的检查也不通过
+#[test]
+fn tampered_inner_result_fails() {
+    let tampered = Fp::from(73);
+    let res = MockProver::run(K, &outer(tampered), vec![vec![tampered]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<RecursionDemoCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    assert!(prover::roundtrip_in_memory(K, &outer(Fp::from(72)), &[vec![Fp::from(72)]]).is_ok());
+}
@@ -0,0 +1,245 @@
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// region 复用示例：计算 x_0 * x_1 * ... * x_{n-1}，对比两种布局方式
+///
+/// 1）每个操作一个 region（PerOp）：和 simple 中的指令一样，load_private、mul 各自打开一个新的 region，
+///    输入需要先单独加载一行，再通过拷贝约束复制到 mul 的 region 中，共 n + (n - 1) 行
+/// 2）共享一个 region（Shared）：在同一个 region 中用 offset 依次排列所有乘法，
+///    private input 直接写在乘法所在的行，上一行的 out 通过拷贝约束作为下一行的 lhs，共 n - 1 行
+///
+/// namespace 的命名约定：用“动作 + 对象”的短语描述这一步做了什么，循环中带上下标，
+/// 例如 "load x0"、"x0 * x1"，MockProver 报错时可以直接根据名字定位到出错的步骤
+///
+/// | lhs | rhs | out | s_mul |
+/// |-----|-----|-----|-------|
+/// | a   | b   | a*b | 1     |
+#[derive(Clone, Debug)]
+pub struct ProductConfig {
+    lhs: Column<Advice>,
+    rhs: Column<Advice>,
+    out: Column<Advice>,
+    instance: Column<Instance>,
+    s_mul: Selector,
+}
+
+/// 选择电路使用哪种布局
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RegionLayout {
+    /// 每个操作一个 region
+    #[default]
+    PerOp,
+    /// 所有操作共享一个 region
+    Shared,
+}
+
+pub struct ProductChip<F: Field> {
+    config: ProductConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> ProductChip<F> {
+    pub fn construct(config: ProductConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+    ) -> ProductConfig {
+        let [lhs, rhs, out] = advice;
+        meta.enable_equality(instance);
+        for c in advice {
+            meta.enable_equality(c);
+        }
+
+        let s_mul = meta.selector();
+        meta.create_gate("mul", |meta| {
+            let lhs = meta.query_advice(lhs, Rotation::cur());
+            let rhs = meta.query_advice(rhs, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+            let s_mul = meta.query_selector(s_mul);
+            vec![s_mul * (lhs * rhs - out)]
+        });
+
+        ProductConfig {
+            lhs,
+            rhs,
+            out,
+            instance,
+            s_mul,
+        }
+    }
+
+    /// PerOp：单独用一个 region 加载 private input
+    pub fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        x: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "load_private",
+            |mut region| region.assign_advice(|| "x", self.config.lhs, 0, || x),
+        )
+    }
+
+    /// PerOp：单独用一个 region 做一次乘法，输入通过拷贝约束复制进来
+    pub fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "mul",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+                a.copy_advice(|| "lhs", &mut region, config.lhs, 0)?;
+                b.copy_advice(|| "rhs", &mut region, config.rhs, 0)?;
+                let res = a.value().copied() * b.value();
+                region.assign_advice(|| "lhs * rhs", config.out, 0, || res)
+            },
+        )
+    }
+
+    /// Shared：在一个 region 中完成所有乘法，第 i 行计算 acc * x_{i+1}；输入少于 2 个时返回 Error::Synthesis
+    pub fn product_in_one_region(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Value<F>],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        if xs.len() < 2 {
+            return Err(Error::Synthesis);
+        }
+        let config = &self.config;
+        layouter.assign_region(
+            || "product",
+            |mut region| {
+                let mut acc = region.assign_advice(|| "x0", config.lhs, 0, || xs[0])?;
+                for (offset, x) in xs[1..].iter().enumerate() {
+                    config.s_mul.enable(&mut region, offset)?;
+                    // 第0行的 lhs 就是 x0；之后每一行把上一行的 out 复制为 lhs
+                    if offset > 0 {
+                        acc = acc.copy_advice(|| "acc", &mut region, config.lhs, offset)?;
+                    }
+                    region.assign_advice(
+                        || format!("x{}", offset + 1),
+                        config.rhs,
+                        offset,
+                        || *x,
+                    )?;
+                    let res = acc.value().copied() * x;
+                    acc = region.assign_advice(|| "acc * x", config.out, offset, || res)?;
+                }
+                Ok(acc)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+        row: usize,
+    ) -> Result<(), Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    }
+}
+
+/// 计算所有输入的乘积并导出到 instance 列的第0行，layout 决定使用哪种布局
+#[derive(Debug, Default)]
+pub struct ProductCircuit<F: Field> {
+    pub inputs: Vec<Value<F>>,
+    pub layout: RegionLayout,
+}
+
+impl<F: Field> Circuit<F> for ProductCircuit<F> {
+    type Config = ProductConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 输入个数和布局方式决定了电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            inputs: vec![Value::unknown(); self.inputs.len()],
+            layout: self.layout,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let instance = meta.instance_column();
+        ProductChip::configure(meta, advice, instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // 两种布局都至少需要 2 个输入，在赋值之前统一检查
+        if self.inputs.len() < 2 {
+            return Err(Error::Synthesis);
+        }
+        let chip = ProductChip::construct(config);
+        let product = match self.layout {
+            RegionLayout::PerOp => {
+                let xs = self
+                    .inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        chip.load_private(layouter.namespace(|| format!("load x{}", i)), *x)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut acc = xs[0].clone();
+                for (i, x) in xs[1..].iter().enumerate() {
+                    acc = chip.mul(layouter.namespace(|| format!("acc * x{}", i + 1)), &acc, x)?;
+                }
+                acc
+            }
+            RegionLayout::Shared => {
+                chip.product_in_one_region(layouter.namespace(|| "product of xs"), &self.inputs)?
+            }
+        };
+        chip.expose_public(layouter.namespace(|| "expose product"), &product, 0)
+    }
+}
+
+/// public input 为所有输入的乘积
+impl<F: Field> DescribeInstance for ProductCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 4 个随机的输入，使用共享 region 的布局（示例 main 的主电路）
+impl RandomWitness for ProductCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let inputs: Vec<Fp> = (0..4).map(|_| Fp::random(&mut *rng)).collect();
+        let product = inputs.iter().product();
+        let circuit = Self {
+            inputs: inputs.into_iter().map(Value::known).collect(),
+            layout: RegionLayout::Shared,
+        };
+        (circuit, vec![vec![product]])
+    }
+}
@@ -0,0 +1,43 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use region_reuse::{ProductCircuit, RegionLayout};
+use simple::layout::measure;
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // 2 * 3 * 4 * 5 = 120
+    let inputs: Vec<Value<Fp>> = [2u64, 3, 4, 5]
+        .iter()
+        .map(|x| Value::known(Fp::from(*x)))
+        .collect();
+    let public_input = vec![Fp::from(120)];
+
+    let per_op = ProductCircuit {
+        inputs: inputs.clone(),
+        layout: RegionLayout::PerOp,
+    };
+    let shared = ProductCircuit {
+        inputs,
+        layout: RegionLayout::Shared,
+    };
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &shared, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: region reuse...");
+
+    // 两种布局的结果相同，都能验证成功
+    for circuit in [&per_op, &shared] {
+        let prover = MockProver::run(row, circuit, vec![public_input.clone()]).unwrap();
+        let res = prover.verify();
+        println!("res ({:?}): {:?}", circuit.layout, res);
+    }
+
+    // PerOp：4 行加载 + 3 行乘法 = 7 行；Shared：3 行乘法
+    let per_op_rows = measure(&per_op).rows;
+    let shared_rows = measure(&shared).rows;
+    println!("rows: per-op = {}, shared = {}", per_op_rows, shared_rows);
+}
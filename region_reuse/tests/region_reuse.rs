@@ -0,0 +1,71 @@
+//! region_reuse 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp, plonk::Error};
+use region_reuse::{ProductCircuit, RegionLayout};
+use simple::{dev, layout::measure, prover};
+
+const K: u32 = 5;
+
+/// 2 * 3 * 4 * 5 = 120
+fn circuit(layout: RegionLayout) -> ProductCircuit<Fp> {
+    ProductCircuit {
+        inputs: [2, 3, 4, 5].map(|x| Value::known(Fp::from(x))).to_vec(),
+        layout,
+    }
+}
+
+/// 两种布局的结果相同，都能验证成功；错误的 public input 验证失败
+#[test]
+fn layouts_agree() {
+    for layout in [RegionLayout::PerOp, RegionLayout::Shared] {
+        let circuit = circuit(layout);
+        let res = MockProver::run(K, &circuit, vec![vec![Fp::from(120)]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "{:?}", circuit.layout);
+        let res = MockProver::run(K, &circuit, vec![vec![Fp::from(121)]])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "{:?}", circuit.layout);
+    }
+}
+
+/// PerOp：4 行加载 + 3 行乘法 = 7 行；Shared：3 行乘法
+#[test]
+fn shared_region_saves_rows() {
+    assert_eq!(measure(&circuit(RegionLayout::PerOp)).rows, 7);
+    assert_eq!(measure(&circuit(RegionLayout::Shared)).rows, 3);
+}
+
+/// 两种布局在输入少于 2 个时都返回 Synthesis 错误，而不是 panic
+#[test]
+fn too_few_inputs() {
+    for layout in [RegionLayout::PerOp, RegionLayout::Shared] {
+        for n in [0, 1] {
+            let circuit = ProductCircuit {
+                inputs: vec![Value::known(Fp::one()); n],
+                layout,
+            };
+            let res = MockProver::run(K, &circuit, vec![vec![Fp::one()]]);
+            assert!(
+                matches!(res, Err(Error::Synthesis)),
+                "{:?}, {} input(s)",
+                circuit.layout,
+                n
+            );
+        }
+    }
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ProductCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = circuit(RegionLayout::Shared);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![Fp::from(120)]]).is_ok());
+}
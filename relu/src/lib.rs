@@ -0,0 +1,90 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::witness::RandomWitness;
+use simple::{field::signed_to_fp, prover::DescribeInstance};
+use simple::{BitInstructions, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 分段线性函数示例：证明 y = relu(x) = max(x, 0)，x 为 private 的有符号整数，y 作为 public input 公开
+/// 负数 -a 在域中为 p - a（见 simple::field::signed_to_fp），不能直接比较大小，这里通过二进制分解判断符号：
+/// - x 的范围为 [-2^(NUM_BITS-1), 2^(NUM_BITS-1))，平移为 x + 2^(NUM_BITS-1)，落在 [0, 2^NUM_BITS) 中；
+/// - 把平移后的数分解为 NUM_BITS 位，最高位为 1 当且仅当 x >= 0；
+/// - 用 select 指令：最高位为 1 时选择 x，否则选择 0。
+///
+/// 分解同时约束了 x 在范围中。
+///
+/// x 的位数（包括符号）
+pub const NUM_BITS: usize = 8;
+
+/// 电路外计算 relu，用于得到正确的 public input
+pub fn relu(x: i64) -> i64 {
+    x.max(0)
+}
+
+#[derive(Default)]
+pub struct ReluCircuit<F: PrimeField> {
+    pub x: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for ReluCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let offset = F::from(1 << (NUM_BITS - 1));
+        let shifted = chip.linear_combination(
+            layouter.namespace(|| "x + 2^(NUM_BITS-1)"),
+            std::slice::from_ref(&x),
+            &[F::ONE],
+            offset,
+        )?;
+        let bits = chip.to_bits(layouter.namespace(|| "sign"), shifted, NUM_BITS)?;
+        let non_negative = bits[NUM_BITS - 1].clone();
+        let zero = chip.load_constant(layouter.namespace(|| "zero"), F::ZERO)?;
+        let y = chip.select(layouter.namespace(|| "max(x, 0)"), non_negative, x, zero)?;
+        chip.expose_public(layouter.namespace(|| "expose y"), y, 0)
+    }
+}
+
+/// public input 为 relu(x)
+impl<F: PrimeField> DescribeInstance for ReluCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// x 为 NUM_BITS 位有符号整数范围内的随机值，public input 为 relu(x)
+impl RandomWitness for ReluCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let half = 1 << (NUM_BITS - 1);
+        let x = (rng.next_u64() % (2 * half)) as i64 - half as i64;
+        let circuit = Self {
+            x: Value::known(signed_to_fp(x)),
+        };
+        (circuit, vec![vec![signed_to_fp(relu(x))]])
+    }
+}
@@ -0,0 +1,26 @@
+use halo2_proofs::{circuit::Value, dev::MockProver};
+use relu::{relu, ReluCircuit};
+use simple::{field::signed_to_fp, report};
+
+fn main() {
+    // 定义电路的行数
+    let row = 6;
+
+    let circuit = |x: i64| ReluCircuit {
+        x: Value::known(signed_to_fp(x)),
+    };
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit(-5), vec![vec![signed_to_fp(0)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: relu...");
+
+    // 正数、负数、0，以及范围的两端
+    for x in [42, -5, 0, 127, -128] {
+        let y = signed_to_fp(relu(x));
+        let prover = MockProver::run(row, &circuit(x), vec![vec![y]]).unwrap();
+        let res = prover.verify();
+        println!("res (relu({})): {:?}", x, res);
+    }
+}
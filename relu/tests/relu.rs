@@ -0,0 +1,48 @@
+//! relu 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use relu::{relu, ReluCircuit};
+use simple::{dev, field::signed_to_fp, prover};
+
+const K: u32 = 6;
+
+fn circuit(x: i64) -> ReluCircuit<Fp> {
+    ReluCircuit {
+        x: Value::known(signed_to_fp(x)),
+    }
+}
+
+/// 正数、负数、0，以及范围的两端
+#[test]
+fn relu_in_range() {
+    for x in [42, -5, 0, 127, -128] {
+        let y = signed_to_fp(relu(x));
+        let res = MockProver::run(K, &circuit(x), vec![vec![y]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "relu({})", x);
+    }
+}
+
+/// 负数的输出不是 0，或者 x 超出范围无法分解时，验证失败
+#[test]
+fn wrong_output_or_out_of_range_fails() {
+    for x in [-5, 128] {
+        let res = MockProver::run(K, &circuit(x), vec![vec![signed_to_fp(x)]])
+            .unwrap()
+            .verify();
+        assert!(res.is_err(), "x = {}", x);
+    }
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ReluCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    assert!(prover::roundtrip_in_memory(K, &circuit(-5), &[vec![signed_to_fp(0)]]).is_ok());
+}
@@ -0,0 +1,195 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// 负偏移（Rotation::prev()）示例：证明 private 的序列 x 的相邻差分 d_i = x_i - x_{i-1}
+/// 门向后看一行：在第 i 行用 Rotation::prev() 查询 x_{i-1}，约束 x_i - x_{i-1} - d_i = 0。
+///
+/// | x   | d   | s_diff |
+/// |-----|-----|--------|
+/// | x_0 |     | 0      |  <- 第一行没有上一行，不能启用 s_diff
+/// | x_1 | d_1 | 1      |
+/// | ... | ... | ...    |
+///
+/// 边界问题：偏移是相对于整个电路的行，而不是相对于 region 的。
+/// - 在 region 的第一行启用 s_diff，prev 查询的是上一个 region 的最后一行，与本 region 无关；
+/// - 在电路的第 0 行启用，prev 会绕回到第 n - 1 行（多项式在乘法子群上循环），那里是 halo2 填充随机值的盲化行。
+///
+/// 所以选择器只在第 1 行之后启用，第 0 行的 x_0 只作为 x_1 的“上一行”被查询。
+#[derive(Clone, Debug)]
+pub struct DiffConfig {
+    x: Column<Advice>,
+    d: Column<Advice>,
+    instance: Column<Instance>,
+    s_diff: Selector,
+}
+
+pub struct DiffChip<F: PrimeField> {
+    config: DiffConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> DiffChip<F> {
+    pub fn construct(config: DiffConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        x: Column<Advice>,
+        d: Column<Advice>,
+        instance: Column<Instance>,
+    ) -> DiffConfig {
+        meta.enable_equality(d);
+        meta.enable_equality(instance);
+        let s_diff = meta.selector();
+
+        meta.create_gate("diff", |meta| {
+            let s = meta.query_selector(s_diff);
+            let prev = meta.query_advice(x, Rotation::prev());
+            let cur = meta.query_advice(x, Rotation::cur());
+            let d = meta.query_advice(d, Rotation::cur());
+            vec![s * (cur - prev - d)]
+        });
+
+        DiffConfig {
+            x,
+            d,
+            instance,
+            s_diff,
+        }
+    }
+
+    /// 在一个 region 中赋值序列和差分，返回 d_1, ..., d_{n-1}
+    /// from_first_row 为 true 时也在第 0 行启用 s_diff（错误的用法，用来演示边界问题），
+    /// 此时第 0 行的 d 赋值为 x_0
+    pub fn assign_diffs(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Value<F>],
+        from_first_row: bool,
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "diffs",
+            |mut region| {
+                let mut diffs = vec![];
+                for (i, x) in xs.iter().enumerate() {
+                    region.assign_advice(|| format!("x_{}", i), config.x, i, || *x)?;
+                    if i == 0 {
+                        if from_first_row {
+                            config.s_diff.enable(&mut region, 0)?;
+                            region.assign_advice(|| "d_0", config.d, 0, || *x)?;
+                        }
+                        continue;
+                    }
+                    config.s_diff.enable(&mut region, i)?;
+                    let d = xs[i - 1].zip(*x).map(|(prev, cur)| cur - prev);
+                    diffs.push(region.assign_advice(|| format!("d_{}", i), config.d, i, || d)?);
+                }
+                Ok(diffs)
+            },
+        )
+    }
+
+    pub fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: &AssignedCell<F, F>,
+        row: usize,
+    ) -> Result<(), Error> {
+        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+    }
+}
+
+/// 电路外计算相邻差分
+pub fn diffs<F: PrimeField>(xs: &[F]) -> Vec<F> {
+    xs.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// private 的序列 x，差分 d_1, ..., d_{n-1} 依次作为 public input
+pub struct DiffCircuit<F: PrimeField> {
+    pub xs: Vec<Value<F>>,
+    /// 是否在第 0 行启用 s_diff，见 DiffChip::assign_diffs
+    pub from_first_row: bool,
+}
+
+impl<F: PrimeField> DiffCircuit<F> {
+    pub fn new(xs: &[F]) -> Self {
+        assert!(
+            xs.len() >= 2,
+            "need at least two values to take a difference"
+        );
+        Self {
+            xs: xs.iter().map(|x| Value::known(*x)).collect(),
+            from_first_row: false,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DiffCircuit<F> {
+    type Config = DiffConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            xs: vec![Value::unknown(); self.xs.len()],
+            from_first_row: self.from_first_row,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let d = meta.advice_column();
+        let instance = meta.instance_column();
+        DiffChip::configure(meta, x, d, instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = DiffChip::construct(config);
+        let diffs = chip.assign_diffs(
+            layouter.namespace(|| "assign diffs"),
+            &self.xs,
+            self.from_first_row,
+        )?;
+        for (row, d) in diffs.iter().enumerate() {
+            chip.expose_public(
+                layouter.namespace(|| format!("expose d_{}", row + 1)),
+                d,
+                row,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// 每个差分占 instance 列的一行
+impl<F: PrimeField> DescribeInstance for DiffCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.xs.len() - 1]
+    }
+}
+
+/// 4 个随机的 x（与示例 main 相同的长度），public input 为它们的差分
+impl RandomWitness for DiffCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let xs: Vec<Fp> = (0..4).map(|_| Fp::random(&mut *rng)).collect();
+        (Self::new(&xs), vec![diffs(&xs)])
+    }
+}
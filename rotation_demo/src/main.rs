@@ -0,0 +1,39 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use rotation_demo::{diffs, DiffCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    // x = [3, 5, 10, 9]，d = [2, 5, -1]
+    let xs = [3, 5, 10, 9].map(Fp::from);
+    let public_input = diffs(&xs);
+    let circuit = DiffCircuit::new(&xs);
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: negative rotation...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 修改一个差分，验证失败
+    let mut public_input_2 = public_input.clone();
+    public_input_2[1] += Fp::one();
+    let prover_2 = MockProver::run(row, &circuit, vec![public_input_2]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // 边界：在第 0 行启用门，prev 绕回到电路的最后一行（盲化行）。
+    // MockProver 把这一行当作未赋值的单元格，报告 CellNotAssigned，而不是静默地用盲化行的值计算
+    let circuit_3 = DiffCircuit {
+        from_first_row: true,
+        ..DiffCircuit::new(&xs)
+    };
+    let prover_3 = MockProver::run(row, &circuit_3, vec![public_input]).unwrap();
+    let res_3 = prover_3.verify();
+    println!("res3 (gate enabled at row 0): {:?}", res_3);
+}
@@ -0,0 +1,62 @@
+//! rotation_demo 示例电路的测试
+
+use halo2_proofs::{
+    dev::{MockProver, VerifyFailure},
+    pasta::Fp,
+};
+use rotation_demo::{diffs, DiffCircuit};
+use simple::{dev, prover};
+
+const K: u32 = 4;
+
+/// x = [3, 5, 10, 9]
+fn xs() -> [Fp; 4] {
+    [3, 5, 10, 9].map(Fp::from)
+}
+
+/// d = [2, 5, -1]，验证成功；修改一个差分，验证失败
+#[test]
+fn differences() {
+    let circuit = DiffCircuit::new(&xs());
+    let public_input = diffs(&xs());
+    assert_eq!(public_input[2], -Fp::one());
+    let res = MockProver::run(K, &circuit, vec![public_input.clone()])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+
+    let mut wrong = public_input;
+    wrong[1] += Fp::one();
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 边界：在第 0 行启用门，prev 绕回到电路的最后一行（盲化行）。
+/// MockProver 把这一行当作未赋值的单元格，报告 CellNotAssigned，而不是静默地用盲化行的值计算
+#[test]
+fn gate_at_first_row_wraps_around() {
+    let circuit = DiffCircuit {
+        from_first_row: true,
+        ..DiffCircuit::new(&xs())
+    };
+    let failures = MockProver::run(K, &circuit, vec![diffs(&xs())])
+        .unwrap()
+        .verify()
+        .unwrap_err();
+    assert!(failures
+        .iter()
+        .any(|failure| matches!(failure, VerifyFailure::CellNotAssigned { .. })));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<DiffCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = DiffCircuit::new(&xs());
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[diffs(&xs())]).is_ok());
+}
@@ -0,0 +1,96 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use simple::{BitInstructions, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 域乘法群中的“标量乘法”示例：证明知道 private 的标量 s，使得 y = g^s
+/// 这是椭圆曲线标量乘法 s * G 的类比（不涉及曲线运算）：曲线上的“倍点-加点”对应乘法群中的“平方-乘”。
+/// - g：电路外固定的底数，用 load_constant 加载到 fixed 列
+/// - s：private input，用 to_bits 分解为 SCALAR_BITS 个布尔值（低位在前），同时约束 s < 2^SCALAR_BITS
+/// - y：public input，由 pow_var 从最高位开始逐位平方，并根据该位选择是否乘以 g
+///
+/// 从 y 求 s 即为离散对数问题。
+///
+/// 标量的位数
+pub const SCALAR_BITS: usize = 8;
+
+/// 电路外计算 g^s，用于得到正确的 public input
+pub fn scalar_mul<F: PrimeField>(base: F, scalar: u64) -> F {
+    base.pow_vartime([scalar])
+}
+
+pub struct ScalarMulCircuit<F: PrimeField> {
+    /// 底数，属于电路结构
+    pub base: F,
+    pub scalar: Value<F>,
+}
+
+impl<F: PrimeField> ScalarMulCircuit<F> {
+    pub fn new(base: F, scalar: u64) -> Self {
+        Self {
+            base,
+            scalar: Value::known(F::from(scalar)),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ScalarMulCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 底数决定了 fixed 列中的常数，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            base: self.base,
+            scalar: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let base = chip.load_constant(layouter.namespace(|| "load g"), self.base)?;
+        let scalar = chip.load_private(layouter.namespace(|| "load s"), self.scalar)?;
+        let bits = chip.to_bits(layouter.namespace(|| "bits of s"), scalar, SCALAR_BITS)?;
+        let y = chip.pow_var(layouter.namespace(|| "g^s"), base, &bits)?;
+        chip.expose_public(layouter.namespace(|| "expose y"), y, 0)
+    }
+}
+
+/// public input 为 y = g^s
+impl<F: PrimeField> DescribeInstance for ScalarMulCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 底数为随机的域元素，标量为随机的 SCALAR_BITS 位整数
+impl RandomWitness for ScalarMulCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let base = Fp::random(&mut *rng);
+        let scalar = rng.next_u64() % (1 << SCALAR_BITS);
+        (
+            Self::new(base, scalar),
+            vec![vec![scalar_mul(base, scalar)]],
+        )
+    }
+}
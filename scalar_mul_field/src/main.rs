@@ -0,0 +1,28 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use scalar_mul_field::{scalar_mul, ScalarMulCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 7;
+
+    // g = 3，s = 13 = 0b1101，y = 3^13 = 1594323
+    let base = Fp::from(3);
+    let y = scalar_mul(base, 13);
+
+    let circuit = ScalarMulCircuit::new(base, 13);
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![y]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: scalar multiplication in the field...");
+
+    let prover = MockProver::run(row, &circuit, vec![vec![y]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 公开的 y 与标量不对应，验证失败
+    let prover_2 = MockProver::run(row, &circuit, vec![vec![scalar_mul(base, 12)]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
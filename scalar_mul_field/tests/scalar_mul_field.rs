@@ -0,0 +1,41 @@
+//! scalar_mul_field 示例电路的测试
+
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use scalar_mul_field::{scalar_mul, ScalarMulCircuit};
+use simple::{dev, prover};
+
+const K: u32 = 7;
+
+/// g = 3，s = 13 = 0b1101，y = 3^13 = 1594323，以及最大的标量 s = 255；公开的 y 与标量不对应时验证失败
+#[test]
+fn scalar_mul_matches() {
+    let base = Fp::from(3);
+    assert_eq!(scalar_mul(base, 13), Fp::from(1594323));
+    for scalar in [13, 255] {
+        let circuit = ScalarMulCircuit::new(base, scalar);
+        let res = MockProver::run(K, &circuit, vec![vec![scalar_mul(base, scalar)]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "s = {}", scalar);
+    }
+
+    let circuit = ScalarMulCircuit::new(base, 13);
+    let res = MockProver::run(K, &circuit, vec![vec![scalar_mul(base, 12)]])
+        .unwrap()
+        .verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ScalarMulCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let base = Fp::from(3);
+    let circuit = ScalarMulCircuit::new(base, 13);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![scalar_mul(base, 13)]]).is_ok());
+}
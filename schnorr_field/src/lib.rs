@@ -0,0 +1,112 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::witness::RandomWitness;
+use simple::{prover::DescribeInstance, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// Schnorr 知识证明的结构示例：用域的加法群 (F, +) 代替椭圆曲线群，生成元为常数 G
+/// - 私钥 x，公钥 P = x * G；
+/// - 证明者选择随机数 r，承诺 R = r * G；
+/// - 验证者给出挑战 e，证明者回复 s = r + e * x；
+/// - 验证者检查 s * G = R + e * P。
+///
+/// 电路证明证明者知道 x、r，使得 P、R 是对应的公钥和承诺，且回复 s = r + e * x。
+/// 注意：加法群中的离散对数是平凡的（x = P / G），这里只演示协议的结构，不提供任何安全性。
+///
+/// instance 列：
+///
+/// | row | 0 | 1 | 2 | 3 |
+/// |-----|---|---|---|---|
+/// |     | e | P | R | s |
+///
+/// 生成元 G
+pub const GENERATOR: u64 = 5;
+
+/// 电路外计算 public input：[e, P, R, s]
+pub fn schnorr_public<F: PrimeField>(x: F, r: F, e: F) -> Vec<F> {
+    let g = F::from(GENERATOR);
+    vec![e, x * g, r * g, r + e * x]
+}
+
+/// 验证者在电路外的检查：s * G = R + e * P
+pub fn schnorr_verify<F: PrimeField>(public: &[F]) -> bool {
+    let [e, p, r, s] = public else {
+        return false;
+    };
+    *s * F::from(GENERATOR) == *r + *e * p
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SchnorrCircuit<F: PrimeField> {
+    /// 私钥
+    pub x: Value<F>,
+    /// 随机数
+    pub r: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for SchnorrCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+        let g = F::from(GENERATOR);
+
+        let e = chip.load_instance(layouter.namespace(|| "load e"), 0)?;
+        let x = chip.load_private(layouter.namespace(|| "load x"), self.x)?;
+        let r = chip.load_private(layouter.namespace(|| "load r"), self.r)?;
+
+        let p = chip.mul_by_constant(layouter.namespace(|| "P = x * G"), x.clone(), g)?;
+        let big_r = chip.mul_by_constant(layouter.namespace(|| "R = r * G"), r.clone(), g)?;
+        let ex = chip.mul(layouter.namespace(|| "e * x"), e, x)?;
+        let s = chip.add(layouter.namespace(|| "s = r + e * x"), r, ex)?;
+
+        chip.expose_public(layouter.namespace(|| "expose P"), p, 1)?;
+        chip.expose_public(layouter.namespace(|| "expose R"), big_r, 2)?;
+        chip.expose_public(layouter.namespace(|| "expose s"), s, 3)
+    }
+}
+
+/// e、P、R、s 各占一行
+impl<F: PrimeField> DescribeInstance for SchnorrCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![4]
+    }
+}
+
+/// 随机的私钥 x、随机数 r 和挑战 e
+impl RandomWitness for SchnorrCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let (x, r, e) = (
+            Fp::random(&mut *rng),
+            Fp::random(&mut *rng),
+            Fp::random(&mut *rng),
+        );
+        let circuit = Self {
+            x: Value::known(x),
+            r: Value::known(r),
+        };
+        (circuit, vec![schnorr_public(x, r, e)])
+    }
+}
@@ -0,0 +1,46 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use schnorr_field::{schnorr_public, SchnorrCircuit};
+use simple::report;
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // 私钥 x = 42，随机数 r = 7，挑战 e = 3：P = 210，R = 35，s = 7 + 3 * 42 = 133
+    let (x, r, e) = (Fp::from(42), Fp::from(7), Fp::from(3));
+    let circuit = SchnorrCircuit {
+        x: Value::known(x),
+        r: Value::known(r),
+    };
+    let public_input = schnorr_public(x, r, e);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: schnorr-like proof in a field...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 回复 s 与 r + e * x 不一致
+    let mut wrong_s = public_input.clone();
+    wrong_s[3] += Fp::one();
+    let prover_2 = MockProver::run(row, &circuit, vec![wrong_s]).unwrap();
+    println!("res2 (wrong s): {:?}", prover_2.verify().is_ok());
+
+    // 换一个挑战但沿用旧的回复
+    let mut wrong_e = public_input.clone();
+    wrong_e[0] = Fp::from(4);
+    let prover_3 = MockProver::run(row, &circuit, vec![wrong_e]).unwrap();
+    println!("res3 (wrong e): {:?}", prover_3.verify().is_ok());
+
+    // 不知道私钥：用另一个 x 无法得到同样的公钥
+    let forged = SchnorrCircuit {
+        x: Value::known(Fp::from(43)),
+        r: Value::known(r),
+    };
+    let prover_4 = MockProver::run(row, &forged, vec![public_input]).unwrap();
+    println!("res4 (wrong x): {:?}", prover_4.verify().is_ok());
+}
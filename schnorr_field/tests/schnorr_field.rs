@@ -0,0 +1,68 @@
+//! schnorr_field 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use schnorr_field::{schnorr_public, schnorr_verify, SchnorrCircuit};
+use simple::{dev, layout, prover};
+
+const K: u32 = 5;
+
+/// 私钥 x = 42，随机数 r = 7
+fn circuit(x: u64) -> SchnorrCircuit<Fp> {
+    SchnorrCircuit {
+        x: Value::known(Fp::from(x)),
+        r: Value::known(Fp::from(7)),
+    }
+}
+
+fn verify(circuit: &SchnorrCircuit<Fp>, public_input: Vec<Fp>) -> bool {
+    MockProver::run(K, circuit, vec![public_input])
+        .unwrap()
+        .verify()
+        .is_ok()
+}
+
+/// 挑战 e = 3：P = 210，R = 35，s = 7 + 3 * 42 = 133；同一个 x、r 回复另一个挑战时 s 随 e 变化
+#[test]
+fn honest_prover() {
+    let (x, r) = (Fp::from(42), Fp::from(7));
+    let public_input = schnorr_public(x, r, Fp::from(3));
+    assert_eq!(public_input[3], Fp::from(133));
+    assert!(schnorr_verify(&public_input));
+    assert!(layout::measure(&circuit(42)).k <= K);
+    assert!(verify(&circuit(42), public_input));
+    assert!(verify(&circuit(42), schnorr_public(x, r, Fp::from(11))));
+}
+
+/// 回复 s 与 r + e * x 不一致（电路外的验证方程也不成立），或者换一个挑战但沿用旧的回复，验证失败
+#[test]
+fn wrong_response_fails() {
+    let public_input = schnorr_public(Fp::from(42), Fp::from(7), Fp::from(3));
+    let mut wrong_s = public_input.clone();
+    wrong_s[3] += Fp::one();
+    assert!(!schnorr_verify(&wrong_s));
+    assert!(!verify(&circuit(42), wrong_s));
+
+    let mut wrong_e = public_input;
+    wrong_e[0] = Fp::from(4);
+    assert!(!verify(&circuit(42), wrong_e));
+}
+
+/// 不知道私钥：用另一个 x 无法得到同样的公钥
+#[test]
+fn wrong_secret_fails() {
+    let public_input = schnorr_public(Fp::from(42), Fp::from(7), Fp::from(3));
+    assert!(!verify(&circuit(43), public_input));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<SchnorrCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let public_input = schnorr_public(Fp::from(42), Fp::from(7), Fp::from(3));
+    assert!(prover::roundtrip_in_memory(K, &circuit(42), &[public_input]).is_ok());
+}
@@ -0,0 +1,198 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 共享选择器示例：一个选择器同时控制两个约束
+/// 每一行证明 sum = a + b 和 prod = a * b，sum、prod 作为 public input 依次公开（第 i 行为 2i、2i + 1）
+///
+/// | a | b | sum   | prod  | s |
+/// |---|---|-------|-------|---|
+/// | a | b | a + b | a * b | 1 |
+///
+/// SHARED = true 时，create_gate 返回两个表达式，都乘以同一个选择器 s：
+///     s * (a + b - sum) = 0
+///     s * (a * b - prod) = 0
+/// SHARED = false 时，两个约束分别放在两个门中，各自有一个选择器，两个选择器总是在同一行启用。
+///
+/// 选择器最终会被压缩成 fixed 列：只有从不在同一行启用的选择器才能合并到一列中，
+/// 所以分开的两个选择器占用两个 fixed 列，共享的选择器只占用一个，证明中也少一个 fixed 列的打开值。
+/// 两种写法的约束完全相同，同一行的两个约束总是一起生效。
+#[derive(Clone, Debug)]
+pub struct SharedSelectorConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    sum: Column<Advice>,
+    prod: Column<Advice>,
+    instance: Column<Instance>,
+    selectors: Vec<Selector>,
+}
+
+/// 一行的 witness：正常情况下由 SumProdRow::new 计算，也可以直接构造错误的 sum 或 prod 来演示约束失败
+#[derive(Clone, Copy, Debug)]
+pub struct SumProdRow<F: PrimeField> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+    pub sum: Value<F>,
+    pub prod: Value<F>,
+}
+
+impl<F: PrimeField> SumProdRow<F> {
+    pub fn new(a: F, b: F) -> Self {
+        Self {
+            a: Value::known(a),
+            b: Value::known(b),
+            sum: Value::known(a + b),
+            prod: Value::known(a * b),
+        }
+    }
+
+    fn unknown() -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            sum: Value::unknown(),
+            prod: Value::unknown(),
+        }
+    }
+}
+
+/// 电路外计算 public input：每一行依次为 a + b、a * b
+pub fn sums_and_products<F: PrimeField>(pairs: &[(F, F)]) -> Vec<F> {
+    pairs.iter().flat_map(|(a, b)| [*a + b, *a * b]).collect()
+}
+
+#[derive(Clone, Debug)]
+pub struct SharedSelectorCircuit<F: PrimeField, const SHARED: bool = true> {
+    pub rows: Vec<SumProdRow<F>>,
+}
+
+impl<F: PrimeField, const SHARED: bool> SharedSelectorCircuit<F, SHARED> {
+    pub fn new(pairs: &[(F, F)]) -> Self {
+        Self {
+            rows: pairs.iter().map(|(a, b)| SumProdRow::new(*a, *b)).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField, const SHARED: bool> Circuit<F> for SharedSelectorCircuit<F, SHARED> {
+    type Config = SharedSelectorConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            rows: vec![SumProdRow::unknown(); self.rows.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let sum = meta.advice_column();
+        let prod = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(sum);
+        meta.enable_equality(prod);
+        meta.enable_equality(instance);
+
+        let selectors = if SHARED {
+            let s = meta.selector();
+            meta.create_gate("sum_and_product", |meta| {
+                let s = meta.query_selector(s);
+                let a = meta.query_advice(a, Rotation::cur());
+                let b = meta.query_advice(b, Rotation::cur());
+                let sum = meta.query_advice(sum, Rotation::cur());
+                let prod = meta.query_advice(prod, Rotation::cur());
+                // 一个门返回多个表达式，每个表达式都是一个独立的约束
+                vec![
+                    s.clone() * (a.clone() + b.clone() - sum),
+                    s * (a * b - prod),
+                ]
+            });
+            vec![s]
+        } else {
+            let s_sum = meta.selector();
+            meta.create_gate("sum", |meta| {
+                let s = meta.query_selector(s_sum);
+                let a = meta.query_advice(a, Rotation::cur());
+                let b = meta.query_advice(b, Rotation::cur());
+                let sum = meta.query_advice(sum, Rotation::cur());
+                vec![s * (a + b - sum)]
+            });
+            let s_prod = meta.selector();
+            meta.create_gate("product", |meta| {
+                let s = meta.query_selector(s_prod);
+                let a = meta.query_advice(a, Rotation::cur());
+                let b = meta.query_advice(b, Rotation::cur());
+                let prod = meta.query_advice(prod, Rotation::cur());
+                vec![s * (a * b - prod)]
+            });
+            vec![s_sum, s_prod]
+        };
+
+        SharedSelectorConfig {
+            a,
+            b,
+            sum,
+            prod,
+            instance,
+            selectors,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let outputs = layouter.assign_region(
+            || "sum and product",
+            |mut region| {
+                let mut outputs = vec![];
+                for (offset, row) in self.rows.iter().enumerate() {
+                    for s in &config.selectors {
+                        s.enable(&mut region, offset)?;
+                    }
+                    region.assign_advice(|| "a", config.a, offset, || row.a)?;
+                    region.assign_advice(|| "b", config.b, offset, || row.b)?;
+                    outputs.push(region.assign_advice(|| "sum", config.sum, offset, || row.sum)?);
+                    outputs.push(region.assign_advice(
+                        || "prod",
+                        config.prod,
+                        offset,
+                        || row.prod,
+                    )?);
+                }
+                Ok(outputs)
+            },
+        )?;
+        for (row, cell) in outputs.iter().enumerate() {
+            layouter.constrain_instance(cell.cell(), config.instance, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// 每一行公开 sum 和 prod 两个数
+impl<F: PrimeField, const SHARED: bool> DescribeInstance for SharedSelectorCircuit<F, SHARED> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![2 * self.rows.len()]
+    }
+}
+
+/// 2 对随机的 (a, b)（与示例 main 相同的行数），共享与不共享 selector 的两种布局都适用
+impl<const SHARED: bool> RandomWitness for SharedSelectorCircuit<Fp, SHARED> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let pairs: Vec<(Fp, Fp)> = (0..2)
+            .map(|_| (Fp::random(&mut *rng), Fp::random(&mut *rng)))
+            .collect();
+        (Self::new(&pairs), vec![sums_and_products(&pairs)])
+    }
+}
@@ -0,0 +1,77 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use shared_selector::{sums_and_products, SharedSelectorCircuit};
+use simple::{
+    backend::{self, Backend},
+    dev, prover, report,
+};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    // (2, 3) -> 5, 6；(4, 5) -> 9, 20
+    let pairs = [(Fp::from(2), Fp::from(3)), (Fp::from(4), Fp::from(5))];
+    let circuit = SharedSelectorCircuit::<Fp>::new(&pairs);
+    let public_input = sums_and_products(&pairs);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: shared selector...");
+
+    // 默认用 MockProver 检查，带 --ipa 参数时生成真实的证明
+    let backend = backend::from_args();
+    let res_backend = backend.check(row, &circuit, std::slice::from_ref(&public_input));
+    println!("res ({} backend): {:?}", backend.name(), res_backend);
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 一个门中有两个约束，共用选择器 S0
+    let gates = dev::dump_gates::<SharedSelectorCircuit<Fp>>().unwrap();
+    println!("gates: {:?}", gates);
+
+    // 第 1 行的 sum 或 prod 错误时，同一个门中对应的约束（0 为 sum，1 为 prod）在同一行失败；
+    // public input 也换成错误的值，排除拷贝约束的失败
+    for wrong_sum in [true, false] {
+        let mut bad = circuit.clone();
+        let mut bad_public = public_input.clone();
+        if wrong_sum {
+            bad.rows[1].sum = Value::known(Fp::from(10));
+            bad_public[2] = Fp::from(10);
+        } else {
+            bad.rows[1].prod = Value::known(Fp::from(21));
+            bad_public[3] = Fp::from(21);
+        }
+        let res_wrong = MockProver::run(row, &bad, vec![bad_public])
+            .unwrap()
+            .verify();
+        println!(
+            "res (wrong {}): {:?}",
+            if wrong_sum { "sum" } else { "prod" },
+            res_wrong
+        );
+    }
+
+    // 两个约束分开放在两个门中时，验证结果相同
+    let separate = SharedSelectorCircuit::<Fp, false>::new(&pairs);
+    let prover_2 = MockProver::run(row, &separate, vec![public_input]).unwrap();
+    println!("res2 (separate selectors): {:?}", prover_2.verify());
+
+    // 选择器压缩后：共享的选择器只有一个 fixed 列，分开的两个选择器总在同一行启用，不能合并，需要两个
+    let shared_fixed = dev::measure_fixed_queries(row, &circuit).unwrap();
+    let separate_fixed = dev::measure_fixed_queries(row, &separate).unwrap();
+    println!(
+        "fixed queries: shared = {}, separate = {}",
+        shared_fixed, separate_fixed
+    );
+
+    let (prove_time, verify_time, verified) =
+        prover::prove_and_verify_timed(row, &circuit, &[sums_and_products(&pairs)]);
+    println!(
+        "timing (k = {}): prove {:?}, verify {:?}, verified {}",
+        row, prove_time, verify_time, verified
+    );
+}
@@ -0,0 +1,97 @@
+//! shared_selector 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use shared_selector::{sums_and_products, SharedSelectorCircuit};
+use simple::{dev, prover};
+
+const K: u32 = 4;
+
+/// (2, 3) -> 5, 6；(4, 5) -> 9, 20
+fn pairs() -> [(Fp, Fp); 2] {
+    [(Fp::from(2), Fp::from(3)), (Fp::from(4), Fp::from(5))]
+}
+
+/// 共享选择器和分开的两个选择器，验证结果相同
+#[test]
+fn shared_and_separate_agree() {
+    let public_input = sums_and_products(&pairs());
+    let shared = SharedSelectorCircuit::<Fp>::new(&pairs());
+    let res = MockProver::run(K, &shared, vec![public_input.clone()])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let separate = SharedSelectorCircuit::<Fp, false>::new(&pairs());
+    let res = MockProver::run(K, &separate, vec![public_input])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// 共享时一个门中有两个约束，共用选择器 S0；分开时有两个门
+#[test]
+fn gates() {
+    let gates = dev::dump_gates::<SharedSelectorCircuit<Fp>>().unwrap();
+    assert_eq!(gates.len(), 1);
+    assert!(gates[0].starts_with("sum_and_product: "));
+    assert_eq!(gates[0].matches("S0 * ").count(), 2);
+    assert_eq!(
+        dev::dump_gates::<SharedSelectorCircuit<Fp, false>>()
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+/// 第 1 行的 sum 或 prod 错误时，同一个门中对应的约束（0 为 sum，1 为 prod）在同一行失败；
+/// public input 也换成错误的值，排除拷贝约束的失败
+#[test]
+fn wrong_row_fails_its_constraint() {
+    let circuit = SharedSelectorCircuit::<Fp>::new(&pairs());
+    let public_input = sums_and_products(&pairs());
+    for (constraint, wrong_sum) in [(0, true), (1, false)] {
+        let mut bad = circuit.clone();
+        let mut bad_public = public_input.clone();
+        if wrong_sum {
+            bad.rows[1].sum = Value::known(Fp::from(10));
+            bad_public[2] = Fp::from(10);
+        } else {
+            bad.rows[1].prod = Value::known(Fp::from(21));
+            bad_public[3] = Fp::from(21);
+        }
+        let failures = MockProver::run(K, &bad, vec![bad_public])
+            .unwrap()
+            .verify()
+            .unwrap_err();
+        assert_eq!(failures.len(), 1);
+        let failure = failures[0].to_string();
+        assert!(failure.starts_with(&format!(
+            "Constraint {} in gate 0 ('sum_and_product') is not satisfied",
+            constraint
+        )));
+        assert!(failure.contains("at offset 1"));
+    }
+}
+
+/// 选择器压缩后：共享的选择器只有一个 fixed 列，分开的两个选择器总在同一行启用，不能合并，需要两个
+#[test]
+fn shared_selector_saves_a_fixed_column() {
+    let shared = SharedSelectorCircuit::<Fp>::new(&pairs());
+    let separate = SharedSelectorCircuit::<Fp, false>::new(&pairs());
+    assert_eq!(
+        dev::measure_fixed_queries(K, &shared).unwrap() + 1,
+        dev::measure_fixed_queries(K, &separate).unwrap()
+    );
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<SharedSelectorCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = SharedSelectorCircuit::<Fp>::new(&pairs());
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[sums_and_products(&pairs())]).is_ok());
+}
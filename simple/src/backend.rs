@@ -0,0 +1,104 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp, plonk::Circuit};
+use rand_core::OsRng;
+
+use crate::prover::{self, ProofError};
+use crate::transcript::TranscriptKind;
+
+///////////////////////////////////////////////////////////////////////
+/// 检查电路的后端：MockProver 和真实证明使用同一个接口，切换时只需要换一个类型
+///
+/// - MockBackend：用 MockProver 检查约束，速度快，失败时能给出具体的单元格，适合开发调试；
+/// - IpaBackend：生成密钥、生成真实的 IPA 证明并验证，与实际使用时一致，但慢得多，
+///   约束不满足时只能得到验证失败，不知道是哪个约束。
+///
+/// 示例程序带 --ipa 参数运行时用 IpaBackend，否则用 MockBackend，见 from_args
+pub trait Backend {
+    /// 后端的名字，用于打印
+    fn name(&self) -> &'static str;
+    /// 电路在 public input 下满足所有约束时返回 Ok
+    fn check<C: Circuit<Fp>>(
+        &self,
+        k: u32,
+        circuit: &C,
+        public: &[Vec<Fp>],
+    ) -> Result<(), ProofError>;
+}
+
+/// MockProver 后端：约束不满足时返回 ProofError::Unsatisfied
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockBackend;
+
+impl Backend for MockBackend {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn check<C: Circuit<Fp>>(
+        &self,
+        k: u32,
+        circuit: &C,
+        public: &[Vec<Fp>],
+    ) -> Result<(), ProofError> {
+        let prover = MockProver::run(k, circuit, public.to_vec())?;
+        prover.verify().map_err(ProofError::Unsatisfied)
+    }
+}
+
+/// 真实证明后端：每次检查都重新生成密钥，生成证明后立即验证，验证失败时返回 ProofError::Plonk
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IpaBackend {
+    pub transcript: TranscriptKind,
+}
+
+impl Backend for IpaBackend {
+    fn name(&self) -> &'static str {
+        "ipa"
+    }
+
+    fn check<C: Circuit<Fp>>(
+        &self,
+        k: u32,
+        circuit: &C,
+        public: &[Vec<Fp>],
+    ) -> Result<(), ProofError> {
+        let proof = prover::prove_unchecked(k, circuit, public, self.transcript, OsRng)?;
+        prover::verify_unchecked(k, circuit, &proof, public, self.transcript)
+    }
+}
+
+/// 由命令行参数选择的后端。Backend::check 对电路泛型，不能用 trait 对象，这里用枚举分发
+#[derive(Clone, Copy, Debug)]
+pub enum SelectedBackend {
+    Mock(MockBackend),
+    Ipa(IpaBackend),
+}
+
+impl Backend for SelectedBackend {
+    fn name(&self) -> &'static str {
+        match self {
+            SelectedBackend::Mock(backend) => backend.name(),
+            SelectedBackend::Ipa(backend) => backend.name(),
+        }
+    }
+
+    fn check<C: Circuit<Fp>>(
+        &self,
+        k: u32,
+        circuit: &C,
+        public: &[Vec<Fp>],
+    ) -> Result<(), ProofError> {
+        match self {
+            SelectedBackend::Mock(backend) => backend.check(k, circuit, public),
+            SelectedBackend::Ipa(backend) => backend.check(k, circuit, public),
+        }
+    }
+}
+
+/// 命令行参数中有 --ipa 时返回 IpaBackend，否则返回 MockBackend
+pub fn from_args() -> SelectedBackend {
+    if std::env::args().any(|arg| arg == "--ipa") {
+        SelectedBackend::Ipa(IpaBackend::default())
+    } else {
+        SelectedBackend::Mock(MockBackend)
+    }
+}
@@ -0,0 +1,414 @@
+use crate::{ACell, NumInstructions, SimpleChip, SimpleConfig};
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+///////////////////////////////////////////////////////////////////////
+/// 常量折叠：可选的 SimpleChip 包装，把只涉及常量的运算在电路外算完，节省行数
+///
+/// ConstFoldChip 的 Num 记录一个数是电路外已知的常量，还是已经赋值的单元格。
+/// load_constant 不赋值，只返回常量；输入都是常量的运算直接在电路外计算，结果仍是常量；
+/// 只有常量与单元格混合时，才把常量加载到电路中（materialize），交给 SimpleChip 处理。
+///
+/// 折叠不改变电路的语义：常量由电路结构决定，对任意 witness 都相同，电路外计算的结果与电路中约束的结果一致。
+/// 会让约束无法满足的常量（例如 and 的输入不是布尔值、对 0 求逆）不折叠，照常交给 SimpleChip，
+/// 这样错误的电路在两种芯片下都无法通过验证。
+///
+/// ConstFoldChip 的数：电路外已知的常量，或者已赋值的单元格
+#[derive(Clone, Debug)]
+pub enum FoldedNum<F: Field> {
+    Constant(F),
+    Cell(ACell<F>),
+}
+
+impl<F: Field> FoldedNum<F> {
+    /// 常量的值，单元格返回 None
+    pub fn constant(&self) -> Option<F> {
+        match self {
+            FoldedNum::Constant(c) => Some(*c),
+            FoldedNum::Cell(_) => None,
+        }
+    }
+}
+
+pub struct ConstFoldChip<F: Field, const N: usize = 2> {
+    inner: SimpleChip<F, N>,
+}
+
+impl<F: Field, const N: usize> ConstFoldChip<F, N> {
+    pub fn construct(config: SimpleConfig<N>) -> Self {
+        Self {
+            inner: SimpleChip::construct(config),
+        }
+    }
+
+    /// 把常量加载到 fixed 列（与 SimpleChip::load_constant 相同），单元格原样返回
+    fn materialize(&self, layouter: impl Layouter<F>, a: FoldedNum<F>) -> Result<ACell<F>, Error> {
+        match a {
+            FoldedNum::Constant(c) => self.inner.load_constant(layouter, c),
+            FoldedNum::Cell(cell) => Ok(cell),
+        }
+    }
+
+    fn materialize_all(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[FoldedNum<F>],
+    ) -> Result<Vec<ACell<F>>, Error> {
+        xs.iter()
+            .map(|x| self.materialize(layouter.namespace(|| "materialize"), x.clone()))
+            .collect()
+    }
+}
+
+fn is_bool<F: Field>(c: F) -> bool {
+    c == F::ZERO || c == F::ONE
+}
+
+impl<F: Field, const N: usize> Chip<F> for ConstFoldChip<F, N> {
+    type Config = SimpleConfig<N>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        self.inner.config()
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: Field, const N: usize> NumInstructions<F> for ConstFoldChip<F, N> {
+    type Num = FoldedNum<F>;
+
+    fn load_private(&self, layouter: impl Layouter<F>, a: Value<F>) -> Result<Self::Num, Error> {
+        self.inner.load_private(layouter, a).map(FoldedNum::Cell)
+    }
+
+    /// 不赋值，需要时再由 materialize 加载
+    fn load_constant(&self, _layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error> {
+        Ok(FoldedNum::Constant(constant))
+    }
+
+    fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        match (a, b) {
+            (FoldedNum::Constant(a), FoldedNum::Constant(b)) => Ok(FoldedNum::Constant(a * b)),
+            (FoldedNum::Constant(k), x) | (x, FoldedNum::Constant(k)) => {
+                self.mul_by_constant(layouter, x, k)
+            }
+            (a, b) => {
+                let a = self.materialize(layouter.namespace(|| "lhs"), a)?;
+                let b = self.materialize(layouter.namespace(|| "rhs"), b)?;
+                self.inner.mul(layouter, a, b).map(FoldedNum::Cell)
+            }
+        }
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        c: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let c = self.materialize(layouter.namespace(|| "public"), c)?;
+        self.inner.expose_public(layouter, c, row)
+    }
+
+    fn pow(&self, layouter: impl Layouter<F>, a: Self::Num, exp: u64) -> Result<Self::Num, Error> {
+        match a {
+            FoldedNum::Constant(a) => Ok(FoldedNum::Constant(a.pow_vartime([exp]))),
+            FoldedNum::Cell(a) => self.inner.pow(layouter, a, exp).map(FoldedNum::Cell),
+        }
+    }
+
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        self.sum(layouter, &[a, b])
+    }
+
+    /// 乘以 1 时不需要任何行
+    fn mul_by_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        match a {
+            FoldedNum::Constant(a) => Ok(FoldedNum::Constant(a * constant)),
+            a if constant == F::ONE => Ok(a),
+            FoldedNum::Cell(a) => self
+                .inner
+                .mul_by_constant(layouter, a, constant)
+                .map(FoldedNum::Cell),
+        }
+    }
+
+    /// 常量先在电路外求和，和不为 0 时作为一个常量参与链式加法
+    fn sum(&self, mut layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error> {
+        let constant: F = xs.iter().filter_map(FoldedNum::constant).sum();
+        let mut cells: Vec<Self::Num> = xs
+            .iter()
+            .filter(|x| x.constant().is_none())
+            .cloned()
+            .collect();
+        if cells.is_empty() {
+            return Ok(FoldedNum::Constant(constant));
+        }
+        if constant != F::ZERO {
+            cells.push(FoldedNum::Constant(constant));
+        }
+        if let [single] = cells.as_slice() {
+            return Ok(single.clone());
+        }
+        let cells = self.materialize_all(layouter.namespace(|| "terms"), &cells)?;
+        self.inner.sum(layouter, &cells).map(FoldedNum::Cell)
+    }
+
+    /// 常量先在电路外求积，积不为 1 时用 mul_by_constant 乘到单元格的积上
+    fn product(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        let constant: F = xs.iter().filter_map(FoldedNum::constant).product();
+        let cells: Vec<ACell<F>> = xs
+            .iter()
+            .filter_map(|x| match x {
+                FoldedNum::Cell(cell) => Some(cell.clone()),
+                FoldedNum::Constant(_) => None,
+            })
+            .collect();
+        let prod = match cells.as_slice() {
+            [] => return Ok(FoldedNum::Constant(constant)),
+            [single] => FoldedNum::Cell(single.clone()),
+            _ => FoldedNum::Cell(self.inner.product(layouter.namespace(|| "cells"), &cells)?),
+        };
+        self.mul_by_constant(layouter, prod, constant)
+    }
+
+    /// 常量与单元格相等时不需要任何行；常量不相等时照常加载后约束，电路无法满足
+    fn constrain_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        k: F,
+    ) -> Result<(), Error> {
+        if a.constant() == Some(k) {
+            return Ok(());
+        }
+        let a = self.materialize(layouter.namespace(|| "a"), a)?;
+        self.inner.constrain_constant(layouter, a, k)
+    }
+
+    /// bit 为布尔常量时直接返回 a 或 b
+    fn select(
+        &self,
+        mut layouter: impl Layouter<F>,
+        bit: Self::Num,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        match bit.constant() {
+            Some(c) if c == F::ONE => Ok(a),
+            Some(c) if c == F::ZERO => Ok(b),
+            _ => {
+                let bit = self.materialize(layouter.namespace(|| "bit"), bit)?;
+                let a = self.materialize(layouter.namespace(|| "a"), a)?;
+                let b = self.materialize(layouter.namespace(|| "b"), b)?;
+                self.inner.select(layouter, bit, a, b).map(FoldedNum::Cell)
+            }
+        }
+    }
+
+    fn pow_var(
+        &self,
+        mut layouter: impl Layouter<F>,
+        base: Self::Num,
+        exp_bits: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        let base = self.materialize(layouter.namespace(|| "base"), base)?;
+        let exp_bits = self.materialize_all(layouter.namespace(|| "exp bits"), exp_bits)?;
+        self.inner
+            .pow_var(layouter, base, &exp_bits)
+            .map(FoldedNum::Cell)
+    }
+
+    fn dot(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+    ) -> Result<Self::Num, Error> {
+        self.linear_combination(layouter, xs, ws, F::ZERO)
+    }
+
+    fn and(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        match (a.constant(), b.constant()) {
+            (Some(x), Some(y)) if is_bool(x) && is_bool(y) => Ok(FoldedNum::Constant(x * y)),
+            _ => {
+                let a = self.materialize(layouter.namespace(|| "lhs"), a)?;
+                let b = self.materialize(layouter.namespace(|| "rhs"), b)?;
+                self.inner.and(layouter, a, b).map(FoldedNum::Cell)
+            }
+        }
+    }
+
+    fn or(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        match (a.constant(), b.constant()) {
+            (Some(x), Some(y)) if is_bool(x) && is_bool(y) => {
+                Ok(FoldedNum::Constant(x + y - x * y))
+            }
+            _ => {
+                let a = self.materialize(layouter.namespace(|| "lhs"), a)?;
+                let b = self.materialize(layouter.namespace(|| "rhs"), b)?;
+                self.inner.or(layouter, a, b).map(FoldedNum::Cell)
+            }
+        }
+    }
+
+    /// 常量项 x_i * w_i 在电路外并入 constant，只对单元格做线性组合
+    fn linear_combination(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        // 与 SimpleChip 的 dot 一致：长度不同时返回错误，而不是被 zip 截断
+        if xs.len() != ws.len() {
+            return Err(Error::Synthesis);
+        }
+        let mut folded = constant;
+        let mut terms = Vec::new();
+        for (x, w) in xs.iter().zip(ws) {
+            match x {
+                FoldedNum::Constant(c) => folded += *c * w,
+                FoldedNum::Cell(cell) => terms.push((cell.clone(), *w)),
+            }
+        }
+        match terms.as_slice() {
+            [] => Ok(FoldedNum::Constant(folded)),
+            [(cell, w)] if folded == F::ZERO => {
+                self.mul_by_constant(layouter, FoldedNum::Cell(cell.clone()), *w)
+            }
+            _ => {
+                let (cells, ws): (Vec<_>, Vec<_>) = terms.into_iter().unzip();
+                let res = if folded == F::ZERO {
+                    self.inner.dot(layouter, &cells, &ws)
+                } else {
+                    self.inner.linear_combination(layouter, &cells, &ws, folded)
+                };
+                res.map(FoldedNum::Cell)
+            }
+        }
+    }
+
+    fn load_and_pin_instance(
+        &self,
+        layouter: impl Layouter<F>,
+        row: usize,
+        expected: F,
+    ) -> Result<Self::Num, Error> {
+        self.inner
+            .load_and_pin_instance(layouter, row, expected)
+            .map(FoldedNum::Cell)
+    }
+
+    /// 非 0 常量的逆在电路外计算；0 没有逆，照常交给 SimpleChip，约束无法满足
+    fn inv(&self, mut layouter: impl Layouter<F>, a: Self::Num) -> Result<Self::Num, Error> {
+        if let Some(c) = a.constant() {
+            if let Some(inv) = Option::<F>::from(c.invert()) {
+                return Ok(FoldedNum::Constant(inv));
+            }
+        }
+        let a = self.materialize(layouter.namespace(|| "a"), a)?;
+        self.inner.inv(layouter, a).map(FoldedNum::Cell)
+    }
+
+    fn load_instance(&self, layouter: impl Layouter<F>, row: usize) -> Result<Self::Num, Error> {
+        self.inner.load_instance(layouter, row).map(FoldedNum::Cell)
+    }
+
+    fn assert_is_square(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let a = self.materialize(layouter.namespace(|| "a"), a)?;
+        self.inner
+            .assert_is_square(layouter, a)
+            .map(FoldedNum::Cell)
+    }
+
+    /// 两个相等的常量不需要拷贝约束
+    fn link(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<(), Error> {
+        if let (Some(x), Some(y)) = (a.constant(), b.constant()) {
+            if x == y {
+                return Ok(());
+            }
+        }
+        let a = self.materialize(layouter.namespace(|| "a"), a)?;
+        let b = self.materialize(layouter.namespace(|| "b"), b)?;
+        self.inner.link(layouter, a, b)
+    }
+
+    fn assert_pythagorean(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        c: Self::Num,
+    ) -> Result<(), Error> {
+        let a = self.materialize(layouter.namespace(|| "a"), a)?;
+        let b = self.materialize(layouter.namespace(|| "b"), b)?;
+        let c = self.materialize(layouter.namespace(|| "c"), c)?;
+        self.inner.assert_pythagorean(layouter, a, b, c)
+    }
+
+    fn weighted_average(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: &[Self::Num],
+        weights: &[F],
+        total_weight_inv: F,
+    ) -> Result<Self::Num, Error> {
+        if values.len() != weights.len() {
+            return Err(Error::Synthesis);
+        }
+        let dot = self.dot(layouter.namespace(|| "values · weights"), values, weights)?;
+        self.mul_by_constant(layouter, dot, total_weight_inv)
+    }
+
+    fn debug_value(cell: &Self::Num) -> Value<F> {
+        match cell {
+            FoldedNum::Constant(c) => Value::known(*c),
+            FoldedNum::Cell(cell) => SimpleChip::<F, N>::debug_value(cell),
+        }
+    }
+}
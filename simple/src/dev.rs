@@ -0,0 +1,222 @@
+use halo2_proofs::{
+    circuit::Value,
+    dev::{CircuitCost, CircuitGates, MockProver, VerifyFailure},
+    pasta::{Eq, Fp},
+    plonk::{Any, Circuit, Column, ConstraintSystem},
+};
+use rand_core::OsRng;
+use std::fmt::Debug;
+
+use crate::{layout, witness::RandomWitness};
+
+///////////////////////////////////////////////////////////////////////
+/// 调试电路时使用的 MockProver 辅助函数
+///
+/// 断言验证失败的原因是指定的门：验证结果中至少有一个 ConstraintNotSatisfied 的约束属于名为 gate_name 的门。
+/// halo2 0.3 中 lookup 没有名字，只有按配置顺序分配的序号，所以 lookup 失败用 "lookup 0"、"lookup 1" ... 来匹配。
+/// 注意：public input 错误时，失败的通常是拷贝约束（VerifyFailure::Permutation），而不是某个门。
+pub fn assert_constraint_failure(prover: &MockProver<Fp>, gate_name: &str) {
+    let failures = match prover.verify() {
+        Ok(()) => panic!(
+            "expected gate '{}' to fail, but the circuit is satisfied",
+            gate_name
+        ),
+        Err(failures) => failures,
+    };
+
+    let matched = failures.iter().any(|failure| match failure {
+        // Constraint 的 Display 格式为 "Constraint i ('name') in gate j ('gate_name')"
+        VerifyFailure::ConstraintNotSatisfied { constraint, .. } => constraint
+            .to_string()
+            .ends_with(&format!("('{}')", gate_name)),
+        VerifyFailure::Lookup { lookup_index, .. } => {
+            format!("lookup {}", lookup_index) == gate_name
+        }
+        _ => false,
+    });
+
+    assert!(
+        matched,
+        "expected gate '{}' to fail, got: {:#?}",
+        gate_name, failures
+    );
+}
+
+/// MockProver 有两种检查方式：
+/// - verify() 返回 Result<(), Vec<VerifyFailure>>，需要自己匹配和打印失败；
+/// - assert_satisfied() 在失败时把每个失败连同所在 region 的单元格布局打印到 stderr 后 panic，更适合调试，但不能在代码中处理。
+///
+/// verify_pretty 结合两者：电路满足时调用 assert_satisfied（不会 panic）并返回 Ok，
+/// 不满足时不 panic，返回每个失败的 Display 格式（每行一个），例如
+/// "Constraint 0 in gate 0 ('mul') is not satisfied in Region 1 ('mul') at offset 0"
+pub fn verify_pretty(prover: &MockProver<Fp>) -> Result<(), String> {
+    match prover.verify() {
+        Ok(()) => {
+            prover.assert_satisfied();
+            Ok(())
+        }
+        Err(failures) => Err(failures
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")),
+    }
+}
+
+/// 与 verify_pretty 相同，但失败信息中的列带上名字，例如 Column('Advice', 0 - lhs/out)，
+/// 不用再对照 configure 去查 advice 0 是哪一列。names 通常来自 SimpleConfig::column_names。
+/// PSE 分支的 halo2 可以在 configure 中用 annotate_column 给列命名，zcash 的 halo2 0.3 没有这个 API，
+/// 这里在 Display 输出中替换列的文本：ConstraintNotSatisfied 中为 Column('Advice', 0)，
+/// Permutation 中为 Column { column_type: Advice, index: 0 }
+pub fn verify_annotated(
+    prover: &MockProver<Fp>,
+    names: &[(Column<Any>, String)],
+) -> Result<(), String> {
+    verify_pretty(prover).map_err(|report| {
+        names.iter().fold(report, |report, (column, name)| {
+            let column_type = format!("{:?}", column.column_type());
+            let index = layout::debug_field(&format!("{:?}", column), "index");
+            let named = format!("Column('{}', {} - {})", column_type, index, name);
+            report
+                .replace(&format!("Column('{}', {})", column_type, index), &named)
+                .replace(
+                    &format!(
+                        "Column {{ column_type: {}, index: {} }}",
+                        column_type, index
+                    ),
+                    &named,
+                )
+        })
+    })
+}
+
+/// 能根据自身的 witness 在电路外算出期望 public input 的电路
+/// witness 未知时（例如 without_witnesses 之后）返回 Value::unknown()
+pub trait ExpectedPublic {
+    fn expected_public(&self) -> Value<Vec<Vec<Fp>>>;
+}
+
+/// 运行 MockProver 并验证，同时用电路的 witness 重新计算期望的 public input，与传入的 public 逐项比较。
+/// public input 与 witness 不一致时，返回具体是哪一列哪一行不一致，而不只是一个拷贝约束失败
+pub fn run_and_check<C: Circuit<Fp> + ExpectedPublic>(
+    k: u32,
+    circuit: &C,
+    public: Vec<Vec<Fp>>,
+) -> Result<(), String> {
+    let prover = MockProver::run(k, circuit, public.clone()).map_err(|e| e.to_string())?;
+    let verified = prover.verify();
+
+    let mut mismatch = Ok(());
+    circuit.expected_public().map(|expected| {
+        mismatch = compare_public(&expected, &public);
+    });
+    mismatch?;
+
+    verified.map_err(|failures| format!("MockProver verification failed: {:?}", failures))
+}
+
+fn compare_public(expected: &[Vec<Fp>], got: &[Vec<Fp>]) -> Result<(), String> {
+    if expected.len() != got.len() {
+        return Err(format!(
+            "public input has {} instance columns, circuit expects {}",
+            got.len(),
+            expected.len()
+        ));
+    }
+    for (column, (expected, got)) in expected.iter().zip(got).enumerate() {
+        if expected.len() != got.len() {
+            return Err(format!(
+                "instance column {} has {} values, circuit expects {}",
+                column,
+                got.len(),
+                expected.len()
+            ));
+        }
+        for (row, (expected, got)) in expected.iter().zip(got).enumerate() {
+            if expected != got {
+                return Err(format!(
+                    "public input mismatch at instance column {}, row {}: expected {:?}, got {:?}",
+                    column, row, expected, got
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 电路的最大次数：所有门、lookup 和拷贝约束（permutation）参数中次数最高的约束，
+/// 拷贝约束参数的次数至少为 3。次数只由 configure 决定，与 witness 和 k 无关
+pub fn measure_degree<C: Circuit<Fp>>() -> usize {
+    let mut cs = ConstraintSystem::default();
+    C::configure(&mut cs);
+    cs.degree()
+}
+
+/// 用 CircuitCost 读取 fixed 列的查询个数（fixed_queries）：CircuitCost 先压缩选择器，
+/// 所以选择器压缩成的 fixed 列也计算在内，可以用来比较两种选择器写法的开销。
+/// 压缩后的约束系统和 CircuitCost 的字段都不公开，只能从 CircuitCost 的 Debug 输出中读取
+/// （Debug 要求电路也实现 Debug），输出中找不到该字段时返回 None
+pub fn measure_fixed_queries<C: Circuit<Fp> + Debug>(k: u32, circuit: &C) -> Option<usize> {
+    let cost = format!("{:?}", CircuitCost::<Eq, C>::measure(k, circuit));
+    cost.split("fixed_queries: ")
+        .nth(1)
+        .and_then(|rest| rest.split(',').next())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+/// 导出电路中每个门的约束多项式，每个门一个字符串，格式为 "门名: 约束1; 约束2; ..."
+/// 表达式中 S 为选择器，A/F/I 分别为 advice/fixed/instance 列，@ 后为行偏移，
+/// 例如乘法门为 "mul: S0 * (A0@0 * A1@0 - A0@1)"
+/// 门的内部结构在 halo2 0.3 中不公开，这里解析 CircuitGates 的 Display 输出；
+/// 输出的格式与预期不同（例如约束出现在第一个门之前，或门的个数与 "Total gates" 不一致）时返回错误
+pub fn dump_gates<C: Circuit<Fp>>() -> Result<Vec<String>, String> {
+    let gates = CircuitGates::collect::<Fp, C>().to_string();
+    let mut dumped: Vec<(String, Vec<String>)> = vec![];
+    let mut total = None;
+    for line in gates.lines() {
+        if let Some(count) = line.strip_prefix("Total gates: ") {
+            total = count.parse::<usize>().ok();
+            break;
+        }
+        let constraint = match line.strip_prefix("  ") {
+            // 有名字的约束："- name:" 的下一行是表达式
+            Some(expression) => Some(expression),
+            None => line.strip_prefix("- ").filter(|c| !c.ends_with(':')),
+        };
+        if let Some(constraint) = constraint {
+            dumped
+                .last_mut()
+                .ok_or_else(|| format!("constraint before any gate: {}", line))?
+                .1
+                .push(constraint.to_string());
+        } else if let Some(name) = line.strip_suffix(':') {
+            if !line.starts_with("- ") {
+                dumped.push((name.to_string(), vec![]));
+            }
+        }
+    }
+    if total != Some(dumped.len()) {
+        return Err(format!(
+            "parsed {} gates, CircuitGates reports {:?}",
+            dumped.len(),
+            total
+        ));
+    }
+    Ok(dumped
+        .into_iter()
+        .map(|(name, constraints)| format!("{}: {}", name, constraints.join("; ")))
+        .collect())
+}
+
+/// 用 RandomWitness 生成 samples 个随机的电路，逐个用 MockProver 验证，返回第一个验证失败的原因
+pub fn check_random<C: Circuit<Fp> + RandomWitness>(k: u32, samples: usize) -> Result<(), String> {
+    for i in 0..samples {
+        let (circuit, public) = C::random(&mut OsRng);
+        let prover =
+            MockProver::run(k, &circuit, public).map_err(|e| format!("sample {}: {}", i, e))?;
+        prover
+            .verify()
+            .map_err(|failures| format!("sample {}: {} failure(s)", i, failures.len()))?;
+    }
+    Ok(())
+}
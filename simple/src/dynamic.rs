@@ -0,0 +1,146 @@
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{Layouter, Region, Value},
+    plonk::Error,
+};
+
+use crate::{ACell, SimpleChip};
+
+///////////////////////////////////////////////////////////////////////
+/// 对象安全（可以放在 dyn 后面）的指令集
+///
+/// NumInstructions 的指令接收 impl Layouter<F>，而 Layouter 的 assign_region 等方法本身带有泛型参数，
+/// 所以既不能把 NumInstructions 做成 trait object，也不能写 &mut dyn Layouter<F>。
+/// Region<'_, F> 是一个具体的类型（内部是 &mut dyn RegionLayouter），可以在 trait object 的方法中使用，
+/// 所以这里的指令都在调用者打开的一个 region 中赋值：offset 为当前行，指令执行后把 offset 移到下一条指令可用的行。
+/// 方法名带 _at 后缀，避免与 NumInstructions 中的同名指令冲突。
+pub trait DynNumInstructions<F: Field> {
+    /// 在 offset 行加载私有变量，占用 1 行
+    fn load_private_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: Value<F>,
+    ) -> Result<ACell<F>, Error>;
+    /// 在 offset 行加载常量，占用 1 行
+    fn load_constant_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        constant: F,
+    ) -> Result<ACell<F>, Error>;
+    /// a * b，占用 2 行
+    fn mul_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: &ACell<F>,
+        b: &ACell<F>,
+    ) -> Result<ACell<F>, Error>;
+    /// a + b，占用 2 行
+    fn add_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: &ACell<F>,
+        b: &ACell<F>,
+    ) -> Result<ACell<F>, Error>;
+}
+
+/// 放在 trait object 后面的芯片，不同列数的 SimpleChip 都可以放进来
+pub type BoxedChip<F> = Box<dyn DynNumInstructions<F>>;
+
+impl<F: Field, const N: usize> DynNumInstructions<F> for SimpleChip<F, N> {
+    fn load_private_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: Value<F>,
+    ) -> Result<ACell<F>, Error> {
+        let cell =
+            region.assign_advice(|| "private input", self.config.advice[0], *offset, || a)?;
+        *offset += 1;
+        Ok(ACell(cell))
+    }
+
+    fn load_constant_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        constant: F,
+    ) -> Result<ACell<F>, Error> {
+        let cell = region.assign_advice_from_constant(
+            || "constant value",
+            self.config.advice[0],
+            *offset,
+            constant,
+        )?;
+        *offset += 1;
+        Ok(ACell(cell))
+    }
+
+    fn mul_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: &ACell<F>,
+        b: &ACell<F>,
+    ) -> Result<ACell<F>, Error> {
+        let config = &self.config;
+        config.s_mul.enable(region, *offset)?;
+        a.0.copy_advice(|| "lhs", region, config.advice[0], *offset)?;
+        b.0.copy_advice(|| "rhs", region, config.advice[1], *offset)?;
+        let res = a.0.value().copied() * b.0.value();
+        let cell = region.assign_advice(|| "lhs * rhs", config.advice[0], *offset + 1, || res)?;
+        *offset += 2;
+        Ok(ACell(cell))
+    }
+
+    fn add_at(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        a: &ACell<F>,
+        b: &ACell<F>,
+    ) -> Result<ACell<F>, Error> {
+        let config = &self.config;
+        config.s_add.enable(region, *offset)?;
+        a.0.copy_advice(|| "lhs", region, config.advice[0], *offset)?;
+        b.0.copy_advice(|| "rhs", region, config.advice[1], *offset)?;
+        let res = a.0.value().copied() + b.0.value();
+        let cell = region.assign_advice(|| "lhs + rhs", config.advice[0], *offset + 1, || res)?;
+        *offset += 2;
+        Ok(ACell(cell))
+    }
+}
+
+/// 流水线中的一步操作：输入一个数，输出一个数，可以放在 Vec<Box<dyn DynOp<F>>> 中
+pub trait DynOp<F: Field> {
+    fn apply(
+        &self,
+        chip: &dyn DynNumInstructions<F>,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        x: ACell<F>,
+    ) -> Result<ACell<F>, Error>;
+}
+
+/// 在一个 region 中依次执行流水线中的所有操作，返回最后的结果
+pub fn run_pipeline<F: Field>(
+    chip: &dyn DynNumInstructions<F>,
+    mut layouter: impl Layouter<F>,
+    x: Value<F>,
+    ops: &[Box<dyn DynOp<F>>],
+) -> Result<ACell<F>, Error> {
+    layouter.assign_region(
+        || "pipeline",
+        |mut region| {
+            let mut offset = 0;
+            let mut acc = chip.load_private_at(&mut region, &mut offset, x)?;
+            for op in ops {
+                acc = op.apply(chip, &mut region, &mut offset, acc)?;
+            }
+            Ok(acc)
+        },
+    )
+}
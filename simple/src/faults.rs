@@ -0,0 +1,266 @@
+use crate::{ACell, NumInstructions, SimpleChip, SimpleConfig};
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{Chip, Layouter, Value},
+    plonk::{Error, Selector},
+};
+
+///////////////////////////////////////////////////////////////////////
+/// 故障注入：用于演示电路的可靠性（soundness），只在启用 faults feature 时编译
+///
+/// FaultyChip 包装 SimpleChip，被选中的指令在输出单元格中赋一个错误的值（正确结果 + 1），
+/// 门和拷贝约束都不变，MockProver 应当报告这条指令的门不满足。
+/// 其余指令直接交给 SimpleChip；pow、product 等由 SimpleChip 内部组合实现的指令不受影响。
+///
+/// 被注入错误的指令
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fault {
+    /// mul 输出 a * b + 1，违反 "mul" 门
+    Mul,
+    /// add 输出 a + b + 1，违反 "add" 门
+    Add,
+}
+
+pub struct FaultyChip<F: Field, const N: usize = 2> {
+    inner: SimpleChip<F, N>,
+    fault: Option<Fault>,
+}
+
+impl<F: Field, const N: usize> FaultyChip<F, N> {
+    /// fault 为 None 时与 SimpleChip 的行为完全相同
+    pub fn construct(config: SimpleConfig<N>, fault: Option<Fault>) -> Self {
+        Self {
+            inner: SimpleChip::construct(config),
+            fault,
+        }
+    }
+
+    /// 按 mul、add 的布局（输入在第 0 行，输出在第 1 行）赋值，输出为 res + 1
+    fn faulty_binary(
+        &self,
+        mut layouter: impl Layouter<F>,
+        name: &'static str,
+        selector: Selector,
+        a: ACell<F>,
+        b: ACell<F>,
+        res: Value<F>,
+    ) -> Result<ACell<F>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || name,
+            |mut region| {
+                selector.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                region
+                    .assign_advice(
+                        || "faulty output",
+                        config.advice[0],
+                        1,
+                        || res + Value::known(F::ONE),
+                    )
+                    .map(ACell)
+            },
+        )
+    }
+}
+
+impl<F: Field, const N: usize> Chip<F> for FaultyChip<F, N> {
+    type Config = SimpleConfig<N>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        self.inner.config()
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: Field, const N: usize> NumInstructions<F> for FaultyChip<F, N> {
+    type Num = ACell<F>;
+
+    fn load_private(&self, layouter: impl Layouter<F>, a: Value<F>) -> Result<Self::Num, Error> {
+        self.inner.load_private(layouter, a)
+    }
+
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error> {
+        self.inner.load_constant(layouter, constant)
+    }
+
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        if self.fault != Some(Fault::Mul) {
+            return self.inner.mul(layouter, a, b);
+        }
+        let res = a.0.value().copied() * b.0.value();
+        self.faulty_binary(layouter, "mul", self.config().s_mul, a, b, res)
+    }
+
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        c: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        self.inner.expose_public(layouter, c, row)
+    }
+
+    fn pow(&self, layouter: impl Layouter<F>, a: Self::Num, exp: u64) -> Result<Self::Num, Error> {
+        self.inner.pow(layouter, a, exp)
+    }
+
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        if self.fault != Some(Fault::Add) {
+            return self.inner.add(layouter, a, b);
+        }
+        let res = a.0.value().copied() + b.0.value();
+        self.faulty_binary(layouter, "add", self.config().s_add, a, b, res)
+    }
+
+    fn mul_by_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        self.inner.mul_by_constant(layouter, a, constant)
+    }
+
+    fn sum(&self, layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error> {
+        self.inner.sum(layouter, xs)
+    }
+
+    fn product(&self, layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error> {
+        self.inner.product(layouter, xs)
+    }
+
+    fn constrain_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        k: F,
+    ) -> Result<(), Error> {
+        self.inner.constrain_constant(layouter, a, k)
+    }
+
+    fn select(
+        &self,
+        layouter: impl Layouter<F>,
+        bit: Self::Num,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        self.inner.select(layouter, bit, a, b)
+    }
+
+    fn pow_var(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Self::Num,
+        exp_bits: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        self.inner.pow_var(layouter, base, exp_bits)
+    }
+
+    fn dot(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+    ) -> Result<Self::Num, Error> {
+        self.inner.dot(layouter, xs, ws)
+    }
+
+    fn and(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        self.inner.and(layouter, a, b)
+    }
+
+    fn or(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        self.inner.or(layouter, a, b)
+    }
+
+    fn linear_combination(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        self.inner.linear_combination(layouter, xs, ws, constant)
+    }
+
+    fn load_and_pin_instance(
+        &self,
+        layouter: impl Layouter<F>,
+        row: usize,
+        expected: F,
+    ) -> Result<Self::Num, Error> {
+        self.inner.load_and_pin_instance(layouter, row, expected)
+    }
+
+    fn inv(&self, layouter: impl Layouter<F>, a: Self::Num) -> Result<Self::Num, Error> {
+        self.inner.inv(layouter, a)
+    }
+
+    fn load_instance(&self, layouter: impl Layouter<F>, row: usize) -> Result<Self::Num, Error> {
+        self.inner.load_instance(layouter, row)
+    }
+
+    fn assert_is_square(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        self.inner.assert_is_square(layouter, a)
+    }
+
+    fn link(&self, layouter: impl Layouter<F>, a: Self::Num, b: Self::Num) -> Result<(), Error> {
+        self.inner.link(layouter, a, b)
+    }
+
+    fn assert_pythagorean(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        c: Self::Num,
+    ) -> Result<(), Error> {
+        self.inner.assert_pythagorean(layouter, a, b, c)
+    }
+
+    fn weighted_average(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Self::Num],
+        weights: &[F],
+        total_weight_inv: F,
+    ) -> Result<Self::Num, Error> {
+        self.inner
+            .weighted_average(layouter, values, weights, total_weight_inv)
+    }
+
+    fn debug_value(cell: &Self::Num) -> Value<F> {
+        SimpleChip::<F, N>::debug_value(cell)
+    }
+}
@@ -0,0 +1,52 @@
+use group::ff::PrimeField;
+use halo2_proofs::pasta::Fp;
+
+///////////////////////////////////////////////////////////////////////
+/// 域元素相关的辅助函数
+///
+/// 把有符号整数映射到域中：Fp::from 只接受无符号整数，负数 -x 对应的域元素为 p - x，即 -Fp::from(x)
+/// 例如 signed_to_fp(-1) == Fp::zero() - Fp::one()，(-3)^2 在域中仍然等于 9
+pub fn signed_to_fp(i: i64) -> Fp {
+    if i >= 0 {
+        Fp::from(i as u64)
+    } else {
+        // unsigned_abs 可以处理 i64::MIN
+        -Fp::from(i.unsigned_abs())
+    }
+}
+
+/// 域元素的十进制表示，例如 55 -> "55"，-1 -> p - 1 的 77 位十进制数
+/// Fp 的 Debug 输出为补齐到 64 位的十六进制数，小的数也不容易读；
+/// to_repr 为 32 字节小端序的规范表示，这里对它反复除以 10 得到每一位
+pub fn fp_to_decimal(x: Fp) -> String {
+    // 大端序的字节，每次除以 10 后去掉前导的 0
+    let mut bytes: Vec<u8> = x.to_repr().iter().rev().copied().collect();
+    let mut digits = vec![];
+    while bytes.iter().any(|b| *b != 0) {
+        let mut rem = 0u32;
+        for b in bytes.iter_mut() {
+            let cur = (rem << 8) | *b as u32;
+            *b = (cur / 10) as u8;
+            rem = cur % 10;
+        }
+        digits.push(b'0' + rem as u8);
+    }
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// 域元素的大端序十六进制表示，带 0x 前缀、不带前导 0，例如 55 -> "0x37"，0 -> "0x0"，只用于打印。
+/// Prover::verify_from_hex 和 witness 文件使用 to_repr 的 32 字节小端序表示，不能读取这里的输出
+pub fn fp_to_hex_be(x: Fp) -> String {
+    let hex: String = x
+        .to_repr()
+        .iter()
+        .rev()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let trimmed = hex.trim_start_matches('0');
+    format!("0x{}", if trimmed.is_empty() { "0" } else { trimmed })
+}
@@ -0,0 +1,89 @@
+use group::ff::Field;
+use halo2_proofs::{circuit::Layouter, plonk::Error};
+
+use crate::NumInstructions;
+
+///////////////////////////////////////////////////////////////////////
+/// 由基本指令组合而成的常用 gadget，适用于任意实现了 NumInstructions 的芯片
+/// 每个 gadget 只调用基本指令，不引入新的门
+///
+/// a^2 + b
+pub fn square_and_add<F: Field, C: NumInstructions<F>>(
+    chip: &C,
+    mut layouter: impl Layouter<F>,
+    a: C::Num,
+    b: C::Num,
+) -> Result<C::Num, Error>
+where
+    C::Num: Clone,
+{
+    let square = chip.mul(layouter.namespace(|| "a * a"), a.clone(), a)?;
+    chip.add(layouter.namespace(|| "a^2 + b"), square, b)
+}
+
+/// Σ x_i * w_i，与 dot 指令不同，权重 w 也是电路中的单元格；输入为空时返回常数 0
+/// xs 和 ws 的长度不同时返回 Error::Synthesis
+pub fn weighted_sum<F: Field, C: NumInstructions<F>>(
+    chip: &C,
+    mut layouter: impl Layouter<F>,
+    xs: &[C::Num],
+    ws: &[C::Num],
+) -> Result<C::Num, Error>
+where
+    C::Num: Clone,
+{
+    if xs.len() != ws.len() {
+        return Err(Error::Synthesis);
+    }
+    let terms = xs
+        .iter()
+        .zip(ws)
+        .map(|(x, w)| chip.mul(layouter.namespace(|| "x * w"), x.clone(), w.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    chip.sum(layouter.namespace(|| "sum of terms"), &terms)
+}
+
+/// 条件累加：bit 为 1 时返回 acc + x，为 0 时返回 acc；约束 bit 为布尔值
+pub fn conditional_accumulate<F: Field, C: NumInstructions<F>>(
+    chip: &C,
+    mut layouter: impl Layouter<F>,
+    acc: C::Num,
+    x: C::Num,
+    bit: C::Num,
+) -> Result<C::Num, Error>
+where
+    C::Num: Clone,
+{
+    let added = chip.add(layouter.namespace(|| "acc + x"), acc.clone(), x)?;
+    chip.select(
+        layouter.namespace(|| "bit ? acc + x : acc"),
+        bit,
+        added,
+        acc,
+    )
+}
+
+/// x 为 0 时返回 1，否则返回 0
+/// 加载 witness inv（x ≠ 0 时为 x^{-1}，由 debug_value 取出 x 的值计算），out = 1 - x * inv，并约束 x * out = 0：
+/// x ≠ 0 时 out 只能为 0；x = 0 时 out = 1 - 0 = 1，inv 取任何值都一样
+pub fn is_zero<F: Field, C: NumInstructions<F>>(
+    chip: &C,
+    mut layouter: impl Layouter<F>,
+    x: C::Num,
+) -> Result<C::Num, Error>
+where
+    C::Num: Clone,
+{
+    let inv = C::debug_value(&x).map(|x| x.invert().unwrap_or(F::ZERO));
+    let inv = chip.load_private(layouter.namespace(|| "inv"), inv)?;
+    let x_inv = chip.mul(layouter.namespace(|| "x * inv"), x.clone(), inv)?;
+    let out = chip.linear_combination(
+        layouter.namespace(|| "1 - x * inv"),
+        &[x_inv],
+        &[-F::ONE],
+        F::ONE,
+    )?;
+    let x_out = chip.mul(layouter.namespace(|| "x * out"), x, out.clone())?;
+    chip.constrain_constant(layouter.namespace(|| "x * out = 0"), x_out, F::ZERO)?;
+    Ok(out)
+}
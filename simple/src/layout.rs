@@ -0,0 +1,279 @@
+use halo2_proofs::{
+    circuit::Value,
+    pasta::Fp,
+    plonk::{
+        Advice, Any, Assigned, Assignment, Circuit, Column, ConstraintSystem, Error, Fixed,
+        FloorPlanner, Instance, Selector,
+    },
+};
+
+///////////////////////////////////////////////////////////////////////
+/// 统计电路布局的调试工具
+///
+/// 记录每个 region 用到的行：实现 Assignment trait，让电路的 FloorPlanner 直接在它上面做一次赋值，
+/// 记录行号、拷贝约束的个数，以及 advice 和 fixed 列中已知的值，不检查约束
+struct RegionRecorder {
+    /// 电路的 k，行号不能超过 2^k
+    k: u32,
+    /// 已结束的 region：(名字, 占用的行数)
+    regions: Vec<(String, usize)>,
+    /// 当前 region 的名字和用到的最小、最大行号
+    current: Option<(String, Option<(usize, usize)>)>,
+    /// 整个电路用到的最大行号（包括 instance 列中被拷贝约束引用的行）
+    max_row: Option<usize>,
+    /// 拷贝约束的个数
+    copies: usize,
+    /// 赋值的单元格：(列, 行, 值)，只记录已知的值
+    assigned: Vec<(Column<Any>, usize, Fp)>,
+}
+
+impl RegionRecorder {
+    fn new(k: u32) -> Self {
+        RegionRecorder {
+            k,
+            regions: vec![],
+            current: None,
+            max_row: None,
+            copies: 0,
+            assigned: vec![],
+        }
+    }
+
+    fn record(&mut self, row: usize) -> Result<(), Error> {
+        if row >= 1 << self.k {
+            return Err(Error::NotEnoughRowsAvailable { current_k: self.k });
+        }
+        self.max_row = Some(self.max_row.map_or(row, |max| max.max(row)));
+        if let Some((_, rows)) = &mut self.current {
+            *rows = Some(match rows {
+                Some((min, max)) => ((*min).min(row), (*max).max(row)),
+                None => (row, row),
+            });
+        }
+        Ok(())
+    }
+
+    fn assign<VR: Into<Assigned<Fp>>>(
+        &mut self,
+        column: Column<Any>,
+        row: usize,
+        value: Value<VR>,
+    ) {
+        value.map(|v| self.assigned.push((column, row, v.into().evaluate())));
+    }
+}
+
+impl Assignment<Fp> for RegionRecorder {
+    fn enter_region<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.current = Some((name_fn().into(), None));
+    }
+
+    fn exit_region(&mut self) {
+        let (name, rows) = self
+            .current
+            .take()
+            .expect("exit_region without enter_region");
+        let count = rows.map_or(0, |(min, max)| max - min + 1);
+        self.regions.push((name, count));
+    }
+
+    fn enable_selector<A, AR>(&mut self, _: A, _: &Selector, row: usize) -> Result<(), Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.record(row)
+    }
+
+    fn query_instance(&self, _: Column<Instance>, _: usize) -> Result<Value<Fp>, Error> {
+        Ok(Value::unknown())
+    }
+
+    fn assign_advice<V, VR, A, AR>(
+        &mut self,
+        _: A,
+        column: Column<Advice>,
+        row: usize,
+        to: V,
+    ) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fp>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.record(row)?;
+        self.assign(column.into(), row, to());
+        Ok(())
+    }
+
+    fn assign_fixed<V, VR, A, AR>(
+        &mut self,
+        _: A,
+        column: Column<Fixed>,
+        row: usize,
+        to: V,
+    ) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<Fp>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.record(row)?;
+        self.assign(column.into(), row, to());
+        Ok(())
+    }
+
+    fn copy(
+        &mut self,
+        left_column: Column<Any>,
+        left_row: usize,
+        right_column: Column<Any>,
+        right_row: usize,
+    ) -> Result<(), Error> {
+        self.copies += 1;
+        // instance 列不会被赋值，只能从拷贝约束中知道用到了哪些行
+        for (column, row) in [(left_column, left_row), (right_column, right_row)] {
+            if *column.column_type() == Any::Instance {
+                self.record(row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_from_row(
+        &mut self,
+        _: Column<Fixed>,
+        _: usize,
+        _: Value<Assigned<Fp>>,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn push_namespace<NR, N>(&mut self, _: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self, _: Option<String>) {}
+}
+
+/// 电路中用于常量的 fixed 列（enable_constant 启用的列）
+/// ConstraintSystem 的 constants 字段不公开，也没有访问方法，这里从它的 Debug 输出中读取列的序号，
+/// 再在一个新的 ConstraintSystem 中依次申请 fixed 列，得到相同序号的 Column<Fixed>。
+/// Debug 输出中没有 constants 字段或序号无法解析时返回 None
+pub(crate) fn constant_columns(cs: &ConstraintSystem<Fp>) -> Option<Vec<Column<Fixed>>> {
+    let debug = format!("{:?}", cs);
+    let constants = debug.split("constants: [").nth(1)?.split(']').next()?;
+    let indices = constants
+        .split("index: ")
+        .skip(1)
+        .map(|rest| rest.split(',').next()?.trim().parse().ok())
+        .collect::<Option<Vec<usize>>>()?;
+
+    let mut scratch = ConstraintSystem::<Fp>::default();
+    let count = indices.iter().max().map_or(0, |max| max + 1);
+    let columns: Vec<Column<Fixed>> = (0..count).map(|_| scratch.fixed_column()).collect();
+    Some(indices.into_iter().map(|i| columns[i]).collect())
+}
+
+/// constant_columns 读取失败时 panic：这些调试工具需要常量列才能运行电路的 FloorPlanner
+fn expect_constant_columns(cs: &ConstraintSystem<Fp>) -> Vec<Column<Fixed>> {
+    constant_columns(cs).expect("ConstraintSystem debug output should list the constant columns")
+}
+
+/// 返回电路中每个 region 的名字和它占用的行数，按 region 的创建顺序排列
+/// 使用电路自己的 FloorPlanner 完成一次布局，行数超过 2^k 时 panic
+pub fn region_row_report<C: Circuit<Fp>>(k: u32, circuit: &C) -> Vec<(String, usize)> {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+    let constants = expect_constant_columns(&cs);
+
+    let mut recorder = RegionRecorder::new(k);
+    C::FloorPlanner::synthesize(&mut recorder, circuit, config, constants)
+        .expect("circuit synthesis failed");
+    recorder.regions
+}
+
+/// 测量时允许的最大 k
+const MEASURE_MAX_K: u32 = 28;
+
+/// 只测量、不赋值得到的电路规模
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitMeasurements {
+    /// 用到的行数（最大行号 + 1），包括 lookup 表、常量和 instance 列中被引用的行
+    pub rows: usize,
+    /// 拷贝约束的个数（包括 constrain_instance 和常量的拷贝约束）
+    pub copy_constraints: usize,
+    /// 盲化需要的行数：每一列的最后 blinding_factors + 1 行不能使用
+    pub blinding_rows: usize,
+    /// 能容纳电路的最小 k
+    pub k: u32,
+}
+
+/// 在只记录行号的 Assignment 上运行一次电路的 FloorPlanner，静态地计算电路需要的行数和最小的 k，
+/// 不需要像 prover::min_k 那样从小到大反复运行 MockProver
+pub fn measure<C: Circuit<Fp>>(circuit: &C) -> CircuitMeasurements {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+    let constants = expect_constant_columns(&cs);
+
+    let mut recorder = RegionRecorder::new(MEASURE_MAX_K);
+    C::FloorPlanner::synthesize(&mut recorder, circuit, config, constants)
+        .expect("circuit synthesis failed");
+
+    let rows = recorder.max_row.map_or(0, |max| max + 1);
+    let blinding_rows = cs.blinding_factors() + 1;
+    let needed = (rows + blinding_rows).max(cs.minimum_rows());
+    CircuitMeasurements {
+        rows,
+        copy_constraints: recorder.copies,
+        blinding_rows,
+        k: needed.next_power_of_two().trailing_zeros(),
+    }
+}
+
+/// 从 Debug 输出中读取 `name: <数字>` 形式的字段，用于读取 halo2 0.3 中不公开的字段
+pub(crate) fn debug_field(debug: &str, name: &str) -> usize {
+    debug
+        .split(&format!("{}: ", name))
+        .nth(1)
+        .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+        .and_then(|n| n.parse().ok())
+        .unwrap_or_else(|| panic!("debug output should contain {}", name))
+}
+
+/// 导出电路赋值后的表格，用于可视化：外层按列，内层按行（共 2^k 行），未赋值或值未知的单元格为 None
+/// 列的顺序为先 advice 列、后 fixed 列，各自按列的序号排列；选择器和 instance 列不包括在内
+/// MockProver 中保存的赋值在 halo2 0.3 中不公开，这里用记录赋值的 Assignment 运行一次电路的 FloorPlanner；
+/// 列的个数和序号同样不公开，从 Debug 输出中读取
+pub fn dump_assignments<C: Circuit<Fp>>(k: u32, circuit: &C) -> Vec<Vec<Option<Fp>>> {
+    let mut cs = ConstraintSystem::default();
+    let config = C::configure(&mut cs);
+    let constants = expect_constant_columns(&cs);
+
+    let mut recorder = RegionRecorder::new(k);
+    C::FloorPlanner::synthesize(&mut recorder, circuit, config, constants)
+        .expect("circuit synthesis failed");
+
+    let debug = format!("{:?}", cs);
+    let num_advice = debug_field(&debug, "num_advice_columns");
+    let num_fixed = debug_field(&debug, "num_fixed_columns");
+    let mut table = vec![vec![None; 1 << k]; num_advice + num_fixed];
+    for (column, row, value) in recorder.assigned {
+        let index = debug_field(&format!("{:?}", column), "index");
+        let index = match column.column_type() {
+            Any::Advice => index,
+            _ => num_advice + index,
+        };
+        table[index][row] = Some(value);
+    }
+    table
+}
@@ -0,0 +1,1701 @@
+#![allow(unused)]
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Region, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{
+        Advice, Any, Circuit, Column, ConstraintSystem, Error, Expression, Fixed, Instance,
+        Selector, TableColumn,
+    },
+    poly::Rotation,
+};
+use std::{collections::HashMap, fmt, marker::PhantomData};
+
+pub mod backend;
+pub mod const_fold;
+pub mod dev;
+pub mod dynamic;
+#[cfg(feature = "faults")]
+pub mod faults;
+pub mod field;
+pub mod gadgets;
+pub mod layout;
+pub mod planner;
+pub mod prover;
+pub mod report;
+pub mod transcript;
+pub mod witness;
+
+/// 启用 trace feature 时，用 log crate 记录每次指令调用：指令名、region 名，以及单元格的值（未知时为 unknown）
+/// 未启用时宏展开为空，不会有任何开销
+macro_rules! trace_op {
+    ($op:expr, $region:expr, $($cell:expr),+ $(,)?) => {
+        trace_op!($op, $region; [$($cell),+])
+    };
+    ($op:expr, $region:expr; $cells:expr) => {
+        #[cfg(feature = "trace")]
+        {
+            let values: Vec<String> = $cells.into_iter().map(ACell::trace_value).collect();
+            log::trace!(
+                target: "simple::synthesis",
+                "{} (region \"{}\"): {}",
+                $op,
+                $region,
+                values.join(", ")
+            );
+        }
+    };
+}
+
+/// 定义只有一个多项式约束的简单门：分配选择器，按 (列, 偏移) 查询 advice 单元格，
+/// 创建约束为 selector * (expr) 的门，返回分配的选择器
+///
+/// ```ignore
+/// let s_mul = simple_gate!(meta, "mul", {
+///     lhs: (advice[0], Rotation::cur()),
+///     rhs: (advice[1], Rotation::cur()),
+///     out: (advice[0], Rotation::next()),
+/// } => lhs * rhs - out);
+/// ```
+#[macro_export]
+macro_rules! simple_gate {
+    ($meta:expr, $gate:expr, { $($cell:ident : ($column:expr, $rotation:expr)),+ $(,)? } => $poly:expr) => {{
+        let selector = $meta.selector();
+        $meta.create_gate($gate, |meta| {
+            $(let $cell = meta.query_advice($column, $rotation);)+
+            let selector = meta.query_selector(selector);
+            vec![selector * ($poly)]
+        });
+        selector
+    }};
+}
+
+/// 这是学习 halo2 的第一个应用例子，主要用来熟悉 zcash-halo2 所提供的API。
+/// 解析参考：https://learnblockchain.cn/article/3442
+/// 例子用来计算和证明 a^2 * b^2 = c, 其中 a、b 为 private input，c 为 public input
+///
+///////////////////////////////////////////////////////////////////////
+/// 1、定义自定义的指令集，本例中指令包括4个指令：加载私有变量， 加载常量， 计算2个数的乘法，导出公共输入
+///
+/// 定义一个 NumInstructions trait，要求实现这个 trait 的类型，需要先实现在F域上 Chip 的 trait.
+pub trait NumInstructions<F: Field>: Chip<F> {
+    /// 要求实现这个trait时，先确定一个Num的类型
+    type Num;
+    /// 指令1：加载私有变量. 等价于 load_private<L: Layouter<F>>(&self, layouter: L, a: Value<F>)
+    fn load_private(&self, layouter: impl Layouter<F>, a: Value<F>) -> Result<Self::Num, Error>;
+    /// 指令2：加载常量
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error>;
+    /// 指令3：两个Num类型的乘法
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令4：将一个数设置为电路的公共输出
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        c: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+    /// 指令5：计算 a^exp，exp 为电路外固定的指数，由多次乘法（平方-乘）实现
+    fn pow(&self, layouter: impl Layouter<F>, a: Self::Num, exp: u64) -> Result<Self::Num, Error>;
+    /// 指令6：两个Num类型的加法
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令7：乘以一个电路外固定的常数，常数通过 load_constant 加载后再做乘法
+    fn mul_by_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        constant: F,
+    ) -> Result<Self::Num, Error>;
+    /// 指令8：对若干个数求和（链式加法），输入为空时返回加载的常数 0
+    fn sum(&self, layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error>;
+    /// 指令9：对若干个数求积（链式乘法），输入为空时返回加载的常数 1
+    fn product(&self, layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error>;
+    /// 指令10：约束一个已赋值的数等于电路外固定的常数 k（常数放在 fixed 列中，不会出现在 instance 列）
+    fn constrain_constant(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        k: F,
+    ) -> Result<(), Error>;
+    /// 指令11：条件选择，bit 为 1 时返回 a，为 0 时返回 b；同时约束 bit 为布尔值
+    fn select(
+        &self,
+        layouter: impl Layouter<F>,
+        bit: Self::Num,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令12：计算 base^exp，exp 由电路中的布尔单元格给出（低位在前），用平方-乘和 select 实现
+    /// exp_bits 为空时返回加载的常数 1
+    fn pow_var(
+        &self,
+        layouter: impl Layouter<F>,
+        base: Self::Num,
+        exp_bits: &[Self::Num],
+    ) -> Result<Self::Num, Error>;
+    /// 指令13：点积 xs · ws，ws 为电路外固定的常数，由 mul_by_constant 和 sum 实现
+    /// xs 和 ws 的长度不同时返回 Error::Synthesis
+    fn dot(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+    ) -> Result<Self::Num, Error>;
+    /// 指令14：布尔与 a ∧ b = a * b，约束 a、b 为布尔值
+    fn and(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令15：布尔或 a ∨ b = a + b - a * b，约束 a、b 为布尔值
+    fn or(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令16：线性组合 xs · ws + constant，ws、constant 为电路外固定的常数，由 dot 和 add 实现
+    fn linear_combination(
+        &self,
+        layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+        constant: F,
+    ) -> Result<Self::Num, Error>;
+    /// 指令17：从 instance 列的第 row 行加载 public input，并约束它等于电路外固定的常数 expected
+    /// 只有 public input 等于 expected 时电路才能满足
+    fn load_and_pin_instance(
+        &self,
+        layouter: impl Layouter<F>,
+        row: usize,
+        expected: F,
+    ) -> Result<Self::Num, Error>;
+    /// 指令18：计算 a^{-1}：加载 witness a_inv，约束 a * a_inv = 1，a = 0 时约束无法满足
+    fn inv(&self, layouter: impl Layouter<F>, a: Self::Num) -> Result<Self::Num, Error>;
+    /// 指令19：从 instance 列的第 row 行加载 public input 到 advice 列（带拷贝约束），作为电路的输入
+    fn load_instance(&self, layouter: impl Layouter<F>, row: usize) -> Result<Self::Num, Error>;
+    /// 指令20：证明 a 是二次剩余：加载平方根 r 作为 witness，约束 r * r = a，返回 r
+    /// a 没有平方根时约束无法满足
+    fn assert_is_square(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+    ) -> Result<Self::Num, Error>;
+    /// 指令21：约束两个已赋值的单元格相等，a、b 可以来自任意两个不同的 region
+    /// 与 copy_advice 不同，这里不复制任何单元格，只在一个空的 region 中添加一条拷贝约束
+    fn link(&self, layouter: impl Layouter<F>, a: Self::Num, b: Self::Num) -> Result<(), Error>;
+    /// 指令22：约束 a^2 + b^2 = c^2（勾股数）：用 mul 计算三个平方，add 求和，再用 link 约束和等于 c^2
+    /// 注意这是域中的等式，不检查 a、b、c 是正整数
+    fn assert_pythagorean(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        c: Self::Num,
+    ) -> Result<(), Error>;
+    /// 指令23：加权平均 (values · weights) * total_weight_inv，weights 和总权重的逆 total_weight_inv 为电路外固定的常数，
+    /// 由 dot（即常数项为 0 的 linear_combination）和 mul_by_constant 实现。
+    /// 电路不检查 total_weight_inv 确实是权重之和的逆，它和 weights 一样是电路结构的一部分。
+    /// values 和 weights 的长度不同时返回 Error::Synthesis
+    fn weighted_average(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Self::Num],
+        weights: &[F],
+        total_weight_inv: F,
+    ) -> Result<Self::Num, Error>;
+    /// 调试用：取出单元格中的值。不会添加任何约束，生成密钥时值为 unknown
+    fn debug_value(cell: &Self::Num) -> Value<F>;
+    /// 调试用：打印单元格中的值，值未知时打印 unknown
+    fn print_cell(label: &str, cell: &Self::Num) {
+        let mut known = false;
+        Self::debug_value(cell).map(|v| {
+            known = true;
+            println!("{}: {:?}", label, v);
+        });
+        if !known {
+            println!("{}: unknown", label);
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 2、定义芯片，在芯片结构中实现上面定义的指令集的trait（可以理解为接口）
+/// 电路由一个个Chip逻辑堆砌而成。每个Chip的创建从 Config 开始。
+/// 定义芯片配置结构 SimpleConfig（Config 就是申请Chip需要的Column以及配置Fixed列的逻辑含义。这些配置可能是Custom Gate，可能是lookup）
+/// Plonk Configuation ccolumns: fixed, advice, instance
+/// N 为 advice 列的数量，默认为 2，至少为 2（乘法门和加法门需要两列）
+#[derive(Clone, Debug)]
+pub struct SimpleConfig<const N: usize = 2> {
+    //这里将用到 N 个 advice 列来实现自定义的指令集（advice: private + 中间值）
+    advice: [Column<Advice>; N],
+    // public input (instance)
+    instance: Column<Instance>,
+    // 常量列（enable_constant 启用的 fixed 列）
+    constant: Column<Fixed>,
+    // 选择子，激活乘法门
+    // 从而在用不到上面定义的 NumInstructions::mul指令的单元格上不设置任何约束
+    s_mul: Selector,
+    // 选择子，激活加法门
+    s_add: Selector,
+    // 选择子，激活 N 个数相加的门
+    s_add_n: Selector,
+    // 选择子，激活条件选择门
+    s_select: Selector,
+    // 选择子，激活布尔与、布尔或门
+    s_and: Selector,
+    s_or: Selector,
+    // 选择子，激活二进制分解门
+    s_bits: Selector,
+    // 8 bit 的异或 lookup 表，只有 configure_with_xor_table 配置的芯片才有
+    xor_table: Option<XorTableConfig>,
+}
+
+/// 8 bit 的异或表：(x, y, x ^ y)，共 2^16 行，使用它的电路至少需要 k = 17。
+/// s_xor 启用的行查询 (a0 当前行, a1 当前行, a0 下一行)，见 SimpleChip::configure_with_xor_table
+#[derive(Clone, Debug)]
+pub struct XorTableConfig {
+    s_xor: Selector,
+    table: [TableColumn; 3],
+}
+impl<const N: usize> SimpleConfig<N> {
+    /// 每一列的名字，用于 dev::verify_annotated 格式化失败信息
+    /// advice[0] 放左操作数和结果，advice[1] 放右操作数，其余的 advice 列只在 add_n 中放输入
+    pub fn column_names(&self) -> Vec<(Column<Any>, String)> {
+        let mut names: Vec<(Column<Any>, String)> = self
+            .advice
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let name = match i {
+                    0 => "lhs/out".to_string(),
+                    1 => "rhs".to_string(),
+                    i => format!("x_{}", i),
+                };
+                ((*column).into(), name)
+            })
+            .collect();
+        names.push((self.instance.into(), "public".to_string()));
+        names.push((self.constant.into(), "constant".to_string()));
+        names
+    }
+}
+
+/// 定义自定义芯片，芯片结构中包含了上面的配置，和一个占位符（https://rustwiki.org/zh-CN/std/marker/struct.PhantomData.html）
+pub struct SimpleChip<F: Field, const N: usize = 2> {
+    config: SimpleConfig<N>,
+    _marker: PhantomData<F>,
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 3、实现自定义芯片的配置
+/// Configure调用ConstraintSystem申请各种列以及Gate的信息。
+/// 调用某个Circuit的Configure函数会顺序调用电路涉及到的Chip的Configure信息，这些信息都记录在ConstraintSystem中。
+impl<F: Field, const N: usize> SimpleChip<F, N> {
+    // 默认构造方法
+    pub fn construct(config: <Self as Chip<F>>::Config) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+    /// 自定义配置：构建约束！
+    /// 输入包括 advice, instance, fixed
+    /// 约束包括：相等约束，选择器构建的乘法约束
+    /// 返回多项式约束
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>, // 约束系统：这是对电路环境的描述，例如门、列和排列的安排。
+        advice: [Column<Advice>; N],    // private input + 中间值
+        instance: Column<Instance>,     // public input
+        constant: Column<Fixed>,        // selector
+    ) -> SimpleConfig<N> {
+        assert!(N >= 2, "SimpleChip needs at least 2 advice columns");
+        // 启用强制执行指定列中的单元格相等的功能
+        meta.enable_equality(instance);
+        for c in &advice {
+            meta.enable_equality(*c);
+        }
+        // 使该固定列能够用于全局常量赋值。此外，该列也将默认启用 enable_equality
+        meta.enable_constant(constant);
+        // 定义乘法门，返回激活乘法门的选择器
+        // create_gate 返回多项式表达式的约束，在证明系统中一定等于0
+        // 这里需要3个 advice cells 和 1个 selector cell 来实现乘法
+        // 参考官方案例，把他们按下表来排列：
+        //
+        // | a0  | a1  | s_mul |
+        // |-----|-----|-------|
+        // | lhs | rhs | s_mul |
+        // | out |     |       |
+        // 门可以用任一相对偏移，但每一个不同的偏移都会对证明增加开销。
+        // 最常见的偏移值是 0 (当前行), 1(下一行), -1(上一行)。
+        // 当不是乘法时，s_mul为0，lhs、rhs、out可以时任何值，返回仍为0
+        // 当是乘法时，s_mul为1，lhs、rhs、out必须满足 lhs * rhs - out = 0 的约束
+        let s_mul = simple_gate!(meta, "mul", {
+            lhs: (advice[0], Rotation::cur()),
+            rhs: (advice[1], Rotation::cur()),
+            out: (advice[0], Rotation::next()),
+        } => lhs * rhs - out);
+
+        // 定义加法门，布局与乘法门相同
+        // | a0  | a1  | s_add |
+        // |-----|-----|-------|
+        // | lhs | rhs | s_add |
+        // | out |     |       |
+        let s_add = simple_gate!(meta, "add", {
+            lhs: (advice[0], Rotation::cur()),
+            rhs: (advice[1], Rotation::cur()),
+            out: (advice[0], Rotation::next()),
+        } => lhs + rhs - out);
+
+        // 定义 N 个数相加的门，一行放下 N 个输入
+        // | a0  | a1  | ... | a(N-1) | s_add_n |
+        // |-----|-----|-----|--------|---------|
+        // | x0  | x1  | ... | x(N-1) | s_add_n |
+        // | out |     |     |        |         |
+        let s_add_n = meta.selector();
+        meta.create_gate("add_n", |meta| {
+            let sum = advice
+                .iter()
+                .map(|c| meta.query_advice(*c, Rotation::cur()))
+                .reduce(|acc, x| acc + x)
+                .unwrap();
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_add_n = meta.query_selector(s_add_n);
+            vec![s_add_n * (sum - out)]
+        });
+
+        // 定义条件选择门：out = bit * a + (1 - bit) * b，并约束 bit * (1 - bit) = 0
+        // | a0  | a1  | s_select |
+        // |-----|-----|----------|
+        // | bit | a   | s_select |
+        // | b   | out |          |
+        let s_select = meta.selector();
+        meta.create_gate("select", |meta| {
+            let bit = meta.query_advice(advice[0], Rotation::cur());
+            let a = meta.query_advice(advice[1], Rotation::cur());
+            let b = meta.query_advice(advice[0], Rotation::next());
+            let out = meta.query_advice(advice[1], Rotation::next());
+            let s_select = meta.query_selector(s_select);
+            let one = Expression::Constant(F::ONE);
+            vec![
+                s_select.clone() * bit.clone() * (one.clone() - bit.clone()),
+                s_select * (bit.clone() * a + (one - bit) * b - out),
+            ]
+        });
+
+        // 定义布尔与、布尔或门，布局与乘法门相同，同时约束两个输入为布尔值
+        // | a0  | a1  | s_and / s_or |
+        // |-----|-----|--------------|
+        // | lhs | rhs | 1            |
+        // | out |     |              |
+        let s_and = meta.selector();
+        meta.create_gate("and", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_and = meta.query_selector(s_and);
+            let one = Expression::Constant(F::ONE);
+            vec![
+                s_and.clone() * lhs.clone() * (one.clone() - lhs.clone()),
+                s_and.clone() * rhs.clone() * (one - rhs.clone()),
+                s_and * (lhs * rhs - out),
+            ]
+        });
+        let s_or = meta.selector();
+        meta.create_gate("or", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s_or = meta.query_selector(s_or);
+            let one = Expression::Constant(F::ONE);
+            vec![
+                s_or.clone() * lhs.clone() * (one.clone() - lhs.clone()),
+                s_or.clone() * rhs.clone() * (one - rhs.clone()),
+                s_or * (lhs.clone() + rhs.clone() - lhs * rhs - out),
+            ]
+        });
+
+        // 定义二进制分解门（低位在前的 running sum）：z_i = 2 * z_{i+1} + b_i，并约束 b_i 为布尔值
+        // | a0      | a1  | s_bits |
+        // |---------|-----|--------|
+        // | z_0 = a | b_0 | 1      |
+        // | z_1     | b_1 | 1      |
+        // | ...     | ... | ...    |
+        // | z_n = 0 |     |        |
+        let s_bits = meta.selector();
+        meta.create_gate("bits", |meta| {
+            let z_cur = meta.query_advice(advice[0], Rotation::cur());
+            let bit = meta.query_advice(advice[1], Rotation::cur());
+            let z_next = meta.query_advice(advice[0], Rotation::next());
+            let s_bits = meta.query_selector(s_bits);
+            let one = Expression::Constant(F::ONE);
+            let two = Expression::Constant(F::ONE.double());
+            vec![
+                s_bits.clone() * bit.clone() * (one - bit.clone()),
+                s_bits * (z_cur - two * z_next - bit),
+            ]
+        });
+
+        SimpleConfig {
+            advice,
+            instance,
+            constant,
+            s_mul,
+            s_add,
+            s_add_n,
+            s_select,
+            s_and,
+            s_or,
+            s_bits,
+            xor_table: None,
+        }
+    }
+
+    /// 与 configure 相同，另外配置 8 bit 的异或 lookup 表，xor_fold 改为链式查表，每个字节只需要一行。
+    /// 电路需要在 synthesize 中调用 load_xor_table 加载表，k 至少为 17
+    ///
+    /// | a0        | a1    | s_xor |
+    /// |-----------|-------|-------|
+    /// | acc_0 = 0 | x_0   | 1     |
+    /// | acc_1     | x_1   | 1     |
+    /// | ...       | ...   | ...   |
+    /// | acc_n     |       |       |
+    ///
+    /// 每个启用的行查询 (acc_i, x_i, acc_{i+1}) 是否在表中，即 acc_{i+1} = acc_i ^ x_i，同时约束 x_i 小于 2^8
+    pub fn configure_with_xor_table(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; N],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+    ) -> SimpleConfig<N> {
+        let mut config = Self::configure(meta, advice, instance, constant);
+        // lookup 中使用的选择器必须是 complex selector
+        let s_xor = meta.complex_selector();
+        let table = [
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+        ];
+        // 未启用的行输入为 (0, 0, 0)，0 ^ 0 = 0 在表中，总能通过
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_xor);
+            let x = meta.query_advice(advice[0], Rotation::cur());
+            let y = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            vec![
+                (s.clone() * x, table[0]),
+                (s.clone() * y, table[1]),
+                (s * out, table[2]),
+            ]
+        });
+        config.xor_table = Some(XorTableConfig { s_xor, table });
+        config
+    }
+
+    /// 在一个 region 中计算 N 个数的和：N 个输入放在同一行，结果放在下一行
+    /// 与 sum 指令的链式加法相比，只需要一个 region 和两行
+    pub fn add_n(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: [ACell<F>; N],
+    ) -> Result<ACell<F>, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "add_n",
+            |mut region| {
+                config.s_add_n.enable(&mut region, 0)?;
+                let mut res = Value::known(F::ZERO);
+                for (i, x) in xs.iter().enumerate() {
+                    x.0.copy_advice(|| "input", &mut region, config.advice[i], 0)?;
+                    res = res + x.0.value();
+                }
+                region
+                    .assign_advice(|| "sum", config.advice[0], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("add_n", "add_n", &res);
+        Ok(res)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 4、实现芯片核心功能
+/// 除了实现simpleChip本身的方法之外，还需要为SimpleShip实现自定义的 NumInstructions Trait，
+/// 以及所有芯片必须实现的 Chip trait
+///
+/// 先为自定义的芯片实现 Chip trait（因为要实现 NumInstructions trait的类型必须先实现在F域上 Chip 的 trait）
+/// 或者说，每一个芯片类型，都要实现 chip trait
+/// Chip 的定义可见：https://docs.rs/halo2_proofs/latest/halo2_proofs/circuit/trait.Chip.html
+/// Chip trait 定义了布局器在设计电路约束时，需要用到的列。trait中必须实现 Config, Loaded, config(), loaded()
+impl<F: Field, const N: usize> Chip<F> for SimpleChip<F, N> {
+    type Config = SimpleConfig<N>;
+    type Loaded = ();
+    // 自定义的芯片配置
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+    // 该芯片加载到电路所需要设置的初始状态，这里设置为空
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// 接着为 SimpleChip实现自定义的 NumInstructions Trait
+/// 定义tuple struct ACell，用于简化与电路中单元格的交互：
+/// 1、通过使用 ACell，我们为用户提供了一个简化和更直观的接口，使他们可以更容易地与已分配的单元格进行交互，而不必每次都直接处理 AssignedCell
+/// 2、将来，如果我们想在 ACell 中添加更多的功能或属性，可以不影响现有的代码
+#[derive(Clone, Debug)]
+pub struct ACell<F: Field>(AssignedCell<F, F>);
+
+/// 与其它芯片组合时，需要在 ACell 和 AssignedCell 之间转换
+impl<F: Field> ACell<F> {
+    pub fn inner(&self) -> &AssignedCell<F, F> {
+        &self.0
+    }
+}
+
+#[cfg(feature = "trace")]
+impl<F: Field> ACell<F> {
+    fn trace_value(&self) -> String {
+        let mut value = String::from("unknown");
+        self.0.value().map(|v| value = format!("{:?}", v));
+        value
+    }
+}
+
+impl<F: Field> From<AssignedCell<F, F>> for ACell<F> {
+    fn from(cell: AssignedCell<F, F>) -> Self {
+        ACell(cell)
+    }
+}
+
+impl<F: Field, const N: usize> NumInstructions<F> for SimpleChip<F, N> {
+    type Num = ACell<F>;
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "load_private",
+            |mut region| {
+                region
+                    .assign_advice(|| "private input", config.advice[0], 0, || a)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("load_private", "load_private", &res);
+        Ok(res)
+    }
+
+    fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "load_constant",
+            |mut region| {
+                region
+                    .assign_advice_from_constant(|| "constant value", config.advice[0], 0, constant)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("load_constant", "load_constant", &res);
+        Ok(res)
+    }
+
+    fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "mul",
+            |mut region| {
+                /// 在这个芯片区域中，我们只使用了乘法，所以只需要激活 s_mul
+                config.s_mul.enable(&mut region, 0);
+                /// 官方解释：给我们的输入(a: Self::Num / b: Self::Num,) 有可能在电路的任何位置.
+                /// 但在region 中，我们只能依靠相对偏移。所以我们需要在 region 内分配新的 cells
+                /// 并限制新分配的 cells 的值 与输入(a: Self::Num / b: Self::Num,) 的值相等。
+                /// copy_advice：将值复制到给定的advice cell并约束它们相等
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0);
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0);
+                /// 计算乘积
+                let res = a.0.value().copied() * b.0.value();
+                /// 对输出赋值，cell所在位置在config中定义过，这里使用相对位置定位
+                region
+                    .assign_advice(|| "lhs * rhs", config.advice[0], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("mul", "mul", &res);
+        Ok(res)
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        c: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        trace_op!("expose_public", "instance", &c);
+        layouter.constrain_instance(c.0.cell(), config.instance, row)
+    }
+
+    fn pow(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        exp: u64,
+    ) -> Result<Self::Num, Error> {
+        if exp == 0 {
+            return self.load_constant(layouter.namespace(|| "a^0"), F::ONE);
+        }
+        // 从最高位开始扫描指数的二进制位：每一位先平方，该位为1时再乘以 a
+        let mut acc = a.clone();
+        for i in (0..(63 - exp.leading_zeros())).rev() {
+            acc = self.mul(layouter.namespace(|| "square"), acc.clone(), acc)?;
+            if (exp >> i) & 1 == 1 {
+                acc = self.mul(layouter.namespace(|| "multiply"), acc, a.clone())?;
+            }
+        }
+        Ok(acc)
+    }
+
+    fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "add",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                let res = a.0.value().copied() + b.0.value();
+                region
+                    .assign_advice(|| "lhs + rhs", config.advice[0], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("add", "add", &res);
+        Ok(res)
+    }
+
+    fn mul_by_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        let k = self.load_constant(layouter.namespace(|| "load constant"), constant)?;
+        self.mul(layouter.namespace(|| "a * constant"), a, k)
+    }
+
+    fn sum(&self, mut layouter: impl Layouter<F>, xs: &[Self::Num]) -> Result<Self::Num, Error> {
+        let Some((first, rest)) = xs.split_first() else {
+            return self.load_constant(layouter.namespace(|| "empty sum"), F::ZERO);
+        };
+        rest.iter().try_fold(first.clone(), |acc, x| {
+            self.add(layouter.namespace(|| "sum"), acc, x.clone())
+        })
+    }
+
+    fn product(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        let Some((first, rest)) = xs.split_first() else {
+            return self.load_constant(layouter.namespace(|| "empty product"), F::ONE);
+        };
+        rest.iter().try_fold(first.clone(), |acc, x| {
+            self.mul(layouter.namespace(|| "product"), acc, x.clone())
+        })
+    }
+
+    fn constrain_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        k: F,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "constrain_constant",
+            |mut region| {
+                // assign_advice_from_constant 把 advice 单元格与常量列中的 k 绑定，
+                // 再用拷贝约束让 a 等于这个单元格
+                let k = region.assign_advice_from_constant(|| "k", config.advice[0], 0, k)?;
+                region.constrain_equal(a.0.cell(), k.cell())
+            },
+        )?;
+        trace_op!("constrain_constant", "constrain_constant", &a);
+        Ok(())
+    }
+
+    fn select(
+        &self,
+        mut layouter: impl Layouter<F>,
+        bit: Self::Num,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "select",
+            |mut region| {
+                config.s_select.enable(&mut region, 0)?;
+                bit.0
+                    .copy_advice(|| "bit", &mut region, config.advice[0], 0)?;
+                a.0.copy_advice(|| "a", &mut region, config.advice[1], 0)?;
+                b.0.copy_advice(|| "b", &mut region, config.advice[0], 1)?;
+                let res = bit
+                    .0
+                    .value()
+                    .zip(a.0.value())
+                    .zip(b.0.value())
+                    .map(|((bit, a), b)| *bit * a + (F::ONE - bit) * b);
+                region
+                    .assign_advice(|| "bit ? a : b", config.advice[1], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("select", "select", &res);
+        Ok(res)
+    }
+
+    fn pow_var(
+        &self,
+        mut layouter: impl Layouter<F>,
+        base: Self::Num,
+        exp_bits: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        // 从最高位开始：每一位先平方，再根据该位选择是否乘以 base
+        let mut acc = self.load_constant(layouter.namespace(|| "one"), F::ONE)?;
+        for bit in exp_bits.iter().rev() {
+            acc = self.mul(layouter.namespace(|| "square"), acc.clone(), acc)?;
+            let multiplied =
+                self.mul(layouter.namespace(|| "multiply"), acc.clone(), base.clone())?;
+            acc = self.select(
+                layouter.namespace(|| "select"),
+                bit.clone(),
+                multiplied,
+                acc,
+            )?;
+        }
+        Ok(acc)
+    }
+
+    fn dot(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+    ) -> Result<Self::Num, Error> {
+        if xs.len() != ws.len() {
+            return Err(Error::Synthesis);
+        }
+        let terms = xs
+            .iter()
+            .zip(ws)
+            .map(|(x, w)| self.mul_by_constant(layouter.namespace(|| "x * w"), x.clone(), *w))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.sum(layouter.namespace(|| "sum of terms"), &terms)
+    }
+
+    fn and(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "and",
+            |mut region| {
+                config.s_and.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                let res = a.0.value().copied() * b.0.value();
+                region
+                    .assign_advice(|| "lhs and rhs", config.advice[0], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("and", "and", &res);
+        Ok(res)
+    }
+
+    fn or(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "or",
+            |mut region| {
+                config.s_or.enable(&mut region, 0)?;
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                let res = a.0.value().zip(b.0.value()).map(|(a, b)| *a + b - *a * b);
+                region
+                    .assign_advice(|| "lhs or rhs", config.advice[0], 1, || res)
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("or", "or", &res);
+        Ok(res)
+    }
+
+    fn linear_combination(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xs: &[Self::Num],
+        ws: &[F],
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        let dot = self.dot(layouter.namespace(|| "xs · ws"), xs, ws)?;
+        let constant = self.load_constant(layouter.namespace(|| "load constant"), constant)?;
+        self.add(layouter.namespace(|| "xs · ws + constant"), dot, constant)
+    }
+
+    fn load_and_pin_instance(
+        &self,
+        mut layouter: impl Layouter<F>,
+        row: usize,
+        expected: F,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "load_and_pin_instance",
+            |mut region| {
+                // assign_advice_from_instance 同时添加了 instance 单元格与 advice 单元格的拷贝约束
+                let cell = region.assign_advice_from_instance(
+                    || "public input",
+                    config.instance,
+                    row,
+                    config.advice[0],
+                    0,
+                )?;
+                let pinned = region.assign_advice_from_constant(
+                    || "expected",
+                    config.advice[1],
+                    0,
+                    expected,
+                )?;
+                region.constrain_equal(cell.cell(), pinned.cell())?;
+                Ok(ACell(cell))
+            },
+        )?;
+        trace_op!("load_and_pin_instance", "load_and_pin_instance", &res);
+        Ok(res)
+    }
+
+    fn inv(&self, mut layouter: impl Layouter<F>, a: Self::Num) -> Result<Self::Num, Error> {
+        // a = 0 时没有逆元，witness 随便取 0，乘积为 0，下面的常数约束不满足
+        let a_inv = a.0.value().map(|a| a.invert().unwrap_or(F::ZERO));
+        let a_inv = self.load_private(layouter.namespace(|| "load a_inv"), a_inv)?;
+        let product = self.mul(layouter.namespace(|| "a * a_inv"), a, a_inv.clone())?;
+        self.constrain_constant(layouter.namespace(|| "a * a_inv = 1"), product, F::ONE)?;
+        Ok(a_inv)
+    }
+
+    fn load_instance(
+        &self,
+        mut layouter: impl Layouter<F>,
+        row: usize,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        let res = layouter.assign_region(
+            || "load_instance",
+            |mut region| {
+                region
+                    .assign_advice_from_instance(
+                        || "public input",
+                        config.instance,
+                        row,
+                        config.advice[0],
+                        0,
+                    )
+                    .map(ACell)
+            },
+        )?;
+        trace_op!("load_instance", "load_instance", &res);
+        Ok(res)
+    }
+
+    fn assert_is_square(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+        // a 没有平方根时，witness 随便取 0，r * r = 0 != a，乘法门不满足
+        let root = a.0.value().map(|a| a.sqrt().unwrap_or(F::ZERO));
+        // 复用乘法门，两个乘数是同一个 r
+        // | a0  | a1  | s_mul |
+        // |-----|-----|-------|
+        // | r   | r   | 1     |
+        // | a   |     |       |
+        let res = layouter.assign_region(
+            || "is_square",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+                let lhs = region.assign_advice(|| "r", config.advice[0], 0, || root)?;
+                let rhs = region.assign_advice(|| "r", config.advice[1], 0, || root)?;
+                region.constrain_equal(lhs.cell(), rhs.cell())?;
+                a.0.copy_advice(|| "a", &mut region, config.advice[0], 1)?;
+                Ok(ACell(lhs))
+            },
+        )?;
+        trace_op!("assert_is_square", "is_square", &a, &res);
+        Ok(res)
+    }
+
+    fn link(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<(), Error> {
+        trace_op!("link", "link", &a, &b);
+        // 拷贝约束是全局的，与在哪个 region 中添加无关；这个 region 不占用任何行
+        layouter.assign_region(
+            || "link",
+            |mut region| region.constrain_equal(a.0.cell(), b.0.cell()),
+        )
+    }
+
+    fn assert_pythagorean(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        c: Self::Num,
+    ) -> Result<(), Error> {
+        let a2 = self.mul(layouter.namespace(|| "a^2"), a.clone(), a)?;
+        let b2 = self.mul(layouter.namespace(|| "b^2"), b.clone(), b)?;
+        let c2 = self.mul(layouter.namespace(|| "c^2"), c.clone(), c)?;
+        let sum = self.add(layouter.namespace(|| "a^2 + b^2"), a2, b2)?;
+        self.link(layouter.namespace(|| "a^2 + b^2 = c^2"), sum, c2)
+    }
+
+    fn weighted_average(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: &[Self::Num],
+        weights: &[F],
+        total_weight_inv: F,
+    ) -> Result<Self::Num, Error> {
+        if values.len() != weights.len() {
+            return Err(Error::Synthesis);
+        }
+        let weighted_sum = self.dot(layouter.namespace(|| "values · weights"), values, weights)?;
+        self.mul_by_constant(
+            layouter.namespace(|| "weighted sum / total weight"),
+            weighted_sum,
+            total_weight_inv,
+        )
+    }
+
+    fn debug_value(cell: &Self::Num) -> Value<F> {
+        cell.0.value().copied()
+    }
+}
+
+/// 在电路外把 a 分解为 base 进制的数字（低位在前）：对 a 的规范表示（小端序字节）反复做长除法，
+/// 前 num_digits - 1 个为余数，最后一个为剩下的商（可能不小于 base）
+fn radix_digits<F: PrimeField>(a: &F, base: u64, num_digits: usize) -> Vec<F> {
+    // 大端序的字节
+    let mut bytes: Vec<u8> = a.to_repr().as_ref().iter().rev().copied().collect();
+    let mut digits = Vec::with_capacity(num_digits);
+    for _ in 0..num_digits - 1 {
+        let mut rem: u128 = 0;
+        for b in bytes.iter_mut() {
+            let cur = (rem << 8) | *b as u128;
+            *b = (cur / base as u128) as u8;
+            rem = cur % base as u128;
+        }
+        digits.push(F::from(rem as u64));
+    }
+    // 剩下的商：从大端序字节还原为域元素
+    let quotient = bytes
+        .iter()
+        .fold(F::ZERO, |acc, b| acc * F::from(256) + F::from(*b as u64));
+    digits.push(quotient);
+    digits
+}
+
+/// 需要取出域元素二进制位的指令，只对 PrimeField 实现（Field 没有规范的字节表示）
+pub trait BitInstructions<F: PrimeField>: NumInstructions<F> {
+    /// 把 a 分解为 num_bits 个布尔单元格（低位在前），并约束 a = Σ b_i * 2^i
+    /// a >= 2^num_bits 时约束无法满足
+    fn to_bits(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        num_bits: usize,
+    ) -> Result<Vec<Self::Num>, Error>;
+    /// a 为偶数时返回 1，为奇数时返回 0，即 1 - b_0；a 需要小于 2^num_bits，num_bits 为 0 时返回 Error::Synthesis
+    fn is_even(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        num_bits: usize,
+    ) -> Result<Self::Num, Error>;
+    /// 约束 next > prev，prev、next 都需要小于 2^max_bits（同时被约束）
+    /// 与 less_than 的做法相同：next - prev - 1 能分解为 max_bits 位时 next > prev，
+    /// 否则差为负数，在域中是一个很大的数，无法分解
+    fn assert_increasing(
+        &self,
+        layouter: impl Layouter<F>,
+        prev: Self::Num,
+        next: Self::Num,
+        max_bits: usize,
+    ) -> Result<(), Error>;
+    /// a < b 时返回 1，否则返回 0；a、b 都需要小于 2^bits
+    /// 把 d = a - b + 2^bits 分解为 bits + 1 位，a >= b 时 d >= 2^bits，最高位为 1，所以结果为 1 - 最高位
+    fn less_than(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        bits: usize,
+    ) -> Result<Self::Num, Error>;
+    /// 饱和（截断）：返回 min(max(a, lo), hi)，lo、hi 为电路外固定的常数，需要 lo <= hi < 2^bits，a 需要小于 2^bits
+    /// 先用 less_than 比较 a 和 lo，用 select 取较大者，再与 hi 比较取较小者
+    fn clamp(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        lo: F,
+        hi: F,
+        bits: usize,
+    ) -> Result<Self::Num, Error>;
+    /// 把 a 分解为 num_digits 个 base 进制的数字（低位在前），约束 a = Σ d_i * base^i，且每个数字在 [0, base) 中
+    /// base 为 2 的幂时，每个数字用 to_bits 检查范围；否则先用 to_bits 约束数字非负且位数不超过 base 的位数，
+    /// 再用 less_than 约束数字小于 base。
+    /// 最高位的数字取 a / base^(num_digits-1)，a >= base^num_digits 时最高位超出范围，约束无法满足。
+    /// base 小于 2 或 num_digits 为 0 时返回 Error::Synthesis
+    fn decompose_radix(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        base: u64,
+        num_digits: usize,
+    ) -> Result<Vec<Self::Num>, Error>;
+    /// 带余除法：返回 (q, r)，a = q * divisor + r 且 0 <= r < divisor，divisor 为电路外固定的常数（至少为 2）
+    /// q、r 由证明者在电路外计算后作为 witness 加载，再用 assert_divmod 约束；a 需要小于 2^bits。
+    /// divisor 小于 2 时返回 Error::Synthesis
+    fn divmod(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        divisor: u64,
+        bits: usize,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+    /// 约束 (q, r) 是 a 除以 divisor 的商和余数：a = q * divisor + r，r 用与 decompose_radix 相同的方法约束在 [0, divisor) 中，
+    /// q 约束在 bits 位中，避免 q * divisor + r 在域中回绕。divisor 小于 2 时返回 Error::Synthesis
+    fn assert_divmod(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        q: Self::Num,
+        r: Self::Num,
+        divisor: u64,
+        bits: usize,
+    ) -> Result<(), Error>;
+    /// 字节流的异或校验和：返回 bytes[0] ^ bytes[1] ^ ...，空切片返回 0。
+    /// 芯片用 configure_with_xor_table 配置了 8 bit 的异或表时，在一个 region 中链式查表，每个字节一行。
+    /// 默认的芯片没有异或表（表有 2^16 行，所有使用芯片的电路都要付出这个 k），
+    /// 这时每个字节先用 to_bits 分解为 8 位（同时约束字节小于 2^8），逐位累积 x ⊕ y = x + y - 2 * (x ∧ y)，
+    /// 最后把累积的位重新组合成一个字节
+    fn xor_fold(&self, layouter: impl Layouter<F>, bytes: &[Self::Num])
+        -> Result<Self::Num, Error>;
+    /// 返回 (min(a, b), max(a, b))，a、b 都需要小于 2^bits
+    /// 只用一次 less_than 比较 a 和 b，再用两次 select 分别取较小者和较大者；a = b 时两者都等于 a
+    fn min_max(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        bits: usize,
+    ) -> Result<(Self::Num, Self::Num), Error>;
+}
+
+impl<F: PrimeField, const N: usize> BitInstructions<F> for SimpleChip<F, N> {
+    fn to_bits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        num_bits: usize,
+    ) -> Result<Vec<Self::Num>, Error> {
+        let config = self.config();
+        let bits = layouter.assign_region(
+            || "bits",
+            |mut region| {
+                let mut z =
+                    a.0.copy_advice(|| "z_0", &mut region, config.advice[0], 0)?;
+                let mut bits = Vec::with_capacity(num_bits);
+                for i in 0..num_bits {
+                    config.s_bits.enable(&mut region, i)?;
+                    // 小端表示中第一个字节的最低位就是 z 的最低位
+                    let bit = z
+                        .value()
+                        .map(|z| F::from((z.to_repr().as_ref()[0] & 1) as u64));
+                    let next = z.value().zip(bit).map(|(z, bit)| (*z - bit) * F::TWO_INV);
+                    bits.push(ACell(region.assign_advice(
+                        || format!("b_{}", i),
+                        config.advice[1],
+                        i,
+                        || bit,
+                    )?));
+                    z = region.assign_advice(
+                        || format!("z_{}", i + 1),
+                        config.advice[0],
+                        i + 1,
+                        || next,
+                    )?;
+                }
+                // 最后的 z 必须为 0，否则 a 超出 num_bits 位
+                let zero = region.assign_advice_from_constant(
+                    || "zero",
+                    config.advice[1],
+                    num_bits,
+                    F::ZERO,
+                )?;
+                region.constrain_equal(z.cell(), zero.cell())?;
+                Ok(bits)
+            },
+        )?;
+        trace_op!("to_bits", "bits"; std::iter::once(&a).chain(&bits));
+        Ok(bits)
+    }
+
+    fn is_even(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        num_bits: usize,
+    ) -> Result<Self::Num, Error> {
+        if num_bits == 0 {
+            return Err(Error::Synthesis);
+        }
+        let bits = self.to_bits(layouter.namespace(|| "decompose"), a, num_bits)?;
+        // 1 - b_0 = 1 + (-1) * b_0
+        let neg_bit =
+            self.mul_by_constant(layouter.namespace(|| "-b_0"), bits[0].clone(), -F::ONE)?;
+        let one = self.load_constant(layouter.namespace(|| "one"), F::ONE)?;
+        self.add(layouter.namespace(|| "1 - b_0"), one, neg_bit)
+    }
+
+    fn assert_increasing(
+        &self,
+        mut layouter: impl Layouter<F>,
+        prev: Self::Num,
+        next: Self::Num,
+        max_bits: usize,
+    ) -> Result<(), Error> {
+        self.to_bits(layouter.namespace(|| "prev bits"), prev.clone(), max_bits)?;
+        self.to_bits(layouter.namespace(|| "next bits"), next.clone(), max_bits)?;
+
+        // next - prev - 1 = next + (-1) * prev + (-1)
+        let neg_prev = self.mul_by_constant(layouter.namespace(|| "-prev"), prev, -F::ONE)?;
+        let diff = self.add(layouter.namespace(|| "next - prev"), next, neg_prev)?;
+        let minus_one = self.load_constant(layouter.namespace(|| "-1"), -F::ONE)?;
+        let diff = self.add(layouter.namespace(|| "next - prev - 1"), diff, minus_one)?;
+        self.to_bits(layouter.namespace(|| "diff bits"), diff, max_bits)?;
+        Ok(())
+    }
+
+    fn less_than(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        let offset = F::from(2).pow_vartime([bits as u64]);
+        let d = self.linear_combination(
+            layouter.namespace(|| "a - b + 2^bits"),
+            &[a, b],
+            &[F::ONE, -F::ONE],
+            offset,
+        )?;
+        let d_bits = self.to_bits(layouter.namespace(|| "decompose"), d, bits + 1)?;
+        self.linear_combination(
+            layouter.namespace(|| "1 - top bit"),
+            &d_bits[bits..],
+            &[-F::ONE],
+            F::ONE,
+        )
+    }
+
+    fn clamp(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        lo: F,
+        hi: F,
+        bits: usize,
+    ) -> Result<Self::Num, Error> {
+        let lo = self.load_constant(layouter.namespace(|| "lo"), lo)?;
+        let hi = self.load_constant(layouter.namespace(|| "hi"), hi)?;
+        let below = self.less_than(layouter.namespace(|| "a < lo"), a.clone(), lo.clone(), bits)?;
+        let a = self.select(layouter.namespace(|| "max(a, lo)"), below, lo, a)?;
+        let above = self.less_than(layouter.namespace(|| "hi < a"), hi.clone(), a.clone(), bits)?;
+        self.select(layouter.namespace(|| "min(a, hi)"), above, hi, a)
+    }
+
+    fn decompose_radix(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        base: u64,
+        num_digits: usize,
+    ) -> Result<Vec<Self::Num>, Error> {
+        if base < 2 || num_digits == 0 {
+            return Err(Error::Synthesis);
+        }
+        let digit_values = a.0.value().map(|a| radix_digits(a, base, num_digits));
+
+        let mut digits = Vec::with_capacity(num_digits);
+        for i in 0..num_digits {
+            let value = digit_values.as_ref().map(|digits| digits[i]);
+            let digit = self.load_private(layouter.namespace(|| format!("d_{}", i)), value)?;
+            self.assert_below(
+                layouter.namespace(|| format!("d_{} < base", i)),
+                digit.clone(),
+                base,
+            )?;
+            digits.push(digit);
+        }
+
+        let mut weights = Vec::with_capacity(num_digits);
+        let mut weight = F::ONE;
+        for _ in 0..num_digits {
+            weights.push(weight);
+            weight *= F::from(base);
+        }
+        let recomposed = self.linear_combination(
+            layouter.namespace(|| "Σ d_i * base^i"),
+            &digits,
+            &weights,
+            F::ZERO,
+        )?;
+        self.link(layouter.namespace(|| "a = Σ d_i * base^i"), a, recomposed)?;
+        Ok(digits)
+    }
+
+    fn divmod(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        divisor: u64,
+        bits: usize,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        if divisor < 2 {
+            return Err(Error::Synthesis);
+        }
+        // 两位 divisor 进制的分解：低位为余数，高位为剩下的商
+        let qr = a.0.value().map(|a| radix_digits(a, divisor, 2));
+        let q = self.load_private(layouter.namespace(|| "q"), qr.as_ref().map(|qr| qr[1]))?;
+        let r = self.load_private(layouter.namespace(|| "r"), qr.as_ref().map(|qr| qr[0]))?;
+        self.assert_divmod(
+            layouter.namespace(|| "a = q * divisor + r"),
+            a,
+            q.clone(),
+            r.clone(),
+            divisor,
+            bits,
+        )?;
+        Ok((q, r))
+    }
+
+    fn assert_divmod(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        q: Self::Num,
+        r: Self::Num,
+        divisor: u64,
+        bits: usize,
+    ) -> Result<(), Error> {
+        if divisor < 2 {
+            return Err(Error::Synthesis);
+        }
+        self.assert_below(layouter.namespace(|| "r < divisor"), r.clone(), divisor)?;
+        self.to_bits(layouter.namespace(|| "q range"), q.clone(), bits)?;
+        let recomposed = self.linear_combination(
+            layouter.namespace(|| "q * divisor + r"),
+            &[q, r],
+            &[F::from(divisor), F::ONE],
+            F::ZERO,
+        )?;
+        self.link(layouter.namespace(|| "a = q * divisor + r"), a, recomposed)
+    }
+
+    fn xor_fold(
+        &self,
+        mut layouter: impl Layouter<F>,
+        bytes: &[Self::Num],
+    ) -> Result<Self::Num, Error> {
+        if let Some(xor) = &self.config.xor_table {
+            return self.xor_fold_lookup(layouter, xor, bytes);
+        }
+        let Some((first, rest)) = bytes.split_first() else {
+            return self.load_constant(layouter.namespace(|| "empty xor"), F::ZERO);
+        };
+        let mut acc = self.to_bits(layouter.namespace(|| "byte 0 bits"), first.clone(), 8)?;
+        for (i, byte) in rest.iter().enumerate() {
+            let bits = self.to_bits(
+                layouter.namespace(|| format!("byte {} bits", i + 1)),
+                byte.clone(),
+                8,
+            )?;
+            acc = acc
+                .into_iter()
+                .zip(bits)
+                .map(|(x, y)| {
+                    let both = self.and(layouter.namespace(|| "x and y"), x.clone(), y.clone())?;
+                    self.linear_combination(
+                        layouter.namespace(|| "x xor y"),
+                        &[x, y, both],
+                        &[F::ONE, F::ONE, -F::from(2)],
+                        F::ZERO,
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        let powers: Vec<F> = (0..8).map(|i| F::from(1 << i)).collect();
+        self.linear_combination(
+            layouter.namespace(|| "recompose byte"),
+            &acc,
+            &powers,
+            F::ZERO,
+        )
+    }
+
+    fn min_max(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+        bits: usize,
+    ) -> Result<(Self::Num, Self::Num), Error> {
+        let lt = self.less_than(layouter.namespace(|| "a < b"), a.clone(), b.clone(), bits)?;
+        let min = self.select(
+            layouter.namespace(|| "min"),
+            lt.clone(),
+            a.clone(),
+            b.clone(),
+        )?;
+        let max = self.select(layouter.namespace(|| "max"), lt, b, a)?;
+        Ok((min, max))
+    }
+}
+
+impl<F: PrimeField, const N: usize> SimpleChip<F, N> {
+    /// 约束 0 <= x < bound（bound >= 2）：bound 为 2 的幂时直接用 to_bits；
+    /// 否则先用 to_bits 约束 x 非负且位数不超过 bound 的位数，再用 less_than 约束 x < bound
+    fn assert_below(
+        &self,
+        mut layouter: impl Layouter<F>,
+        x: ACell<F>,
+        bound: u64,
+    ) -> Result<(), Error> {
+        let bound_bits = (64 - bound.leading_zeros()) as usize;
+        if bound.is_power_of_two() {
+            return self
+                .to_bits(layouter.namespace(|| "x range"), x, bound_bits - 1)
+                .map(|_| ());
+        }
+        self.to_bits(layouter.namespace(|| "x >= 0"), x.clone(), bound_bits)?;
+        let bound_cell = self.load_constant(layouter.namespace(|| "bound"), F::from(bound))?;
+        let lt = self.less_than(
+            layouter.namespace(|| "x < bound"),
+            x,
+            bound_cell,
+            bound_bits,
+        )?;
+        self.constrain_constant(layouter.namespace(|| "x < bound"), lt, F::ONE)
+    }
+
+    /// 加载异或表；芯片没有用 configure_with_xor_table 配置时什么也不做
+    pub fn load_xor_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let Some(xor) = &self.config.xor_table else {
+            return Ok(());
+        };
+        layouter.assign_table(
+            || "xor table",
+            |mut table| {
+                for (row, (x, y)) in (0..256u64)
+                    .flat_map(|x| (0..256u64).map(move |y| (x, y)))
+                    .enumerate()
+                {
+                    for (column, value) in xor.table.iter().zip([x, y, x ^ y]) {
+                        table.assign_cell(
+                            || "xor",
+                            *column,
+                            row,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 用异或表实现 xor_fold：acc_0 为常量 0，第 i 行查询 (acc_i, x_i, acc_{i+1})，最后一行的 acc_n 即为结果
+    fn xor_fold_lookup(
+        &self,
+        mut layouter: impl Layouter<F>,
+        xor: &XorTableConfig,
+        bytes: &[ACell<F>],
+    ) -> Result<ACell<F>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "xor_fold",
+            |mut region| {
+                let mut acc =
+                    region.assign_advice_from_constant(|| "acc_0", config.advice[0], 0, F::ZERO)?;
+                for (i, byte) in bytes.iter().enumerate() {
+                    xor.s_xor.enable(&mut region, i)?;
+                    let x = byte.0.copy_advice(
+                        || format!("x_{}", i),
+                        &mut region,
+                        config.advice[1],
+                        i,
+                    )?;
+                    // 输入是字节时，小端表示的第一个字节就是它的值
+                    let next = acc.value().zip(x.value()).map(|(acc, x)| {
+                        F::from((acc.to_repr().as_ref()[0] ^ x.to_repr().as_ref()[0]) as u64)
+                    });
+                    acc = region.assign_advice(
+                        || format!("acc_{}", i + 1),
+                        config.advice[0],
+                        i + 1,
+                        || next,
+                    )?;
+                }
+                Ok(ACell(acc))
+            },
+        )
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 5、构建电路
+/// 上面步骤中，已经进行了自定义指令、定义芯片、实现芯片的过程，接下来就是构建电路
+/// 自定义电路中需要实现 plonk::Circuit 的 trait （https://docs.rs/halo2_proofs/latest/halo2_proofs/plonk/trait.Circuit.html）
+///
+/// 首先先定义电路结构体. 结构体中保存private input
+/// 官方解释：我们使用 `Option<F>` 类型是因为，
+/// 在生成密钥阶段，它们不需要有任何的值；在证明阶段中，如果它们任一为 `None` 的话，我们将得到一个错误。
+/// REPS 控制电路的深度：电路计算 constant * (a*b)^(2*REPS)，REPS = 1 时即为 constant * a^2 * b^2
+#[derive(Clone, Debug, Default)]
+pub struct SimpleCircuit<F: Field, const REPS: usize = 1> {
+    pub constant: F,
+    pub a: Value<F>,
+    pub b: Value<F>,
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 为 SimpleCircuit实现 Circuit trait
+/// 在电路实现时，
+/// 1）先进行configure过程：Circuit configure -> Chip configure -> ConstraintSystem，最后返回 CircuitConfig
+/// 2）再进行Synthesize过程：
+impl<F: Field, const REPS: usize> Circuit<F> for SimpleCircuit<F, REPS> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 返回此电路的副本，没有 witness（即所有witness设置为 None）。对于大多数电路，这将等于Self::default()。
+    /// 这里的 constant 会被写入 fixed 列，属于电路结构的一部分，所以需要保留，否则生成的验证密钥会不一致
+    fn without_witnesses(&self) -> Self {
+        Self {
+            constant: self.constant,
+            ..Self::default()
+        }
+    }
+
+    /// 精确的布置电路门、列的排列
+    /// 输入约束系统，输出之前自定义的 simpleConfig
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // 2个 advice 列，存储 private input
+        let advice = [meta.advice_column(), meta.advice_column()];
+        // 1个 instance 列，存储 public input
+        let instance = meta.instance_column();
+        // fixed列，储存常数
+        let constant = meta.fixed_column();
+
+        // 调用芯片的配置，初始化配置
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    /// 根据提供的 config，来对 Layouter 进行赋值，核心用到了它的 assin_region() 函数，而这个函数用到了 closure，它的参数是 Region。
+    /// 这里直接调用我们在simpleChip中实现的的4个指令（load_private, load_constant, mul, expose_public）
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let simple_chip = SimpleChip::<F>::construct(config);
+
+        /// 将 private input 加载到电路中
+        /// ? 运算符用在返回值为 Result 的表达式后面，它等同于这样一个匹配表达式：其中 Err(err) 分支展开成提前返回的 return Err(err)，而 Ok(ok) 分支展开成 ok 表达式。
+        let a = simple_chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = simple_chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+
+        /// 将常数加载到电路中
+        let constant =
+            simple_chip.load_constant(layouter.namespace(|| "load constant"), self.constant)?;
+
+        /// 实现 (a*b)^(2*REPS)
+        let ab = simple_chip.mul(layouter.namespace(|| "a * b"), a, b)?;
+        let ab_pow =
+            simple_chip.pow(layouter.namespace(|| "(a*b)^(2*REPS)"), ab, 2 * REPS as u64)?;
+        let c = simple_chip.mul(
+            layouter.namespace(|| "constant * (a*b)^(2*REPS)"),
+            constant,
+            ab_pow,
+        )?;
+
+        /// 把运算结果作为电路的public input
+        simple_chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
+    }
+}
+
+/// SimpleCircuit 只在 instance 列的第0行导出 c
+impl<F: Field, const REPS: usize> prover::DescribeInstance for SimpleCircuit<F, REPS> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 电路外计算 c = constant * (a * b)^(2 * REPS)
+impl<const REPS: usize> dev::ExpectedPublic for SimpleCircuit<Fp, REPS> {
+    fn expected_public(&self) -> Value<Vec<Vec<Fp>>> {
+        let constant = self.constant;
+        self.a
+            .zip(self.b)
+            .map(|(a, b)| vec![vec![constant * (a * b).pow_vartime([2 * REPS as u64])]])
+    }
+}
+
+/// constant、a、b 都是随机的域元素
+impl<const REPS: usize> witness::RandomWitness for SimpleCircuit<Fp, REPS> {
+    fn random(rng: &mut impl rand_core::RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let constant = Fp::random(&mut *rng);
+        let a = Fp::random(&mut *rng);
+        let b = Fp::random(&mut *rng);
+        let c = constant * (a * b).pow_vartime([2 * REPS as u64]);
+        let circuit = SimpleCircuit {
+            constant,
+            a: Value::known(a),
+            b: Value::known(b),
+        };
+        (circuit, vec![vec![c]])
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// SimpleCircuit 的构造器：同时生成电路和与之匹配的 public input，避免手动计算时传错
+/// 例如：SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build()
+/// 未设置的字段默认为 1（乘法单位元），都不设置时 public input 为 1
+pub struct SimpleCircuitBuilder<F: PrimeField, const REPS: usize = 1> {
+    constant: F,
+    a: F,
+    b: F,
+}
+
+impl<F: PrimeField, const REPS: usize> SimpleCircuit<F, REPS> {
+    pub fn builder() -> SimpleCircuitBuilder<F, REPS> {
+        SimpleCircuitBuilder {
+            constant: F::ONE,
+            a: F::ONE,
+            b: F::ONE,
+        }
+    }
+}
+
+impl<F: PrimeField, const REPS: usize> SimpleCircuitBuilder<F, REPS> {
+    pub fn a(mut self, a: u64) -> Self {
+        self.a = F::from(a);
+        self
+    }
+
+    pub fn b(mut self, b: u64) -> Self {
+        self.b = F::from(b);
+        self
+    }
+
+    pub fn constant(mut self, constant: u64) -> Self {
+        self.constant = F::from(constant);
+        self
+    }
+
+    /// 返回电路和 public input：c = constant * (a * b)^(2 * REPS)，与 synthesize 中的计算一致
+    pub fn build(self) -> (SimpleCircuit<F, REPS>, Vec<F>) {
+        let c = self.constant * (self.a * self.b).pow_vartime([2 * REPS as u64]);
+        let circuit = SimpleCircuit {
+            constant: self.constant,
+            a: Value::known(self.a),
+            b: Value::known(self.b),
+        };
+        (circuit, vec![c])
+    }
+}
+
+///////////////////////////////////////////////////////////////////////
+/// 按名字提供 witness：输入较多时，按名字查找比按位置设置字段更不容易出错
+///
+/// 按名字构造电路时缺少必需的输入
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingInput {
+    pub name: &'static str,
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing required input \"{}\"", self.name)
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+impl<F: PrimeField, const REPS: usize> SimpleCircuit<F, REPS> {
+    /// 从名字到值的映射中取出 "a"、"b"、"constant" 构造电路，多余的键会被忽略
+    pub fn from_named(inputs: HashMap<String, F>) -> Result<Self, MissingInput> {
+        let get = |name: &'static str| inputs.get(name).copied().ok_or(MissingInput { name });
+        Ok(SimpleCircuit {
+            constant: get("constant")?,
+            a: Value::known(get("a")?),
+            b: Value::known(get("b")?),
+        })
+    }
+}
@@ -0,0 +1,97 @@
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{floor_planner::V1, Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error, FloorPlanner},
+};
+use std::marker::PhantomData;
+
+use crate::{dev::ExpectedPublic, prover::DescribeInstance};
+
+///////////////////////////////////////////////////////////////////////
+/// 可选择布局器（FloorPlanner）的电路包装
+/// 电路的 FloorPlanner 是 Circuit trait 的关联类型，决定了各个 region 在电路中的摆放位置：
+/// - SimpleFloorPlanner：按 region 的调用顺序依次向下摆放，每个 region 从上一个 region 的下一行开始
+/// - V1（halo2_proofs::circuit::floor_planner::V1）：先测量每个 region 占用的列和行，再尽量把不冲突的 region 摆到同一行，
+///   常量最后统一放置，通常能得到更少的行数
+///
+/// WithPlanner 保留原电路的配置和赋值逻辑，只替换 FloorPlanner，用来观察不同布局器对 region 摆放的影响
+/// 例如：WithPlanner::<_, V1>::new(circuit)
+pub struct WithPlanner<C, P> {
+    pub circuit: C,
+    _marker: PhantomData<P>,
+}
+
+impl<C, P> WithPlanner<C, P> {
+    pub fn new(circuit: C) -> Self {
+        Self {
+            circuit,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, C: Circuit<F>, P: FloorPlanner> Circuit<F> for WithPlanner<C, P> {
+    type Config = C::Config;
+    type FloorPlanner = P;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(self.circuit.without_witnesses())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        C::configure(meta)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.circuit.synthesize(config, layouter)
+    }
+}
+
+/// 换布局器不改变 public input
+impl<C: DescribeInstance, P> DescribeInstance for WithPlanner<C, P> {
+    fn instance_shape(&self) -> Vec<usize> {
+        self.circuit.instance_shape()
+    }
+}
+
+impl<C: ExpectedPublic, P> ExpectedPublic for WithPlanner<C, P> {
+    fn expected_public(&self) -> Value<Vec<Vec<Fp>>> {
+        self.circuit.expected_public()
+    }
+}
+
+/// 分别用 SimpleFloorPlanner 和 V1 运行 MockProver，断言两者的验证结果一致（都通过或都失败），返回是否通过。
+/// MockProver::run 本身失败（例如 k 太小）也算作验证失败；V1 通常用更少的行，k 需要对两种布局都足够。
+///
+/// 电路的结果与布局器无关，需要满足：
+/// - 门只通过 Rotation 访问同一个 region 内的行，不跨 region 查询（两种布局器把 region 放在不同的行）；
+/// - 不依赖 region 的绝对行号，例如在门中直接查询 instance 列时，public input 的行号与 region 的位置绑定，
+///   不同 region 之间的联系用拷贝约束表达；
+/// - region 的形状（用到的列和行数）不依赖 witness：V1 先运行一次 region 的闭包测量形状，再运行一次赋值，
+///   两次运行赋值的单元格必须相同，闭包中也不能有依赖调用次数的副作用
+pub fn assert_planner_agnostic<C: Circuit<Fp> + Clone>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> bool {
+    let verified =
+        |res: Result<MockProver<Fp>, Error>| res.is_ok_and(|prover| prover.verify().is_ok());
+    let simple = verified(MockProver::run(
+        k,
+        &WithPlanner::<_, SimpleFloorPlanner>::new(circuit.clone()),
+        public.to_vec(),
+    ));
+    let v1 = verified(MockProver::run(
+        k,
+        &WithPlanner::<_, V1>::new(circuit.clone()),
+        public.to_vec(),
+    ));
+    assert_eq!(
+        simple, v1,
+        "planners disagree: SimpleFloorPlanner verified = {}, V1 verified = {}",
+        simple, v1
+    );
+    simple
+}
@@ -0,0 +1,569 @@
+use halo2_proofs::{
+    dev::{MockProver, VerifyFailure},
+    pasta::{EqAffine, Fp},
+    plonk::{
+        create_proof, keygen_pk, keygen_vk, verify_proof, BatchVerifier, Circuit, Error,
+        ProvingKey, SingleVerifier, VerifyingKey,
+    },
+    poly::commitment::Params,
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{OsRng, RngCore, SeedableRng};
+use std::{
+    fmt,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use crate::transcript::{KeccakRead, KeccakWrite, TranscriptKind};
+
+///////////////////////////////////////////////////////////////////////
+/// 对 MockProver 和真实证明（IPA）的简单封装
+/// 在调用 halo2 之前先检查 public input，避免 halo2 内部给出难以理解的错误
+///
+/// 这里固定使用 pasta 曲线（Fp / EqAffine）和 IPA 承诺：zcash 的 halo2_proofs 0.3 只实现了 IPA，
+/// 没有 KZG，也不依赖 halo2curves，所以无法切换到 bn256 + KZG 的后端。
+/// 如果要支持 bn256，需要换成 PSE 的 halo2_proofs 分支，证明函数对曲线泛型时需要的约束大致为：
+/// C: CurveAffine，C::Scalar: FromUniformBytes<64>（Challenge255 的要求），以及承诺方案对应的 Params / Prover / Verifier 类型
+///
+/// 电路使用的 instance 列的形状：第 i 个元素为第 i 个 instance 列中 public input 的行数，
+/// 不使用 instance 列的电路返回空的 Vec。
+/// 形状可能依赖电路的参数（例如矩阵的行数、数列的长度），所以需要传入电路
+pub trait DescribeInstance {
+    fn instance_shape(&self) -> Vec<usize>;
+
+    /// 电路需要的 public input 个数，即各 instance 列的行数之和
+    fn instance_count(&self) -> usize {
+        self.instance_shape().iter().sum()
+    }
+}
+
+#[derive(Debug)]
+pub enum ProofError {
+    /// 传入的 public input 与电路声明的形状不一致：column 为 None 时是 instance 列的个数不同，
+    /// 为 Some(i) 时是第 i 个 instance 列中的值的个数不同
+    BadPublicInput {
+        column: Option<usize>,
+        expected: usize,
+        got: usize,
+    },
+    /// k 太小，电路的行数不够；needed_hint 为估计的最小 k（估计失败时为 None）
+    TooFewRows { k: u32, needed_hint: Option<u32> },
+    /// 批量验证中第 index 个证明验证失败
+    BatchFailed { index: usize, error: Error },
+    /// halo2 在生成密钥、证明或验证时返回的错误
+    Plonk(Error),
+    /// 十六进制的证明或 public input 无法解析，还没有进行验证
+    Parse(String),
+    /// MockProver 发现约束不满足，没有生成真实的证明
+    Unsatisfied(Vec<VerifyFailure>),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::BadPublicInput {
+                column: None,
+                expected,
+                got,
+            } => write!(
+                f,
+                "bad public input: circuit has {} instance column(s), got {}",
+                expected, got
+            ),
+            ProofError::BadPublicInput {
+                column: Some(column),
+                expected,
+                got,
+            } => write!(
+                f,
+                "bad public input: instance column {} expects {} value(s), got {}",
+                column, expected, got
+            ),
+            ProofError::TooFewRows {
+                k,
+                needed_hint: Some(needed),
+            } => write!(f, "k = {} gives too few rows, try k = {}", k, needed),
+            ProofError::TooFewRows {
+                k,
+                needed_hint: None,
+            } => write!(f, "k = {} gives too few rows, try a larger k", k),
+            ProofError::BatchFailed { index, error } => {
+                write!(
+                    f,
+                    "proof {} in the batch failed to verify: {}",
+                    index, error
+                )
+            }
+            ProofError::Plonk(e) => write!(f, "halo2 error: {}", e),
+            ProofError::Parse(msg) => write!(f, "parse error: {}", msg),
+            ProofError::Unsatisfied(failures) => {
+                write!(
+                    f,
+                    "circuit is not satisfied ({} failure(s))",
+                    failures.len()
+                )?;
+                for failure in failures {
+                    write!(f, "\n{}", failure)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+impl From<Error> for ProofError {
+    fn from(e: Error) -> Self {
+        ProofError::Plonk(e)
+    }
+}
+
+/// 检查 public input 的形状是否与电路声明的一致：先比较 instance 列的个数，再逐列比较值的个数
+fn check_public_input(shape: &[usize], public: &[Vec<Fp>]) -> Result<(), ProofError> {
+    if public.len() != shape.len() {
+        return Err(ProofError::BadPublicInput {
+            column: None,
+            expected: shape.len(),
+            got: public.len(),
+        });
+    }
+    for (column, (&expected, values)) in shape.iter().zip(public).enumerate() {
+        if values.len() != expected {
+            return Err(ProofError::BadPublicInput {
+                column: Some(column),
+                expected,
+                got: values.len(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// 估计时尝试的最大 k
+const MAX_K: u32 = 18;
+
+/// 从小到大尝试 k，返回 MockProver 能完成赋值的最小 k（只检查行数是否足够，不检查约束）
+pub fn min_k<C: Circuit<Fp>>(circuit: &C, public: &[Vec<Fp>]) -> Option<u32> {
+    (1..=MAX_K).find(|k| MockProver::run(*k, circuit, public.to_vec()).is_ok())
+}
+
+/// 把 halo2 的 NotEnoughRowsAvailable 转换为 TooFewRows，并附上估计的最小 k
+fn rows_error<C: Circuit<Fp>>(k: u32, circuit: &C, public: &[Vec<Fp>], e: Error) -> ProofError {
+    match e {
+        Error::NotEnoughRowsAvailable { .. } => ProofError::TooFewRows {
+            k,
+            needed_hint: min_k(circuit, public),
+        },
+        e => ProofError::Plonk(e),
+    }
+}
+
+/// 检查 public input 后运行 MockProver，返回的 prover 可以继续调用 verify()
+pub fn run<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> Result<MockProver<Fp>, ProofError> {
+    check_public_input(&circuit.instance_shape(), public)?;
+    MockProver::run(k, circuit, public.to_vec()).map_err(|e| rows_error(k, circuit, public, e))
+}
+
+/// 生成真实的证明：keygen -> create_proof，返回证明的字节
+/// transcript 为 Fiat-Shamir 使用的哈希，验证时必须传入同一种
+pub fn prove_simple<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+    transcript: TranscriptKind,
+) -> Result<Vec<u8>, ProofError> {
+    check_public_input(&circuit.instance_shape(), public)?;
+    prove_unchecked(k, circuit, public, transcript, OsRng)
+}
+
+/// 用种子确定的随机数生成证明：create_proof 中的盲化因子都来自 rng，
+/// 种子相同时（电路、witness、public input 和 transcript 也相同）两次生成的证明逐字节相同，可以作为测试向量；
+/// 种子不同时证明不同，但都能通过验证。种子必须保密且不能重复使用，否则会泄露 witness，只用于测试
+pub fn prove_seeded<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+    transcript: TranscriptKind,
+    seed: [u8; 32],
+) -> Result<Vec<u8>, ProofError> {
+    check_public_input(&circuit.instance_shape(), public)?;
+    prove_unchecked(k, circuit, public, transcript, ChaCha20Rng::from_seed(seed))
+}
+
+/// prove_simple 中不检查 public input 个数的部分，电路不需要实现 DescribeInstance
+pub(crate) fn prove_unchecked<C: Circuit<Fp>>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+    transcript: TranscriptKind,
+    mut rng: impl RngCore,
+) -> Result<Vec<u8>, ProofError> {
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, circuit).map_err(|e| rows_error(k, circuit, public, e))?;
+    let pk = keygen_pk(&params, vk, circuit)?;
+
+    let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+    let circuits = std::slice::from_ref(circuit);
+    let proof = match transcript {
+        TranscriptKind::Blake2b => {
+            let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+            create_proof(
+                &params,
+                &pk,
+                circuits,
+                &[&instances],
+                &mut rng,
+                &mut transcript,
+            )?;
+            transcript.finalize()
+        }
+        TranscriptKind::Keccak => {
+            let mut transcript = KeccakWrite::<_, EqAffine>::init(vec![]);
+            create_proof(
+                &params,
+                &pk,
+                circuits,
+                &[&instances],
+                &mut rng,
+                &mut transcript,
+            )?;
+            transcript.finalize()
+        }
+    };
+    Ok(proof)
+}
+
+/// 验证 prove_simple 生成的证明：验证密钥由电路结构（不含 witness）重新生成
+pub fn verify_simple<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    proof: &[u8],
+    public: &[Vec<Fp>],
+    transcript: TranscriptKind,
+) -> Result<(), ProofError> {
+    check_public_input(&circuit.instance_shape(), public)?;
+    verify_unchecked(k, circuit, proof, public, transcript)
+}
+
+/// verify_simple 中不检查 public input 个数的部分，电路不需要实现 DescribeInstance
+pub(crate) fn verify_unchecked<C: Circuit<Fp>>(
+    k: u32,
+    circuit: &C,
+    proof: &[u8],
+    public: &[Vec<Fp>],
+    transcript: TranscriptKind,
+) -> Result<(), ProofError> {
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, &circuit.without_witnesses())
+        .map_err(|e| rows_error(k, circuit, public, e))?;
+
+    let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+    let strategy = SingleVerifier::new(&params);
+    match transcript {
+        TranscriptKind::Blake2b => {
+            let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+            verify_proof(&params, &vk, strategy, &[&instances], &mut transcript)?;
+        }
+        TranscriptKind::Keccak => {
+            let mut transcript = KeccakRead::<_, EqAffine>::init(proof);
+            verify_proof(&params, &vk, strategy, &[&instances], &mut transcript)?;
+        }
+    }
+    Ok(())
+}
+
+/// 生成证明并返回证明的字节数
+/// 证明的大小由电路的形状决定（列数、门的次数、lookup 和拷贝约束的个数），与 witness 无关；
+/// 行数只通过 k 影响 IPA 的轮数：每多一轮多两个群元素，所以 k 每加 1，证明增加 64 字节
+pub fn proof_size<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> Result<usize, ProofError> {
+    prove_simple(k, circuit, public, TranscriptKind::default()).map(|proof| proof.len())
+}
+
+/// 从 start_k 开始生成并验证真实的证明，行数不够（TooFewRows）时直接换成错误中估计的 needed_hint 重试，
+/// 没有估计（或估计不大于当前的 k）时换成 k + 1，直到 MAX_K。
+/// 返回最后尝试的 k 和结果：成功时 k 为能容纳电路的最小 k（不小于 start_k）；
+/// 其它错误（例如 public input 不对）不会因为增大 k 而消失，直接返回
+pub fn prove_autogrow<C: Circuit<Fp> + DescribeInstance>(
+    circuit: &C,
+    public: &[Vec<Fp>],
+    start_k: u32,
+) -> (u32, Result<(), ProofError>) {
+    let mut k = start_k;
+    loop {
+        let res = prove_simple(k, circuit, public, TranscriptKind::default())
+            .and_then(|proof| verify_simple(k, circuit, &proof, public, TranscriptKind::default()));
+        match res {
+            Err(ProofError::TooFewRows { needed_hint, .. }) if k < MAX_K => {
+                k = needed_hint
+                    .filter(|needed| *needed > k)
+                    .unwrap_or(k + 1)
+                    .min(MAX_K)
+            }
+            res => return (k, res),
+        }
+    }
+}
+
+/// 分别统计生成证明和验证证明的耗时，返回 (证明耗时, 验证耗时, 是否验证成功)
+/// 生成密钥不计入耗时；不检查 public input 的个数，所以电路不需要实现 DescribeInstance。
+/// 生成密钥或证明失败时（例如 k 太小）返回 (0, 0, false)
+pub fn prove_and_verify_timed<C: Circuit<Fp>>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> (Duration, Duration, bool) {
+    let failed = (Duration::ZERO, Duration::ZERO, false);
+    let params: Params<EqAffine> = Params::new(k);
+    let Ok(vk) = keygen_vk(&params, circuit) else {
+        return failed;
+    };
+    let Ok(pk) = keygen_pk(&params, vk, circuit) else {
+        return failed;
+    };
+    let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+
+    let start = Instant::now();
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    let created = create_proof(
+        &params,
+        &pk,
+        std::slice::from_ref(circuit),
+        &[&instances],
+        OsRng,
+        &mut transcript,
+    );
+    if created.is_err() {
+        return failed;
+    }
+    let proof = transcript.finalize();
+    let prove_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(&proof[..]);
+    let strategy = SingleVerifier::new(&params);
+    let verified = verify_proof(
+        &params,
+        pk.get_vk(),
+        strategy,
+        &[&instances],
+        &mut transcript,
+    )
+    .is_ok();
+    (prove_time, start.elapsed(), verified)
+}
+
+/// 最简单的真实证明冒烟测试：keygen、create_proof 写入内存中的 Vec，再从这个 Vec 中读出并 verify_proof，
+/// 不经过文件，也不序列化密钥。生成和验证共用同一个 ProvingKey 中的验证密钥。
+/// 不检查 public input 的个数，所以电路不需要实现 DescribeInstance；k 太小时返回 TooFewRows
+pub fn roundtrip_in_memory<C: Circuit<Fp>>(
+    k: u32,
+    circuit: &C,
+    public: &[Vec<Fp>],
+) -> Result<(), ProofError> {
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, circuit).map_err(|e| rows_error(k, circuit, public, e))?;
+    let pk = keygen_pk(&params, vk, circuit)?;
+    let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        std::slice::from_ref(circuit),
+        &[&instances],
+        OsRng,
+        &mut transcript,
+    )?;
+    let proof: Vec<u8> = transcript.finalize();
+
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof.as_slice());
+    let strategy = SingleVerifier::new(&params);
+    verify_proof(
+        &params,
+        pk.get_vk(),
+        strategy,
+        &[&instances],
+        &mut transcript,
+    )?;
+    Ok(())
+}
+
+/// 复用密钥的证明器：Params 和 ProvingKey 只生成一次，之后可以为多个结构相同（witness 不同）的电路生成证明
+/// 批量生成证明时，避免每个证明都重新 keygen
+pub struct Prover<C> {
+    params: Params<EqAffine>,
+    pk: ProvingKey<EqAffine>,
+    /// 生成密钥时电路的 instance 形状，验证时没有电路，用它检查 public input
+    instance_shape: Vec<usize>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: Circuit<Fp> + DescribeInstance + Default> Prover<C> {
+    /// 用 C::default() 的电路结构生成密钥
+    pub fn new(k: u32) -> Result<Self, ProofError> {
+        Self::from_circuit(k, &C::default())
+    }
+}
+
+impl<C: Circuit<Fp> + DescribeInstance> Prover<C> {
+    /// 用给定电路的结构（不含 witness）生成密钥。电路结构中包含常量时（例如 SimpleCircuit 的 constant），
+    /// 之后证明的电路必须使用相同的常量
+    pub fn from_circuit(k: u32, circuit: &C) -> Result<Self, ProofError> {
+        let params: Params<EqAffine> = Params::new(k);
+        let circuit = circuit.without_witnesses();
+        // 这里没有 public input，无法估计最小的 k
+        let vk = keygen_vk(&params, &circuit).map_err(|e| match e {
+            Error::NotEnoughRowsAvailable { .. } => ProofError::TooFewRows {
+                k,
+                needed_hint: None,
+            },
+            e => ProofError::Plonk(e),
+        })?;
+        let pk = keygen_pk(&params, vk, &circuit)?;
+        Ok(Self {
+            params,
+            pk,
+            instance_shape: circuit.instance_shape(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// 使用已生成的密钥为电路生成证明
+    pub fn prove(&self, circuit: &C, public: &[Vec<Fp>]) -> Result<Vec<u8>, ProofError> {
+        check_public_input(&circuit.instance_shape(), public)?;
+
+        let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+        let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+        create_proof(
+            &self.params,
+            &self.pk,
+            std::slice::from_ref(circuit),
+            &[&instances],
+            OsRng,
+            &mut transcript,
+        )?;
+        Ok(transcript.finalize())
+    }
+
+    pub fn params(&self) -> &Params<EqAffine> {
+        &self.params
+    }
+
+    pub fn vk(&self) -> &VerifyingKey<EqAffine> {
+        self.pk.get_vk()
+    }
+
+    /// 验证其它工具以十六进制给出的证明和 public input（单个 instance 列，每个字符串一个域元素，
+    /// 域元素为小端字节表示，可以带 0x 前缀）。解析失败时返回 ProofError::Parse，不会进行验证
+    pub fn verify_from_hex(&self, proof_hex: &str, public_hex: &[&str]) -> Result<(), ProofError> {
+        let proof = hex::decode(proof_hex.strip_prefix("0x").unwrap_or(proof_hex))
+            .map_err(|e| ProofError::Parse(format!("proof: {}", e)))?;
+        let public = public_hex
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                crate::witness::fp_from_hex(s)
+                    .map_err(|e| ProofError::Parse(format!("public input {}: {}", i, e)))
+            })
+            .collect::<Result<Vec<Fp>, _>>()?;
+        self.verify(&proof, &[public])
+    }
+
+    /// 使用 ProvingKey 中的验证密钥验证证明
+    pub fn verify(&self, proof: &[u8], public: &[Vec<Fp>]) -> Result<(), ProofError> {
+        check_public_input(&self.instance_shape, public)?;
+
+        let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+        let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+        verify_proof(
+            &self.params,
+            self.pk.get_vk(),
+            SingleVerifier::new(&self.params),
+            &[&instances],
+            &mut transcript,
+        )?;
+        Ok(())
+    }
+}
+
+/// 验证密钥的指纹：用 C::default() 的电路结构生成验证密钥，对它的 pinned 表示（包含约束系统、固定列承诺等）做 Blake2b 哈希
+/// halo2 0.3 的验证密钥不能直接序列化，pinned 的 Debug 输出包含了验证密钥中所有影响验证的内容。
+/// 重构芯片时，指纹不变说明约束系统和电路结构没有变化
+pub fn vk_fingerprint<C: Circuit<Fp> + Default>(k: u32) -> [u8; 32] {
+    vk_fingerprint_of(k, &C::default())
+}
+
+/// 用给定电路的结构（不含 witness）计算验证密钥的指纹，电路结构中的常量（例如 SimpleCircuit 的 constant）会影响指纹
+pub fn vk_fingerprint_of<C: Circuit<Fp>>(k: u32, circuit: &C) -> [u8; 32] {
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, &circuit.without_witnesses()).expect("keygen_vk should not fail");
+    let pinned = format!("{:?}", vk.pinned());
+    let hash = blake2b_simd::Params::new()
+        .hash_length(32)
+        .personal(b"halo2-vk-fprint_")
+        .hash(pinned.as_bytes());
+    let mut fingerprint = [0u8; 32];
+    fingerprint.copy_from_slice(hash.as_bytes());
+    fingerprint
+}
+
+/// 检查验证密钥与 witness 无关：用两组不同的 witness（a、b 不同，constant 相同）构造 SimpleCircuit，
+/// 不经过 without_witnesses 直接生成验证密钥，比较两者的 pinned 表示是否逐字节相同。
+/// 验证密钥只能由电路结构（约束系统、fixed 列、选择器、拷贝约束）决定：验证者在没有 witness 的情况下生成它，
+/// 如果 witness 会影响验证密钥，验证者就得不到与证明者相同的密钥，部署时也无法复现
+pub fn vk_is_deterministic() -> bool {
+    let k = 5;
+    let params: Params<EqAffine> = Params::new(k);
+    let pinned = |a: u64, b: u64| {
+        let (circuit, _) = crate::SimpleCircuit::<Fp>::builder()
+            .a(a)
+            .b(b)
+            .constant(2)
+            .build();
+        let vk = keygen_vk(&params, &circuit).expect("keygen_vk should not fail");
+        format!("{:?}", vk.pinned()).into_bytes()
+    };
+    pinned(2, 3) == pinned(5, 7)
+}
+
+/// 批量验证使用同一个验证密钥的多个证明，每一项为 (证明, public input)
+/// 先用 BatchVerifier 把所有证明合并为一次检查；合并检查失败时，再逐个验证，返回第一个失败的证明的序号
+pub fn verify_batch(
+    params: &Params<EqAffine>,
+    vk: &VerifyingKey<EqAffine>,
+    proofs: &[(Vec<u8>, Vec<Vec<Fp>>)],
+) -> Result<(), ProofError> {
+    let mut batch = BatchVerifier::new();
+    for (proof, public) in proofs {
+        batch.add_proof(vec![public.clone()], proof.clone());
+    }
+    if batch.finalize(params, vk) {
+        return Ok(());
+    }
+
+    for (index, (proof, public)) in proofs.iter().enumerate() {
+        let instances: Vec<&[Fp]> = public.iter().map(Vec::as_slice).collect();
+        let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof.as_slice());
+        verify_proof(
+            params,
+            vk,
+            SingleVerifier::new(params),
+            &[&instances],
+            &mut transcript,
+        )
+        .map_err(|error| ProofError::BatchFailed { index, error })?;
+    }
+    // 合并检查失败但每个证明单独验证都通过，不应该发生
+    Err(ProofError::Plonk(Error::ConstraintSystemFailure))
+}
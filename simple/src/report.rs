@@ -0,0 +1,134 @@
+use halo2_proofs::{
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem},
+};
+use serde_json::json;
+
+use crate::{
+    dev, layout,
+    prover::{self, DescribeInstance},
+};
+
+///////////////////////////////////////////////////////////////////////
+/// 示例程序的 JSON 输出模式，方便脚本和 CI 读取结果
+/// 示例的 main 带 --json 参数运行时（例如 `cargo run -p simple -- --json`），
+/// 只对主电路输出一个 JSON 对象，不打印其它文字：
+///
+/// {"k": 5, "verified": true, "failures": [],
+///  "cost": {"rows": 12, "copy_constraints": 6, "blinding_rows": 6, "min_k": 5},
+///  "proof_size": null}
+///
+/// proof_size 只有在示例真实生成了证明时才有值，否则为 null
+///
+/// 命令行参数中是否有 --json
+pub fn json_requested() -> bool {
+    std::env::args().any(|arg| arg == "--json")
+}
+
+/// 命令行参数中有 --json 时打印电路的 JSON 报告（proof_size 为 null）并返回 true，示例的 main 随后直接返回；
+/// 没有 --json 时什么也不做，返回 false
+pub fn maybe_emit_json<C: Circuit<Fp>>(k: u32, circuit: &C, public: Vec<Vec<Fp>>) -> bool {
+    if !json_requested() {
+        return false;
+    }
+    println!("{}", json_report(k, circuit, public, None));
+    true
+}
+
+/// 与 maybe_emit_json 相同，但先生成一次真实的证明，报告中包含证明的大小（生成失败时为 null）
+pub fn maybe_emit_json_with_proof<C: Circuit<Fp> + DescribeInstance>(
+    k: u32,
+    circuit: &C,
+    public: Vec<Vec<Fp>>,
+) -> bool {
+    if !json_requested() {
+        return false;
+    }
+    let proof_size = prover::proof_size(k, circuit, &public).ok();
+    println!("{}", json_report(k, circuit, public, proof_size));
+    true
+}
+
+/// 用 MockProver 验证电路，并用 layout::measure 统计电路的开销，生成 JSON 报告
+pub fn json_report<C: Circuit<Fp>>(
+    k: u32,
+    circuit: &C,
+    public: Vec<Vec<Fp>>,
+    proof_size: Option<usize>,
+) -> serde_json::Value {
+    let failures: Vec<String> = match MockProver::run(k, circuit, public) {
+        Ok(prover) => match prover.verify() {
+            Ok(()) => vec![],
+            Err(failures) => failures.iter().map(|f| f.to_string()).collect(),
+        },
+        Err(e) => vec![e.to_string()],
+    };
+    let cost = layout::measure(circuit);
+    json!({
+        "k": k,
+        "verified": failures.is_empty(),
+        "failures": failures,
+        "cost": {
+            "rows": cost.rows,
+            "copy_constraints": cost.copy_constraints,
+            "blinding_rows": cost.blinding_rows,
+            "min_k": cost.k,
+        },
+        "proof_size": proof_size,
+    })
+}
+
+/// 电路定义的元数据，只由 configure 决定，与 witness 无关，供生成文档或其它工具使用
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitMetadata {
+    pub k: u32,
+    pub advice_columns: usize,
+    pub fixed_columns: usize,
+    pub instance_columns: usize,
+    /// 选择器压缩之前的个数
+    pub selectors: usize,
+    /// 按 create_gate 的调用顺序排列
+    pub gate_names: Vec<String>,
+    pub lookups: usize,
+}
+
+impl CircuitMetadata {
+    /// 序列化为 JSON 对象：三种列数放在 columns 中，其它字段名与结构体相同
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "k": self.k,
+            "columns": {
+                "advice": self.advice_columns,
+                "fixed": self.fixed_columns,
+                "instance": self.instance_columns,
+            },
+            "selectors": self.selectors,
+            "gate_names": self.gate_names,
+            "lookups": self.lookups,
+        })
+    }
+}
+
+/// 运行 C::configure 收集电路的元数据，不需要电路实例。k 只是原样记录在元数据中
+/// ConstraintSystem 的字段在 halo2 0.3 中不公开，列数、选择器个数和 lookup 个数从它的 Debug 输出中读取，
+/// 门的名字来自 dev::dump_gates，解析门失败时返回它的错误
+pub fn circuit_metadata<C: Circuit<Fp>>(k: u32) -> Result<CircuitMetadata, String> {
+    let mut cs = ConstraintSystem::<Fp>::default();
+    C::configure(&mut cs);
+    let debug = format!("{:?}", cs);
+    let gate_names = dev::dump_gates::<C>()?
+        .iter()
+        .map(|gate| gate.split(": ").next().unwrap_or_default().to_string())
+        .collect();
+    Ok(CircuitMetadata {
+        k,
+        advice_columns: layout::debug_field(&debug, "num_advice_columns"),
+        fixed_columns: layout::debug_field(&debug, "num_fixed_columns"),
+        instance_columns: layout::debug_field(&debug, "num_instance_columns"),
+        selectors: layout::debug_field(&debug, "num_selectors"),
+        gate_names,
+        // 每个 lookup 参数的 Debug 输出中都有一个 input_expressions 字段
+        lookups: debug.matches("input_expressions").count(),
+    })
+}
@@ -0,0 +1,186 @@
+use group::ff::{FromUniformBytes, PrimeField};
+use halo2_proofs::{
+    arithmetic::{Coordinates, CurveAffine},
+    transcript::{Challenge255, EncodedChallenge, Transcript, TranscriptRead, TranscriptWrite},
+};
+use sha3::{Digest, Keccak256};
+use std::{
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+
+///////////////////////////////////////////////////////////////////////
+/// Fiat-Shamir transcript 的选择
+/// halo2 0.3 自带的 transcript 只有 Blake2b；有些验证者（例如 EVM 上的合约）更容易计算 Keccak256，
+/// 这里按 Blake2bWrite/Blake2bRead 的结构实现了 Keccak256 的 transcript。
+/// 证明者和验证者必须使用同一种 transcript，否则挑战值不同，验证失败。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TranscriptKind {
+    /// halo2 默认的 Blake2b transcript
+    #[default]
+    Blake2b,
+    /// Keccak256 transcript
+    Keccak,
+}
+
+/// 与 Blake2b transcript 相同的消息前缀
+const KECCAK256_PREFIX_CHALLENGE: u8 = 0;
+const KECCAK256_PREFIX_POINT: u8 = 1;
+const KECCAK256_PREFIX_SCALAR: u8 = 2;
+/// Keccak256 只输出 32 字节，挑战值需要 64 字节：分别追加两个前缀各哈希一次，拼接为 64 字节
+const KECCAK256_PREFIX_CHALLENGE_LO: u8 = 16;
+const KECCAK256_PREFIX_CHALLENGE_HI: u8 = 17;
+
+/// 证明者和验证者共用的 Keccak256 状态
+#[derive(Clone, Debug)]
+struct KeccakState(Keccak256);
+
+impl KeccakState {
+    fn new() -> Self {
+        let mut state = Keccak256::new();
+        state.update(b"Halo2-Transcript");
+        KeccakState(state)
+    }
+
+    fn squeeze(&mut self) -> [u8; 64] {
+        self.0.update([KECCAK256_PREFIX_CHALLENGE]);
+        let mut lo = self.0.clone();
+        let mut hi = self.0.clone();
+        lo.update([KECCAK256_PREFIX_CHALLENGE_LO]);
+        hi.update([KECCAK256_PREFIX_CHALLENGE_HI]);
+
+        let mut result = [0u8; 64];
+        result[..32].copy_from_slice(&lo.finalize());
+        result[32..].copy_from_slice(&hi.finalize());
+        result
+    }
+
+    fn common_point<C: CurveAffine>(&mut self, point: C) -> io::Result<()> {
+        self.0.update([KECCAK256_PREFIX_POINT]);
+        let coords: Coordinates<C> = Option::from(point.coordinates())
+            .ok_or_else(|| io::Error::other("cannot write points at infinity to the transcript"))?;
+        self.0.update(coords.x().to_repr().as_ref());
+        self.0.update(coords.y().to_repr().as_ref());
+        Ok(())
+    }
+
+    fn common_scalar<F: PrimeField>(&mut self, scalar: F) -> io::Result<()> {
+        self.0.update([KECCAK256_PREFIX_SCALAR]);
+        self.0.update(scalar.to_repr().as_ref());
+        Ok(())
+    }
+}
+
+/// 证明者使用的 Keccak256 transcript，对应 Blake2bWrite
+#[derive(Debug, Clone)]
+pub struct KeccakWrite<W: Write, C: CurveAffine> {
+    state: KeccakState,
+    writer: W,
+    _marker: PhantomData<C>,
+}
+
+impl<W: Write, C: CurveAffine> KeccakWrite<W, C> {
+    pub fn init(writer: W) -> Self {
+        KeccakWrite {
+            state: KeccakState::new(),
+            writer,
+            _marker: PhantomData,
+        }
+    }
+
+    /// 取出写入的证明
+    pub fn finalize(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, C: CurveAffine> Transcript<C, Challenge255<C>> for KeccakWrite<W, C>
+where
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn squeeze_challenge(&mut self) -> Challenge255<C> {
+        Challenge255::new(&self.state.squeeze())
+    }
+
+    fn common_point(&mut self, point: C) -> io::Result<()> {
+        self.state.common_point(point)
+    }
+
+    fn common_scalar(&mut self, scalar: C::Scalar) -> io::Result<()> {
+        self.state.common_scalar(scalar)
+    }
+}
+
+impl<W: Write, C: CurveAffine> TranscriptWrite<C, Challenge255<C>> for KeccakWrite<W, C>
+where
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn write_point(&mut self, point: C) -> io::Result<()> {
+        self.state.common_point(point)?;
+        let compressed = point.to_bytes();
+        self.writer.write_all(compressed.as_ref())
+    }
+
+    fn write_scalar(&mut self, scalar: C::Scalar) -> io::Result<()> {
+        self.state.common_scalar(scalar)?;
+        self.writer.write_all(scalar.to_repr().as_ref())
+    }
+}
+
+/// 验证者使用的 Keccak256 transcript，对应 Blake2bRead
+#[derive(Debug, Clone)]
+pub struct KeccakRead<R: Read, C: CurveAffine> {
+    state: KeccakState,
+    reader: R,
+    _marker: PhantomData<C>,
+}
+
+impl<R: Read, C: CurveAffine> KeccakRead<R, C> {
+    pub fn init(reader: R) -> Self {
+        KeccakRead {
+            state: KeccakState::new(),
+            reader,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: Read, C: CurveAffine> Transcript<C, Challenge255<C>> for KeccakRead<R, C>
+where
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn squeeze_challenge(&mut self) -> Challenge255<C> {
+        Challenge255::new(&self.state.squeeze())
+    }
+
+    fn common_point(&mut self, point: C) -> io::Result<()> {
+        self.state.common_point(point)
+    }
+
+    fn common_scalar(&mut self, scalar: C::Scalar) -> io::Result<()> {
+        self.state.common_scalar(scalar)
+    }
+}
+
+impl<R: Read, C: CurveAffine> TranscriptRead<C, Challenge255<C>> for KeccakRead<R, C>
+where
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn read_point(&mut self) -> io::Result<C> {
+        let mut compressed = C::Repr::default();
+        self.reader.read_exact(compressed.as_mut())?;
+        let point: C = Option::from(C::from_bytes(&compressed))
+            .ok_or_else(|| io::Error::other("invalid point encoding in proof"))?;
+        self.state.common_point(point)?;
+        Ok(point)
+    }
+
+    fn read_scalar(&mut self) -> io::Result<C::Scalar> {
+        let mut data = <C::Scalar as PrimeField>::Repr::default();
+        self.reader.read_exact(data.as_mut())?;
+        let scalar: C::Scalar = Option::from(C::Scalar::from_repr(data))
+            .ok_or_else(|| io::Error::other("invalid field element encoding in proof"))?;
+        self.state.common_scalar(scalar)?;
+        Ok(scalar)
+    }
+}
@@ -0,0 +1,79 @@
+use group::ff::PrimeField;
+use halo2_proofs::{circuit::Value, pasta::Fp};
+use rand_core::RngCore;
+use serde_json::{json, Map};
+
+use crate::SimpleCircuit;
+
+///////////////////////////////////////////////////////////////////////
+/// 可重放的 witness 导出格式：真实证明失败时，把电路的输入保存为 JSON，之后可以原样加载并重现
+/// 格式为 {"constant": "<hex>", "a": "<hex>", "b": "<hex>"}，域元素为小端字节表示的十六进制；
+/// witness 未知时（例如 without_witnesses 得到的电路）对应的值为 null。
+/// 加上 public input，就可以完整地重现一次证明。
+///
+/// 导出 SimpleCircuit 的输入
+pub fn dump_witness(circuit: &SimpleCircuit<Fp>) -> String {
+    json!({
+        "constant": fp_to_hex(&circuit.constant),
+        "a": value_to_json(circuit.a),
+        "b": value_to_json(circuit.b),
+    })
+    .to_string()
+}
+
+/// 随机生成有效的 witness：返回一个随机的电路实例和与之匹配的 public input，用于生成演示和测试数据。
+/// 决定电路形状的参数（数组长度、limb 个数等）固定为示例 main 中使用的值，
+/// 所以示例 main 中使用的 k 对随机的电路同样适用
+pub trait RandomWitness: Sized {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>);
+}
+
+/// 从 dump_witness 导出的 JSON 加载电路，格式错误时返回错误信息
+pub fn load_witness(json: &str) -> Result<SimpleCircuit<Fp>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("invalid json: {}", e))?;
+    let fields = value
+        .as_object()
+        .ok_or_else(|| "witness must be a json object".to_string())?;
+
+    let constant = match field(fields, "constant")? {
+        Some(constant) => constant,
+        None => return Err("\"constant\" must not be null".to_string()),
+    };
+    Ok(SimpleCircuit {
+        constant,
+        a: field(fields, "a")?.map_or(Value::unknown(), Value::known),
+        b: field(fields, "b")?.map_or(Value::unknown(), Value::known),
+    })
+}
+
+fn fp_to_hex(x: &Fp) -> String {
+    hex::encode(x.to_repr())
+}
+
+/// 解析小端字节表示的十六进制域元素（可以带 0x 前缀），必须为 32 字节且小于域的模数
+pub(crate) fn fp_from_hex(s: &str) -> Result<Fp, String> {
+    let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| e.to_string())?;
+    let repr: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| "must be 32 bytes".to_string())?;
+    Option::from(Fp::from_repr(repr)).ok_or_else(|| "not a canonical field element".to_string())
+}
+
+fn value_to_json(value: Value<Fp>) -> serde_json::Value {
+    let mut json = serde_json::Value::Null;
+    value.map(|x| json = fp_to_hex(&x).into());
+    json
+}
+
+/// 取出名为 name 的域元素，值为 null 时返回 None
+fn field(fields: &Map<String, serde_json::Value>, name: &str) -> Result<Option<Fp>, String> {
+    match fields.get(name) {
+        None => Err(format!("missing field \"{}\"", name)),
+        Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => fp_from_hex(s)
+            .map(Some)
+            .map_err(|e| format!("field \"{}\": {}", name, e)),
+        Some(_) => Err(format!("field \"{}\" must be a hex string or null", name)),
+    }
+}
@@ -0,0 +1,422 @@
+//! BitInstructions 的测试：比较、截断、进制分解、带余除法、异或、min_max 和奇偶
+
+mod common;
+
+use common::{Op, OpCircuit};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use simple::{dev, layout, ACell, BitInstructions, NumInstructions, SimpleChip, SimpleConfig};
+
+/// 约束 x1 > x0（8 bit），不导出 public input
+struct AssertIncreasing;
+impl Op for AssertIncreasing {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.assert_increasing(layouter, xs[0].clone(), xs[1].clone(), 8)?;
+        Ok(vec![])
+    }
+}
+
+/// 单调递增：递增的一对通过，相等或递减的一对失败
+#[test]
+fn assert_increasing() {
+    for (prev, next, ok) in [
+        (3u64, 4u64, true),
+        (0, 255, true),
+        (7, 7, false),
+        (9, 2, false),
+    ] {
+        let res = MockProver::run(
+            6,
+            &OpCircuit::<AssertIncreasing>::new(&[Fp::from(prev), Fp::from(next)]),
+            vec![vec![]],
+        )
+        .unwrap()
+        .verify();
+        assert_eq!(res.is_ok(), ok, "assert_increasing({}, {})", prev, next);
+    }
+}
+
+/// 把 x0 截断到 [10, 20] 中，输入为 8 bit
+struct Clamp;
+impl Op for Clamp {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let clamped = chip.clamp(layouter, xs[0].clone(), Fp::from(10), Fp::from(20), 8)?;
+        Ok(vec![clamped])
+    }
+}
+
+/// 截断到 [10, 20]：小于 lo、在范围内（包括两端）、大于 hi
+#[test]
+fn clamp() {
+    for (x, clamped) in [(3, 10), (10, 10), (15, 15), (20, 20), (200, 20)] {
+        let res = MockProver::run(
+            7,
+            &OpCircuit::<Clamp>::new(&[Fp::from(x)]),
+            vec![vec![Fp::from(clamped)]],
+        )
+        .unwrap()
+        .verify();
+        assert_eq!(res, Ok(()), "clamp {}", x);
+    }
+    let wrong = MockProver::run(
+        7,
+        &OpCircuit::<Clamp>::new(&[Fp::from(3)]),
+        vec![vec![Fp::from(3)]],
+    )
+    .unwrap()
+    .verify();
+    assert!(wrong.is_err());
+}
+
+/// 把 x0 分解为 DIGITS 个 BASE 进制的数字，依次公开（低位在前）
+struct DecomposeRadix<const BASE: u64, const DIGITS: usize>;
+impl<const BASE: u64, const DIGITS: usize> Op for DecomposeRadix<BASE, DIGITS> {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.decompose_radix(layouter, xs[0].clone(), BASE, DIGITS)
+    }
+}
+
+/// 进制分解：1234 = [4, 3, 2, 1]（十进制），0xbeef = [f, e, e, b]（十六进制），公开的数字与电路外的计算一致
+#[test]
+fn decompose_radix() {
+    let decimal = OpCircuit::<DecomposeRadix<10, 4>>::new(&[Fp::from(1234)]);
+    let hex_digits = OpCircuit::<DecomposeRadix<16, 4>>::new(&[Fp::from(0xbeef)]);
+    let k = layout::measure(&decimal)
+        .k
+        .max(layout::measure(&hex_digits).k);
+    let res = MockProver::run(k, &decimal, vec![[4, 3, 2, 1].map(Fp::from).to_vec()])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    let res = MockProver::run(
+        k,
+        &hex_digits,
+        vec![[0xf, 0xe, 0xe, 0xb].map(Fp::from).to_vec()],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(res, Ok(()));
+
+    // 数字的顺序错误时，拷贝约束不满足
+    assert!(
+        MockProver::run(k, &decimal, vec![[1, 2, 3, 4].map(Fp::from).to_vec()])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+    // 1234 用 3 位十进制数表示时最高位为 12，超出 [0, 10)，范围检查失败（分解本身仍然成立）
+    let too_few = OpCircuit::<DecomposeRadix<10, 3>>::new(&[Fp::from(1234)]);
+    assert!(
+        MockProver::run(k, &too_few, vec![[4, 3, 12].map(Fp::from).to_vec()])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+    // 十六进制的最高位超出 [0, 16)：0x1beef 用 4 位表示
+    let hex_too_few = OpCircuit::<DecomposeRadix<16, 4>>::new(&[Fp::from(0x1beef)]);
+    assert!(MockProver::run(
+        k,
+        &hex_too_few,
+        vec![[0xf, 0xe, 0xe, 0x1b].map(Fp::from).to_vec()],
+    )
+    .unwrap()
+    .verify()
+    .is_err());
+
+    // base 小于 2 或没有数字时合成电路返回 Synthesis 错误，而不是 panic
+    let res = MockProver::run(
+        k,
+        &OpCircuit::<DecomposeRadix<1, 4>>::new(&[Fp::one()]),
+        vec![vec![]],
+    );
+    assert!(matches!(res, Err(Error::Synthesis)));
+    let res = MockProver::run(
+        k,
+        &OpCircuit::<DecomposeRadix<10, 0>>::new(&[Fp::one()]),
+        vec![vec![]],
+    );
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// 带余除法 x0 = q * DIVISOR + r，依次公开 q、r；x0 需要小于 2^8
+struct DivMod<const DIVISOR: u64>;
+impl<const DIVISOR: u64> Op for DivMod<DIVISOR> {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let (q, r) = chip.divmod(layouter, xs[0].clone(), DIVISOR, 8)?;
+        Ok(vec![q, r])
+    }
+}
+
+/// 不计算商和余数，直接把 x1、x2 当作 x0 除以 DIVISOR 的商和余数检查，用来模拟作弊的证明者
+struct AssertDivMod<const DIVISOR: u64>;
+impl<const DIVISOR: u64> Op for AssertDivMod<DIVISOR> {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.assert_divmod(
+            layouter,
+            xs[0].clone(),
+            xs[1].clone(),
+            xs[2].clone(),
+            DIVISOR,
+            8,
+        )?;
+        Ok(vec![xs[1].clone(), xs[2].clone()])
+    }
+}
+
+/// 带余除法：17 = 3 * 5 + 2，20 = 4 * 5 + 0
+#[test]
+fn divmod() {
+    let divmod_17 = OpCircuit::<DivMod<5>>::new(&[Fp::from(17)]);
+    let k = layout::measure(&divmod_17).k;
+    for (a, q, r) in [(17, 3, 2), (20, 4, 0)] {
+        let res = MockProver::run(
+            k,
+            &OpCircuit::<DivMod<5>>::new(&[Fp::from(a)]),
+            vec![vec![Fp::from(q), Fp::from(r)]],
+        )
+        .unwrap()
+        .verify();
+        assert_eq!(res, Ok(()), "{} divmod 5", a);
+    }
+    assert!(
+        MockProver::run(k, &divmod_17, vec![vec![Fp::from(2), Fp::from(7)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+
+    // 作弊的证明者给出 17 = 2 * 5 + 7：等式成立，但 r >= divisor，范围检查失败
+    let cheat = OpCircuit::<AssertDivMod<5>>::new(&[17, 2, 7].map(Fp::from));
+    assert!(
+        MockProver::run(k, &cheat, vec![vec![Fp::from(2), Fp::from(7)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+    // 正确的商和余数通过同一个检查
+    let honest = OpCircuit::<AssertDivMod<5>>::new(&[17, 3, 2].map(Fp::from));
+    assert_eq!(
+        MockProver::run(k, &honest, vec![vec![Fp::from(3), Fp::from(2)]])
+            .unwrap()
+            .verify(),
+        Ok(())
+    );
+
+    // divisor 小于 2 时合成电路返回 Synthesis 错误，而不是 panic
+    let res = MockProver::run(
+        k,
+        &OpCircuit::<DivMod<1>>::new(&[Fp::from(17)]),
+        vec![vec![]],
+    );
+    assert!(matches!(res, Err(Error::Synthesis)));
+    let res = MockProver::run(
+        k,
+        &OpCircuit::<AssertDivMod<0>>::new(&[17, 3, 2].map(Fp::from)),
+        vec![vec![]],
+    );
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// 所有输入的异或，输入都需要是字节
+struct XorFold;
+impl Op for XorFold {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.xor_fold(layouter, &xs)?])
+    }
+}
+
+/// 字节的异或校验和，与 Rust 中的 fold 结果一致；空输入为 0
+#[test]
+fn xor_fold() {
+    let bytes: [u64; 4] = [0x12, 0xa5, 0xff, 0x3c];
+    let checksum = bytes.iter().fold(0, |a, b| a ^ b);
+    let circuit = OpCircuit::<XorFold>::new(&bytes.map(Fp::from));
+    let k = layout::measure(&circuit).k;
+    let res = MockProver::run(k, &circuit, vec![vec![Fp::from(checksum)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    assert!(
+        MockProver::run(k, &circuit, vec![vec![Fp::from(checksum ^ 1)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+    assert_eq!(
+        MockProver::run(k, &OpCircuit::<XorFold>::new(&[]), vec![vec![Fp::zero()]])
+            .unwrap()
+            .verify(),
+        Ok(())
+    );
+    // 不是字节的输入无法分解为 8 位
+    let not_byte = OpCircuit::<XorFold>::new(&[0x12, 0x100].map(Fp::from));
+    assert!(
+        MockProver::run(k, &not_byte, vec![vec![Fp::from(0x12 ^ 0x100)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+}
+
+/// 配置了异或表的芯片上的 xor_fold：加载表后对所有输入链式查表，公开校验和
+#[derive(Default)]
+struct XorTableCircuit {
+    bytes: Vec<Value<Fp>>,
+}
+
+impl Circuit<Fp> for XorTableCircuit {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            bytes: vec![Value::unknown(); self.bytes.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure_with_xor_table(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::construct(config);
+        chip.load_xor_table(layouter.namespace(|| "xor table"))?;
+        let bytes = self
+            .bytes
+            .iter()
+            .map(|x| chip.load_private(layouter.namespace(|| "load byte"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let checksum = chip.xor_fold(layouter.namespace(|| "xor_fold"), &bytes)?;
+        chip.expose_public(layouter.namespace(|| "expose checksum"), checksum, 0)
+    }
+}
+
+/// 异或表有 2^16 行，电路需要 k = 17；结果与 Rust 中的 fold 一致，不是字节的输入查不到表
+#[test]
+fn xor_fold_with_table() {
+    const K: u32 = 17;
+    let circuit = |bytes: &[u64]| XorTableCircuit {
+        bytes: bytes.iter().map(|b| Value::known(Fp::from(*b))).collect(),
+    };
+    let bytes = [0x12, 0xa5, 0xff, 0x3c];
+    let checksum = bytes.iter().fold(0, |a, b| a ^ b);
+    let res = MockProver::run(K, &circuit(&bytes), vec![vec![Fp::from(checksum)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+    assert!(
+        MockProver::run(K, &circuit(&bytes), vec![vec![Fp::from(checksum ^ 1)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+    assert_eq!(
+        MockProver::run(K, &circuit(&[]), vec![vec![Fp::zero()]])
+            .unwrap()
+            .verify(),
+        Ok(())
+    );
+    let not_byte = MockProver::run(K, &circuit(&[0x12, 0x100]), vec![vec![Fp::zero()]]).unwrap();
+    dev::assert_constraint_failure(&not_byte, "lookup 0");
+}
+
+/// 比较 8 bit 的 x0 和 x1，依次公开较小者和较大者
+struct MinMax;
+impl Op for MinMax {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let (min, max) = chip.min_max(layouter, xs[0].clone(), xs[1].clone(), 8)?;
+        Ok(vec![min, max])
+    }
+}
+
+/// (min, max)：a < b、a > b、a = b
+#[test]
+fn min_max() {
+    for (a, b, min, max) in [(3, 200, 3, 200), (200, 3, 3, 200), (42, 42, 42, 42)] {
+        let circuit = OpCircuit::<MinMax>::new(&[a, b].map(Fp::from));
+        let res = MockProver::run(7, &circuit, vec![vec![Fp::from(min), Fp::from(max)]])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()), "min_max({}, {})", a, b);
+    }
+    // 顺序反了：(max, min) 不对
+    let swapped = OpCircuit::<MinMax>::new(&[3, 200].map(Fp::from));
+    assert!(
+        MockProver::run(7, &swapped, vec![vec![Fp::from(200), Fp::from(3)]])
+            .unwrap()
+            .verify()
+            .is_err()
+    );
+}
+
+/// x0 为偶数时公开 1，否则公开 0；x0 需要小于 2^BITS
+struct IsEven<const BITS: usize>;
+impl<const BITS: usize> Op for IsEven<BITS> {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.is_even(layouter, xs[0].clone(), BITS)?])
+    }
+}
+
+/// 6 为偶数、7 为奇数；num_bits 为 0 时合成电路返回 Synthesis 错误，而不是 panic
+#[test]
+fn is_even() {
+    for (x, even) in [(6, 1), (7, 0)] {
+        let res = MockProver::run(
+            6,
+            &OpCircuit::<IsEven<8>>::new(&[Fp::from(x)]),
+            vec![vec![Fp::from(even)]],
+        )
+        .unwrap()
+        .verify();
+        assert_eq!(res, Ok(()), "is_even({})", x);
+    }
+    let res = MockProver::run(
+        6,
+        &OpCircuit::<IsEven<0>>::new(&[Fp::from(6)]),
+        vec![vec![]],
+    );
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
@@ -0,0 +1,228 @@
+//! SimpleCircuit 本身的测试：public input、REPS、builder、多个 advice 列、布局器和 witness 的加载
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::{example_circuit, ROW};
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{floor_planner::V1, Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use simple::{
+    dev,
+    planner::{self, WithPlanner},
+    prover::{self, DescribeInstance},
+    witness::{self, RandomWitness},
+    ACell, MissingInput, NumInstructions, SimpleChip, SimpleCircuit, SimpleConfig,
+};
+
+#[test]
+fn accepts_correct_public_input() {
+    let (circuit, c) = example_circuit();
+    let prover = MockProver::run(ROW, &circuit, vec![vec![c]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+/// 没有乘以常数的 public input a^2 * b^2 = 36 被拒绝：
+/// 失败的是拷贝约束，instance 列的第0行与 “乘以常数” 这个 mul region 的输出不相等
+#[test]
+fn rejects_missing_constant() {
+    let (circuit, _) = example_circuit();
+    let (a, b) = (Fp::from(2), Fp::from(3));
+    let missing_constant = a.square() * b.square();
+    let prover = MockProver::run(ROW, &circuit, vec![vec![missing_constant]]).unwrap();
+    let res = prover.verify();
+    assert!(res.is_err());
+    assert!(res
+        .unwrap_err()
+        .iter()
+        .any(|failure| failure.to_string().contains("('mul')")));
+}
+
+/// REPS = 1 和 REPS = 3 时，电路输出与电路外计算的 constant * (a*b)^(2*REPS) 一致
+#[test]
+fn reps_match_off_circuit_power() {
+    let (constant, a, b) = (Fp::from(2), Fp::from(2), Fp::from(3));
+    let circuit_1: SimpleCircuit<Fp, 1> = SimpleCircuit {
+        constant,
+        a: Value::known(a),
+        b: Value::known(b),
+    };
+    let expected_1 = constant * (a * b).pow_vartime([2]);
+    let prover = MockProver::run(ROW, &circuit_1, vec![vec![expected_1]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let circuit_3: SimpleCircuit<Fp, 3> = SimpleCircuit {
+        constant,
+        a: Value::known(a),
+        b: Value::known(b),
+    };
+    let expected_3 = constant * (a * b).pow_vartime([6]);
+    let prover = MockProver::run(ROW, &circuit_3, vec![vec![expected_3]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    // REPS = 1 的结果不是 REPS = 3 的输出
+    let prover = MockProver::run(ROW, &circuit_3, vec![vec![expected_1]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 用 builder 构造电路，public input 由 builder 计算
+#[test]
+fn builder_computes_public_input() {
+    // 2 * (2 * 3)^2 = 72
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    assert_eq!(public, vec![Fp::from(72)]);
+    let prover = MockProver::run(ROW, &circuit, vec![public]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    // 未设置 b 时默认为 1：3 * (5 * 1)^2 = 75
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(5).constant(3).build();
+    assert_eq!(public, vec![Fp::from(75)]);
+    let prover = MockProver::run(ROW, &circuit, vec![public]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+/// 按名字提供 witness，缺少 "b" 时返回错误，并指出缺少的名字
+#[test]
+fn from_named_inputs() {
+    let named: HashMap<String, Fp> = [("a", 2), ("b", 3), ("constant", 2)]
+        .into_iter()
+        .map(|(name, v)| (name.to_string(), Fp::from(v)))
+        .collect();
+    let circuit = SimpleCircuit::<Fp>::from_named(named.clone()).unwrap();
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(72)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let mut incomplete = named;
+    incomplete.remove("b");
+    let missing = SimpleCircuit::<Fp>::from_named(incomplete).err();
+    assert_eq!(missing, Some(MissingInput { name: "b" }));
+}
+
+/// 使用 3 个 advice 列的电路：3 个 private input 通过 add_n 在同一行相加，结果导出到 instance 列的第0行
+#[derive(Default)]
+struct AddThreeCircuit {
+    inputs: [Value<Fp>; 3],
+}
+
+impl Circuit<Fp> for AddThreeCircuit {
+    type Config = SimpleConfig<3>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<Fp, 3>::construct(config);
+        let mut xs = Vec::new();
+        for x in self.inputs {
+            xs.push(chip.load_private(layouter.namespace(|| "load input"), x)?);
+        }
+        let xs: [ACell<Fp>; 3] = xs.try_into().unwrap();
+        let sum = chip.add_n(layouter.namespace(|| "x0 + x1 + x2"), xs)?;
+        chip.expose_public(layouter.namespace(|| "expose sum"), sum, 0)
+    }
+}
+
+/// 3 个 advice 列：2 + 3 + 4 = 9 在一行中完成
+#[test]
+fn add_n_with_three_advice_columns() {
+    let add_three = AddThreeCircuit {
+        inputs: [2, 3, 4].map(|x| Value::known(Fp::from(x))),
+    };
+    let prover = MockProver::run(ROW, &add_three, vec![vec![Fp::from(9)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    let prover = MockProver::run(ROW, &add_three, vec![vec![Fp::from(10)]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 分别使用 SimpleFloorPlanner 和 V1 布局器，电路都能验证通过
+#[test]
+fn floor_planners_agree() {
+    let (circuit, c) = example_circuit();
+    let simple_planner = WithPlanner::<_, SimpleFloorPlanner>::new(circuit);
+    let prover = MockProver::run(ROW, &simple_planner, vec![vec![c]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let v1_planner = WithPlanner::<_, V1>::new(simple_planner.circuit);
+    let prover = MockProver::run(ROW, &v1_planner, vec![vec![c]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+/// 两种布局器对正确和错误的 public input 给出相同的结果
+#[test]
+fn planner_agnostic() {
+    let (circuit, c) = example_circuit();
+    assert!(planner::assert_planner_agnostic(ROW, &circuit, &[vec![c]]));
+    assert!(!planner::assert_planner_agnostic(
+        ROW,
+        &circuit,
+        &[vec![c + Fp::one()]]
+    ));
+}
+
+/// instance 列的形状：SimpleCircuit 只有一个 instance 列，只用第0行；换布局器不改变形状。
+/// prover 检查的 public input 个数由形状求和得到
+#[test]
+fn instance_shape() {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().build();
+    assert_eq!(circuit.instance_shape(), vec![1]);
+    assert_eq!(circuit.instance_shape(), vec![public.len()]);
+    assert_eq!(circuit.instance_count(), 1);
+    assert_eq!(WithPlanner::<_, V1>::new(circuit).instance_shape(), vec![1]);
+}
+
+/// 随机的 witness：种子固定时每次运行得到相同的电路，每个随机电路都与它的 public input 匹配
+#[test]
+fn random_witness_matches_public_input() {
+    let mut rng = ChaCha20Rng::from_seed([7; 32]);
+    for _ in 0..4 {
+        let (random, public) = SimpleCircuit::<Fp>::random(&mut rng);
+        let prover = MockProver::run(ROW, &random, public).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+    assert!(dev::check_random::<SimpleCircuit<Fp>>(ROW, 4).is_ok());
+    assert!(dev::check_random::<SimpleCircuit<Fp, 2>>(ROW + 1, 4).is_ok());
+}
+
+/// witness 导出后重新加载：输入不变，验证密钥的指纹也不变
+#[test]
+fn witness_dump_and_load() {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let dumped = witness::dump_witness(&circuit);
+    let loaded = witness::load_witness(&dumped).unwrap();
+    assert_eq!(witness::dump_witness(&loaded), dumped);
+    assert_eq!(
+        prover::vk_fingerprint_of(ROW, &loaded),
+        prover::vk_fingerprint_of(ROW, &circuit)
+    );
+    let prover = MockProver::run(ROW, &loaded, vec![public]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    // 没有 witness 的电路也能导出，a、b 为 null
+    let structure =
+        witness::load_witness(&witness::dump_witness(&loaded.without_witnesses())).unwrap();
+    assert_eq!(structure.constant, loaded.constant);
+    assert!(witness::load_witness("{\"a\": null}").is_err());
+}
@@ -0,0 +1,115 @@
+//! 多个测试文件共用的电路：OpCircuit 加载若干个 private input，执行 Op 定义的指令，
+//! 并把返回的每个结果依次导出到 instance 列，用来单独测试 NumInstructions 中的某条指令
+#![allow(dead_code)]
+
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use simple::{ACell, NumInstructions, SimpleChip, SimpleCircuit, SimpleConfig};
+use std::marker::PhantomData;
+
+/// 示例 main 中使用的行数
+pub const ROW: u32 = 5;
+
+/// 示例 main 中的电路：constant = 2，a = 2，b = 3，返回电路和 c = 2 * (2 * 3)^2 = 72
+pub fn example_circuit() -> (SimpleCircuit<Fp>, Fp) {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    (circuit, public[0])
+}
+
+pub trait Op {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error>;
+}
+
+pub struct OpCircuit<O: Op> {
+    inputs: Vec<Value<Fp>>,
+    _marker: PhantomData<O>,
+}
+
+impl<O: Op> OpCircuit<O> {
+    pub fn new(inputs: &[Fp]) -> Self {
+        Self {
+            inputs: inputs.iter().map(|x| Value::known(*x)).collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<O: Op> Circuit<Fp> for OpCircuit<O> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            inputs: vec![Value::unknown(); self.inputs.len()],
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::construct(config);
+        let xs = self
+            .inputs
+            .iter()
+            .map(|x| chip.load_private(layouter.namespace(|| "load input"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let outs = O::apply(&chip, layouter.namespace(|| "op"), xs)?;
+        for (row, out) in outs.into_iter().enumerate() {
+            chip.expose_public(layouter.namespace(|| "expose output"), out, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// 证明 x0 是二次剩余，不公开平方根
+pub struct IsSquare;
+impl Op for IsSquare {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.assert_is_square(layouter, xs[0].clone())?;
+        Ok(vec![])
+    }
+}
+
+/// 分别计算 x0 * x1 和 x2 * x3（两个不同的 mul region），并约束两个乘积相等
+pub struct Link;
+impl Op for Link {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let lhs = chip.mul(
+            layouter.namespace(|| "x0 * x1"),
+            xs[0].clone(),
+            xs[1].clone(),
+        )?;
+        let rhs = chip.mul(
+            layouter.namespace(|| "x2 * x3"),
+            xs[2].clone(),
+            xs[3].clone(),
+        )?;
+        chip.link(layouter.namespace(|| "x0 * x1 = x2 * x3"), lhs, rhs)?;
+        Ok(vec![])
+    }
+}
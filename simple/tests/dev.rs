@@ -0,0 +1,183 @@
+//! dev 模块的测试：失败信息的格式化、门的导出和 simple_gate! 宏
+
+mod common;
+
+use common::{example_circuit, IsSquare, Link, OpCircuit, ROW};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector},
+    poly::Rotation,
+};
+use simple::{dev, simple_gate, SimpleCircuit};
+
+/// run_and_check：public input 正确时通过；差 1 时给出具体的不一致位置
+#[test]
+fn run_and_check_reports_public_input_mismatch() {
+    let (circuit, c) = example_circuit();
+    assert!(dev::run_and_check(ROW, &circuit, vec![vec![c]]).is_ok());
+    let message = dev::run_and_check(ROW, &circuit, vec![vec![c + Fp::one()]]).unwrap_err();
+    assert!(message.contains("public input mismatch at instance column 0, row 0"));
+}
+
+/// SimpleCircuit 的约束多项式中只有一个门是乘法门
+#[test]
+fn dump_gates_has_one_mul_gate() {
+    let gates = dev::dump_gates::<SimpleCircuit<Fp>>().unwrap();
+    assert_eq!(gates.iter().filter(|gate| gate.contains("mul")).count(), 1);
+}
+
+/// SimpleCircuit 的门和拷贝约束参数的次数都是 3
+#[test]
+fn measure_degree() {
+    assert_eq!(dev::measure_degree::<SimpleCircuit<Fp>>(), 3);
+}
+
+/// fixed 列的查询数（包括选择器压缩成的 fixed 列）可以从 CircuitCost 中读出
+#[test]
+fn measure_fixed_queries() {
+    let (circuit, _) = example_circuit();
+    assert_eq!(dev::measure_fixed_queries(ROW, &circuit), Some(7));
+}
+
+/// verify_pretty：满足时返回 Ok，不满足时返回格式化的失败信息
+#[test]
+fn verify_pretty() {
+    let satisfied = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(9)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    assert!(dev::verify_pretty(&satisfied).is_ok());
+
+    let non_residue = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(5)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    let pretty = dev::verify_pretty(&non_residue).unwrap_err();
+    assert!(pretty.contains("('mul')") && pretty.contains("is_square"));
+}
+
+/// 带列名的失败信息：列显示为 Column('Advice', 0 - lhs/out)，而不只是序号
+#[test]
+fn verify_annotated_names_columns() {
+    let names = OpCircuit::<IsSquare>::configure(&mut ConstraintSystem::default()).column_names();
+    let satisfied = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(9)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    assert!(dev::verify_annotated(&satisfied, &names).is_ok());
+
+    let non_residue = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(5)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    let annotated = dev::verify_annotated(&non_residue, &names).unwrap_err();
+    assert!(annotated.contains("Column('Advice', 0 - lhs/out)"));
+    assert!(annotated.contains("Column('Advice', 1 - rhs)"));
+    assert!(!annotated.contains("Column('Advice', 0)"));
+
+    // 拷贝约束的失败同样显示列名
+    let names = OpCircuit::<Link>::configure(&mut ConstraintSystem::default()).column_names();
+    let unlinked = MockProver::run(
+        ROW,
+        &OpCircuit::<Link>::new(&[2, 6, 3, 5].map(Fp::from)),
+        vec![vec![]],
+    )
+    .unwrap();
+    let report = dev::verify_annotated(&unlinked, &names).unwrap_err();
+    assert!(report.contains("(Column('Advice', 0 - lhs/out), in Region"));
+}
+
+/// 只有一个乘法门的电路，用来对比手写的门和 simple_gate! 生成的门
+/// MACRO 为 true 时用 simple_gate! 定义，否则手写 selector + create_gate
+/// | a0  | a1  | s   |
+/// |-----|-----|-----|
+/// | lhs | rhs | 1   |
+/// | out |     |     |
+#[derive(Default)]
+struct MulGateCircuit<const MACRO: bool> {
+    lhs: Value<Fp>,
+    rhs: Value<Fp>,
+    out: Value<Fp>,
+}
+
+impl<const MACRO: bool> Circuit<Fp> for MulGateCircuit<MACRO> {
+    type Config = ([Column<Advice>; 2], Selector);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        if MACRO {
+            let s = simple_gate!(meta, "mul", {
+                lhs: (advice[0], Rotation::cur()),
+                rhs: (advice[1], Rotation::cur()),
+                out: (advice[0], Rotation::next()),
+            } => lhs * rhs - out);
+            return (advice, s);
+        }
+        let s = meta.selector();
+        meta.create_gate("mul", |meta| {
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let out = meta.query_advice(advice[0], Rotation::next());
+            let s = meta.query_selector(s);
+            vec![s * (lhs * rhs - out)]
+        });
+        (advice, s)
+    }
+
+    fn synthesize(
+        &self,
+        (advice, s): Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "mul",
+            |mut region| {
+                s.enable(&mut region, 0)?;
+                region.assign_advice(|| "lhs", advice[0], 0, || self.lhs)?;
+                region.assign_advice(|| "rhs", advice[1], 0, || self.rhs)?;
+                region.assign_advice(|| "out", advice[0], 1, || self.out)?;
+                Ok(())
+            },
+        )
+    }
+}
+
+/// 用同样的 witness 分别运行手写的门和宏生成的门，返回两者的验证结果是否都通过
+fn check_mul_gates(lhs: u64, rhs: u64, out: u64) -> (bool, bool) {
+    let [lhs, rhs, out] = [lhs, rhs, out].map(|x| Value::known(Fp::from(x)));
+    let hand = MulGateCircuit::<false> { lhs, rhs, out };
+    let generated = MulGateCircuit::<true> { lhs, rhs, out };
+    (
+        MockProver::run(4, &hand, vec![]).unwrap().verify().is_ok(),
+        MockProver::run(4, &generated, vec![])
+            .unwrap()
+            .verify()
+            .is_ok(),
+    )
+}
+
+/// simple_gate! 生成的门与手写的门有相同的约束多项式，对同样的 witness 验证结果相同
+#[test]
+fn simple_gate_matches_hand_written_gate() {
+    assert_eq!(
+        dev::dump_gates::<MulGateCircuit<true>>().unwrap(),
+        dev::dump_gates::<MulGateCircuit<false>>().unwrap()
+    );
+    assert_eq!(check_mul_gates(3, 4, 12), (true, true));
+    assert_eq!(check_mul_gates(3, 4, 13), (false, false));
+}
@@ -0,0 +1,69 @@
+//! faults feature（cargo test -p simple --features faults）：FaultyChip 注入的错误能被 MockProver 发现
+#![cfg(feature = "faults")]
+
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use simple::{
+    dev,
+    faults::{Fault, FaultyChip},
+    NumInstructions, SimpleChip, SimpleConfig,
+};
+
+/// a * b 作为唯一公开输出的电路，指令由可以注入错误的 FaultyChip 执行
+struct FaultyMulCircuit {
+    a: Value<Fp>,
+    b: Value<Fp>,
+    fault: Option<Fault>,
+}
+
+impl Circuit<Fp> for FaultyMulCircuit {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            a: Value::unknown(),
+            b: Value::unknown(),
+            fault: self.fault,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip = FaultyChip::construct(config, self.fault);
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+        let c = chip.mul(layouter.namespace(|| "a * b"), a, b)?;
+        chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
+    }
+}
+
+/// FaultyChip 让 mul 输出 a * b + 1：公开的是错误的结果 7，拷贝约束仍然满足，但 MockProver 报告 "mul" 门不满足
+#[test]
+fn faulty_mul_fails_the_mul_gate() {
+    let faulty = |fault| FaultyMulCircuit {
+        a: Value::known(Fp::from(2)),
+        b: Value::known(Fp::from(3)),
+        fault,
+    };
+    let honest = MockProver::run(4, &faulty(None), vec![vec![Fp::from(6)]]).unwrap();
+    assert_eq!(honest.verify(), Ok(()));
+    let cheated = MockProver::run(4, &faulty(Some(Fault::Mul)), vec![vec![Fp::from(7)]]).unwrap();
+    let report = dev::verify_pretty(&cheated).unwrap_err();
+    assert!(report.contains("('mul') is not satisfied"));
+    assert_eq!(cheated.verify().unwrap_err().len(), 1);
+}
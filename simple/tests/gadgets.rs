@@ -0,0 +1,130 @@
+//! gadgets 模块的测试：与电路外的参考计算比较
+
+mod common;
+
+use common::{Op, OpCircuit, ROW};
+use halo2_proofs::{circuit::Layouter, dev::MockProver, pasta::Fp, plonk::Error};
+use simple::{dev, gadgets, ACell, NumInstructions, SimpleChip};
+
+/// gadget：x0^2 + x1
+struct SquareAndAdd;
+impl Op for SquareAndAdd {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![gadgets::square_and_add(
+            chip,
+            layouter,
+            xs[0].clone(),
+            xs[1].clone(),
+        )?])
+    }
+}
+
+#[test]
+fn square_and_add() {
+    let (x, y) = (Fp::from(7), Fp::from(5));
+    let res = MockProver::run(
+        ROW,
+        &OpCircuit::<SquareAndAdd>::new(&[x, y]),
+        vec![vec![x.square() + y]],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// gadget：输入的前一半为值，后一半为权重（个数为奇数时权重多一个），计算加权和
+struct WeightedSum;
+impl Op for WeightedSum {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let (values, weights) = xs.split_at(xs.len() / 2);
+        Ok(vec![gadgets::weighted_sum(
+            chip, layouter, values, weights,
+        )?])
+    }
+}
+
+/// 2 * 5 + 3 * 6 + 4 * 7 = 56
+#[test]
+fn weighted_sum() {
+    let values = [2, 3, 4].map(Fp::from);
+    let weights = [5, 6, 7].map(Fp::from);
+    let expected = values
+        .iter()
+        .zip(&weights)
+        .fold(Fp::zero(), |acc, (x, w)| acc + x * w);
+    assert_eq!(expected, Fp::from(56));
+    let res = MockProver::run(
+        6,
+        &OpCircuit::<WeightedSum>::new(&[values, weights].concat()),
+        vec![vec![expected]],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(res, Ok(()));
+
+    // 两个值、三个权重：合成电路时返回 Synthesis 错误，而不是 panic
+    let mismatched = OpCircuit::<WeightedSum>::new(&[2, 3, 4, 5, 6].map(Fp::from));
+    let res = MockProver::run(6, &mismatched, vec![vec![expected]]);
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// gadget：输入为 (x_i, bit_i) 对，从 0 开始，依次在 bit_i 为 1 时累加 x_i
+struct ConditionalSum;
+impl Op for ConditionalSum {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let mut acc = chip.load_constant(layouter.namespace(|| "zero"), Fp::zero())?;
+        for pair in xs.chunks(2) {
+            acc = gadgets::conditional_accumulate(
+                chip,
+                layouter.namespace(|| "accumulate"),
+                acc,
+                pair[0].clone(),
+                pair[1].clone(),
+            )?;
+        }
+        Ok(vec![acc])
+    }
+}
+
+#[test]
+fn conditional_accumulate() {
+    let pairs = [(10u64, 1u64), (20, 0), (30, 1)];
+    let expected = pairs
+        .iter()
+        .filter(|(_, bit)| *bit == 1)
+        .map(|(x, _)| Fp::from(*x))
+        .fold(Fp::zero(), |acc, x| acc + x);
+    let inputs: Vec<Fp> = pairs
+        .iter()
+        .flat_map(|(x, bit)| [Fp::from(*x), Fp::from(*bit)])
+        .collect();
+    let res = MockProver::run(
+        6,
+        &OpCircuit::<ConditionalSum>::new(&inputs),
+        vec![vec![expected]],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(res, Ok(()));
+
+    // bit 不是布尔值时 select 门的约束不满足
+    let bad_bit = MockProver::run(
+        6,
+        &OpCircuit::<ConditionalSum>::new(&[Fp::from(10), Fp::from(2)]),
+        vec![vec![Fp::from(20)]],
+    )
+    .unwrap();
+    dev::assert_constraint_failure(&bad_bit, "select");
+}
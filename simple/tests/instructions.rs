@@ -0,0 +1,590 @@
+//! NumInstructions 中各条指令的测试，每条指令由 OpCircuit 单独运行；以及 trait object 流水线和常量折叠
+
+mod common;
+
+use common::{IsSquare, Link, Op, OpCircuit, ROW};
+use group::ff::Field;
+use halo2_proofs::{
+    circuit::{Layouter, Region, SimpleFloorPlanner, Value},
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use simple::{
+    const_fold::ConstFoldChip,
+    dev,
+    dynamic::{run_pipeline, BoxedChip, DynNumInstructions, DynOp},
+    field::signed_to_fp,
+    layout, ACell, NumInstructions, SimpleChip, SimpleConfig,
+};
+
+/// 计算 a * b，并用 print_cell 打印乘法的结果
+/// print_cell 只读取单元格的值，不添加约束，所以不影响验证结果
+struct MulAndPrint;
+impl Op for MulAndPrint {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let ab = chip.mul(layouter.namespace(|| "a * b"), xs[0].clone(), xs[1].clone())?;
+        SimpleChip::<Fp>::print_cell("a * b", &ab);
+        Ok(vec![ab])
+    }
+}
+
+#[test]
+fn print_cell_does_not_change_verification() {
+    let circuit = OpCircuit::<MulAndPrint>::new(&[Fp::from(2), Fp::from(3)]);
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(6)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+/// 计算所有输入的乘积
+struct Product;
+impl Op for Product {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.product(layouter, &xs)?])
+    }
+}
+
+/// 2 * 3 * 4 = 24
+#[test]
+fn product() {
+    let circuit = OpCircuit::<Product>::new(&[2, 3, 4].map(Fp::from));
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(24)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(25)]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 计算 x^2
+struct Square;
+impl Op for Square {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.mul(layouter, xs[0].clone(), xs[0].clone())?])
+    }
+}
+
+/// 负数映射到域中：-1 对应 p - 1，(-3)^2 = 9
+#[test]
+fn square_of_negative_input() {
+    assert_eq!(signed_to_fp(-1), Fp::zero() - Fp::one());
+    let circuit = OpCircuit::<Square>::new(&[signed_to_fp(-3)]);
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(9)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+}
+
+/// 计算 a * b，并约束结果等于常数 6，不导出任何 public input
+struct PinProduct;
+impl Op for PinProduct {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let ab = chip.mul(layouter.namespace(|| "a * b"), xs[0].clone(), xs[1].clone())?;
+        chip.constrain_constant(layouter.namespace(|| "a * b == 6"), ab, Fp::from(6))?;
+        Ok(vec![])
+    }
+}
+
+/// 2 * 3 被约束为常数 6，验证成功；2 * 4 != 6，验证失败
+#[test]
+fn constrain_constant() {
+    let circuit = OpCircuit::<PinProduct>::new(&[Fp::from(2), Fp::from(3)]);
+    let prover = MockProver::run(ROW, &circuit, vec![vec![]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let circuit = OpCircuit::<PinProduct>::new(&[Fp::from(2), Fp::from(4)]);
+    let prover = MockProver::run(ROW, &circuit, vec![vec![]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 计算 x0^e，指数 e 由其余输入给出的二进制位表示（低位在前）
+struct PowVar;
+impl Op for PowVar {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let res = chip.pow_var(layouter.namespace(|| "base^exp"), xs[0].clone(), &xs[1..])?;
+        Ok(vec![res])
+    }
+}
+
+/// 3^5 = 243，5 的二进制位（低位在前）为 [1, 0, 1]；指数位不是布尔值时，select 门不满足
+#[test]
+fn pow_var() {
+    let circuit = OpCircuit::<PowVar>::new(&[3, 1, 0, 1].map(Fp::from));
+    let prover = MockProver::run(6, &circuit, vec![vec![Fp::from(243)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let circuit = OpCircuit::<PowVar>::new(&[3, 2, 0, 1].map(Fp::from));
+    let prover = MockProver::run(6, &circuit, vec![vec![Fp::from(243)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "select");
+}
+
+/// 计算 a ∧ b
+struct And;
+impl Op for And {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.and(
+            layouter.namespace(|| "a and b"),
+            xs[0].clone(),
+            xs[1].clone(),
+        )?])
+    }
+}
+
+/// 计算 a ∨ b
+struct Or;
+impl Op for Or {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.or(
+            layouter.namespace(|| "a or b"),
+            xs[0].clone(),
+            xs[1].clone(),
+        )?])
+    }
+}
+
+/// and、or 的完整真值表
+#[test]
+fn and_or_truth_table() {
+    for (a, b) in [(0u64, 0u64), (0, 1), (1, 0), (1, 1)] {
+        let inputs = [Fp::from(a), Fp::from(b)];
+        let and = MockProver::run(
+            ROW,
+            &OpCircuit::<And>::new(&inputs),
+            vec![vec![Fp::from(a & b)]],
+        )
+        .unwrap()
+        .verify();
+        let or = MockProver::run(
+            ROW,
+            &OpCircuit::<Or>::new(&inputs),
+            vec![vec![Fp::from(a | b)]],
+        )
+        .unwrap()
+        .verify();
+        assert!(and.is_ok() && or.is_ok(), "{} {}: {:?} {:?}", a, b, and, or);
+    }
+}
+
+/// 输入不是布尔值时验证失败：2 and 1 = 2，2 or 0 = 2，算术上成立，但布尔约束不满足
+#[test]
+fn and_or_reject_non_boolean_inputs() {
+    let and = MockProver::run(
+        ROW,
+        &OpCircuit::<And>::new(&[Fp::from(2), Fp::one()]),
+        vec![vec![Fp::from(2)]],
+    )
+    .unwrap();
+    dev::assert_constraint_failure(&and, "and");
+    let or = MockProver::run(
+        ROW,
+        &OpCircuit::<Or>::new(&[Fp::from(2), Fp::zero()]),
+        vec![vec![Fp::from(2)]],
+    )
+    .unwrap();
+    dev::assert_constraint_failure(&or, "or");
+}
+
+/// 从 instance 列的第0行加载 public input，并约束它等于 42
+struct PinInstance;
+impl Op for PinInstance {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        _xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.load_and_pin_instance(layouter, 0, Fp::from(42))?;
+        Ok(vec![])
+    }
+}
+
+/// public input 等于固定的常数 42 时通过，否则失败
+#[test]
+fn load_and_pin_instance() {
+    let circuit = OpCircuit::<PinInstance>::new(&[]);
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(42)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(43)]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 计算 x0^{-1}
+struct Inv;
+impl Op for Inv {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.inv(layouter, xs[0].clone())?])
+    }
+}
+
+/// 逆元：4^{-1}，以及 a = 0 时验证失败
+#[test]
+fn inv() {
+    let four_inv = Fp::from(4).invert().unwrap();
+    let prover = MockProver::run(
+        ROW,
+        &OpCircuit::<Inv>::new(&[Fp::from(4)]),
+        vec![vec![four_inv]],
+    )
+    .unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    let prover = MockProver::run(
+        ROW,
+        &OpCircuit::<Inv>::new(&[Fp::zero()]),
+        vec![vec![Fp::zero()]],
+    )
+    .unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 二次剩余：9 = 3^2 可以证明；5 是 Fp 乘法群的生成元，不是二次剩余，约束无法满足
+#[test]
+fn assert_is_square() {
+    let prover = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(9)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    assert!(bool::from(Fp::from(5).sqrt().is_none()));
+    let prover = MockProver::run(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(5)]),
+        vec![vec![]],
+    )
+    .unwrap();
+    dev::assert_constraint_failure(&prover, "mul");
+}
+
+/// link：2 * 6 = 3 * 4 时验证成功，2 * 6 != 3 * 5 时拷贝约束失败，
+/// 失败的位置是两个乘积所在的 mul region，而不是添加约束的 link region
+#[test]
+fn link() {
+    let linked = MockProver::run(
+        ROW,
+        &OpCircuit::<Link>::new(&[2, 6, 3, 4].map(Fp::from)),
+        vec![vec![]],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(linked, Ok(()));
+
+    let unlinked = MockProver::run(
+        ROW,
+        &OpCircuit::<Link>::new(&[2, 6, 3, 5].map(Fp::from)),
+        vec![vec![]],
+    )
+    .unwrap()
+    .verify();
+    let failures = unlinked.unwrap_err();
+    assert!(!failures.is_empty());
+    assert!(failures.iter().all(|failure| {
+        let failure = format!("{:?}", failure);
+        failure.contains("Permutation") && failure.contains("\"mul\"")
+    }));
+}
+
+/// 输入 a、b、c，约束 a^2 + b^2 = c^2，并公开 c
+struct Pythagorean;
+impl Op for Pythagorean {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        chip.assert_pythagorean(
+            layouter.namespace(|| "a^2 + b^2 = c^2"),
+            xs[0].clone(),
+            xs[1].clone(),
+            xs[2].clone(),
+        )?;
+        Ok(vec![xs[2].clone()])
+    }
+}
+
+/// 勾股数：(3, 4, 5) 满足 a^2 + b^2 = c^2，c 公开；(3, 4, 6) 中 25 != 36，link 的拷贝约束不满足
+#[test]
+fn assert_pythagorean() {
+    let right = MockProver::run(
+        ROW,
+        &OpCircuit::<Pythagorean>::new(&[3, 4, 5].map(Fp::from)),
+        vec![vec![Fp::from(5)]],
+    )
+    .unwrap()
+    .verify();
+    assert_eq!(right, Ok(()));
+
+    let not_right = MockProver::run(
+        ROW,
+        &OpCircuit::<Pythagorean>::new(&[3, 4, 6].map(Fp::from)),
+        vec![vec![Fp::from(6)]],
+    )
+    .unwrap()
+    .verify();
+    let failures = not_right.unwrap_err();
+    assert!(!failures.is_empty());
+    assert!(failures
+        .iter()
+        .all(|failure| format!("{:?}", failure).contains("Permutation")));
+}
+
+/// 点积 xs · [1, 2, 3]，输入个数不是 3 时 dot 返回错误
+struct Dot;
+impl Op for Dot {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        Ok(vec![chip.dot(layouter, &xs, &[1, 2, 3].map(Fp::from))?])
+    }
+}
+
+/// 10 * 1 + 20 * 2 + 30 * 3 = 140；长度不同时合成电路返回 Synthesis 错误，而不是 panic
+#[test]
+fn dot() {
+    let circuit = OpCircuit::<Dot>::new(&[10, 20, 30].map(Fp::from));
+    let prover = MockProver::run(ROW, &circuit, vec![vec![Fp::from(140)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let mismatched = OpCircuit::<Dot>::new(&[10, 20].map(Fp::from));
+    let res = MockProver::run(ROW, &mismatched, vec![vec![Fp::from(50)]]);
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// 权重为 1、2、2（总权重 5）的加权平均，输入个数不是 3 时 weighted_average 返回错误
+struct WeightedAverage;
+impl Op for WeightedAverage {
+    fn apply(
+        chip: &SimpleChip<Fp>,
+        mut layouter: impl Layouter<Fp>,
+        xs: Vec<ACell<Fp>>,
+    ) -> Result<Vec<ACell<Fp>>, Error> {
+        let weights = [1, 2, 2].map(Fp::from);
+        let total_weight_inv = Fp::from(5).invert().unwrap();
+        let avg = chip.weighted_average(
+            layouter.namespace(|| "weighted average"),
+            &xs,
+            &weights,
+            total_weight_inv,
+        )?;
+        Ok(vec![avg])
+    }
+}
+
+/// (10 * 1 + 20 * 2 + 30 * 2) / 5 = 22
+#[test]
+fn weighted_average() {
+    let weighted = OpCircuit::<WeightedAverage>::new(&[10, 20, 30].map(Fp::from));
+    let prover = MockProver::run(ROW, &weighted, vec![vec![Fp::from(22)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    // 不加权的平均值 20 不对
+    let prover = MockProver::run(ROW, &weighted, vec![vec![Fp::from(20)]]).unwrap();
+    assert!(prover.verify().is_err());
+    // 两个值、三个权重：合成电路时返回 Synthesis 错误
+    let mismatched = OpCircuit::<WeightedAverage>::new(&[10, 20].map(Fp::from));
+    let res = MockProver::run(ROW, &mismatched, vec![vec![Fp::from(22)]]);
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// 流水线中的操作：平方
+struct SquareOp;
+impl DynOp<Fp> for SquareOp {
+    fn apply(
+        &self,
+        chip: &dyn DynNumInstructions<Fp>,
+        region: &mut Region<'_, Fp>,
+        offset: &mut usize,
+        x: ACell<Fp>,
+    ) -> Result<ACell<Fp>, Error> {
+        chip.mul_at(region, offset, &x, &x)
+    }
+}
+
+/// 流水线中的操作：加上常数
+struct AddConstOp(Fp);
+impl DynOp<Fp> for AddConstOp {
+    fn apply(
+        &self,
+        chip: &dyn DynNumInstructions<Fp>,
+        region: &mut Region<'_, Fp>,
+        offset: &mut usize,
+        x: ACell<Fp>,
+    ) -> Result<ACell<Fp>, Error> {
+        let k = chip.load_constant_at(region, offset, self.0)?;
+        chip.add_at(region, offset, &x, &k)
+    }
+}
+
+/// 流水线中的操作：乘以常数
+struct MulConstOp(Fp);
+impl DynOp<Fp> for MulConstOp {
+    fn apply(
+        &self,
+        chip: &dyn DynNumInstructions<Fp>,
+        region: &mut Region<'_, Fp>,
+        offset: &mut usize,
+        x: ACell<Fp>,
+    ) -> Result<ACell<Fp>, Error> {
+        let k = chip.load_constant_at(region, offset, self.0)?;
+        chip.mul_at(region, offset, &x, &k)
+    }
+}
+
+/// 用 trait object 组成的流水线：x -> x^2 -> x^2 + 3 -> (x^2 + 3) * 2，结果导出到 instance 列的第0行
+#[derive(Default)]
+struct PipelineCircuit {
+    x: Value<Fp>,
+}
+
+impl Circuit<Fp> for PipelineCircuit {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let chip: BoxedChip<Fp> = Box::new(SimpleChip::<Fp>::construct(config.clone()));
+        let ops: Vec<Box<dyn DynOp<Fp>>> = vec![
+            Box::new(SquareOp),
+            Box::new(AddConstOp(Fp::from(3))),
+            Box::new(MulConstOp(Fp::from(2))),
+        ];
+        let out = run_pipeline(
+            chip.as_ref(),
+            layouter.namespace(|| "pipeline"),
+            self.x,
+            &ops,
+        )?;
+        SimpleChip::<Fp>::construct(config).expose_public(layouter.namespace(|| "expose"), out, 0)
+    }
+}
+
+/// trait object 流水线：(4^2 + 3) * 2 = 38
+#[test]
+fn dyn_pipeline() {
+    let pipeline = PipelineCircuit {
+        x: Value::known(Fp::from(4)),
+    };
+    let prover = MockProver::run(ROW, &pipeline, vec![vec![Fp::from(38)]]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    let prover = MockProver::run(ROW, &pipeline, vec![vec![Fp::from(39)]]).unwrap();
+    assert!(prover.verify().is_err());
+}
+
+/// 常量链 x * (3 * 5)^2 + (2 + 7)：常量部分 225、9 与 witness 无关，可以在电路外算完
+fn const_chain<C: NumInstructions<Fp>>(
+    chip: &C,
+    mut layouter: impl Layouter<Fp>,
+    x: Value<Fp>,
+) -> Result<(), Error> {
+    let x = chip.load_private(layouter.namespace(|| "x"), x)?;
+    let three = chip.load_constant(layouter.namespace(|| "3"), Fp::from(3))?;
+    let fifteen = chip.mul_by_constant(layouter.namespace(|| "3 * 5"), three, Fp::from(5))?;
+    let square = chip.pow(layouter.namespace(|| "15^2"), fifteen, 2)?;
+    let two = chip.load_constant(layouter.namespace(|| "2"), Fp::from(2))?;
+    let seven = chip.load_constant(layouter.namespace(|| "7"), Fp::from(7))?;
+    let nine = chip.add(layouter.namespace(|| "2 + 7"), two, seven)?;
+    let scaled = chip.mul(layouter.namespace(|| "x * 225"), x, square)?;
+    let out = chip.add(layouter.namespace(|| "x * 225 + 9"), scaled, nine)?;
+    chip.expose_public(layouter.namespace(|| "out"), out, 0)
+}
+
+/// FOLD 为 true 时用 ConstFoldChip，否则用 SimpleChip，两者的配置相同
+struct ConstChainCircuit<const FOLD: bool> {
+    x: Value<Fp>,
+}
+
+impl<const FOLD: bool> Circuit<Fp> for ConstChainCircuit<FOLD> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            x: Value::unknown(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<Fp>) -> Result<(), Error> {
+        if FOLD {
+            const_chain(&ConstFoldChip::construct(config), layouter, self.x)
+        } else {
+            const_chain(&SimpleChip::construct(config), layouter, self.x)
+        }
+    }
+}
+
+/// 常量折叠：x = 2 时输出 2 * 225 + 9 = 459，折叠后公开的结果相同，但用到的行更少
+#[test]
+fn const_fold_uses_fewer_rows() {
+    let folded = ConstChainCircuit::<true> {
+        x: Value::known(Fp::from(2)),
+    };
+    let unfolded = ConstChainCircuit::<false> {
+        x: Value::known(Fp::from(2)),
+    };
+    assert!(layout::measure(&folded).rows < layout::measure(&unfolded).rows);
+
+    let k = layout::measure(&unfolded).k;
+    for (correct, wrong) in [
+        (
+            MockProver::run(k, &folded, vec![vec![Fp::from(459)]]),
+            MockProver::run(k, &folded, vec![vec![Fp::from(460)]]),
+        ),
+        (
+            MockProver::run(k, &unfolded, vec![vec![Fp::from(459)]]),
+            MockProver::run(k, &unfolded, vec![vec![Fp::from(460)]]),
+        ),
+    ] {
+        assert_eq!(correct.unwrap().verify(), Ok(()));
+        assert!(wrong.unwrap().verify().is_err());
+    }
+}
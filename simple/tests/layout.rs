@@ -0,0 +1,31 @@
+//! layout 模块的测试：region 的行数和导出的赋值表
+
+use halo2_proofs::pasta::Fp;
+use simple::{layout, SimpleCircuit};
+
+const ROW: u32 = 5;
+
+/// 每个 region 占用的行数：乘法的 region 都占用 2 行（lhs、rhs 一行，out 一行）
+#[test]
+fn mul_regions_use_two_rows() {
+    let (circuit, _) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let report = layout::region_row_report(ROW, &circuit);
+    let mul_regions: Vec<_> = report.iter().filter(|(name, _)| name == "mul").collect();
+    assert!(!mul_regions.is_empty());
+    assert!(mul_regions.iter().all(|(_, rows)| *rows == 2));
+}
+
+/// 赋值表：2 个 advice 列和 1 个 fixed 列，最后一次乘法 2 * 36 = 72 的结果在乘数的下一行
+#[test]
+fn dump_assignments() {
+    let (circuit, _) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let table = layout::dump_assignments(ROW, &circuit);
+    assert_eq!(table.len(), 3);
+    assert!(table.iter().all(|column| column.len() == 1 << ROW));
+    let product_row = (0..(1 << ROW) - 1)
+        .find(|&r| table[0][r] == Some(Fp::from(2)) && table[1][r] == Some(Fp::from(36)));
+    let product_row = product_row.expect("2 * 36 should be assigned in a mul region");
+    assert_eq!(table[0][product_row + 1], Some(Fp::from(72)));
+    // 常数 2 在 fixed 列中
+    assert!(table[2].contains(&Some(Fp::from(2))));
+}
@@ -0,0 +1,274 @@
+//! prover 和 backend 模块的测试：MockProver 的包装、真实的证明、批量验证和自动增大 k
+
+mod common;
+
+use std::time::Duration;
+
+use common::{example_circuit, ROW};
+use group::ff::PrimeField;
+use halo2_proofs::pasta::Fp;
+use simple::{
+    backend::{Backend, IpaBackend, MockBackend},
+    layout,
+    prover::{self, ProofError},
+    transcript::TranscriptKind,
+    SimpleCircuit,
+};
+
+/// 两个后端的结果一致；约束不满足时 MockBackend 给出具体的失败，IpaBackend 只知道验证失败
+#[test]
+fn backends_agree() {
+    let (circuit, c) = example_circuit();
+    assert!(MockBackend.check(ROW, &circuit, &[vec![c]]).is_ok());
+    assert!(IpaBackend::default()
+        .check(ROW, &circuit, &[vec![c]])
+        .is_ok());
+
+    let wrong_public = [vec![c + Fp::one()]];
+    let mock_err = MockBackend.check(ROW, &circuit, &wrong_public);
+    assert!(matches!(mock_err, Err(ProofError::Unsatisfied(ref failures)) if !failures.is_empty()));
+    let ipa_err = IpaBackend::default().check(ROW, &circuit, &wrong_public);
+    assert!(matches!(ipa_err, Err(ProofError::Plonk(_))));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let (circuit, c) = example_circuit();
+    assert!(prover::roundtrip_in_memory(ROW, &circuit, &[vec![c]]).is_ok());
+    // k 太小时与 prove_simple 一样返回 TooFewRows
+    assert!(matches!(
+        prover::roundtrip_in_memory(2, &circuit, &[vec![c]]),
+        Err(ProofError::TooFewRows { k: 2, .. })
+    ));
+    // public input 错误时验证失败
+    assert!(matches!(
+        prover::roundtrip_in_memory(ROW, &circuit, &[vec![c + Fp::one()]]),
+        Err(ProofError::Plonk(_))
+    ));
+}
+
+/// 通过 prover::run 运行 MockProver，public input 的形状不对时直接返回 BadPublicInput，并指出不一致的列；
+/// 总数相同但多出一个空的 instance 列也不行
+#[test]
+fn run_checks_public_input_count() {
+    let (circuit, c) = example_circuit();
+    assert!(matches!(
+        prover::run(ROW, &circuit, &[vec![]]),
+        Err(ProofError::BadPublicInput {
+            column: Some(0),
+            expected: 1,
+            got: 0
+        })
+    ));
+    assert!(matches!(
+        prover::run(ROW, &circuit, &[vec![c, c]]),
+        Err(ProofError::BadPublicInput {
+            column: Some(0),
+            expected: 1,
+            got: 2
+        })
+    ));
+    assert!(matches!(
+        prover::run(ROW, &circuit, &[vec![c], vec![]]),
+        Err(ProofError::BadPublicInput {
+            column: None,
+            expected: 1,
+            got: 2
+        })
+    ));
+}
+
+/// 生成真实的证明并验证；证明者和验证者的 transcript 必须一致
+#[test]
+fn prove_and_verify_with_transcripts() {
+    let (circuit, c) = example_circuit();
+    let public = [vec![c]];
+    let proof = prover::prove_simple(ROW, &circuit, &public, TranscriptKind::Blake2b).unwrap();
+    assert!(prover::verify_simple(ROW, &circuit, &proof, &public, TranscriptKind::Blake2b).is_ok());
+
+    let keccak_proof =
+        prover::prove_simple(ROW, &circuit, &public, TranscriptKind::Keccak).unwrap();
+    assert!(prover::verify_simple(
+        ROW,
+        &circuit,
+        &keccak_proof,
+        &public,
+        TranscriptKind::Keccak
+    )
+    .is_ok());
+    // Blake2b 的证明不能用 Keccak 验证，反之亦然
+    assert!(prover::verify_simple(ROW, &circuit, &proof, &public, TranscriptKind::Keccak).is_err());
+    assert!(prover::verify_simple(
+        ROW,
+        &circuit,
+        &keccak_proof,
+        &public,
+        TranscriptKind::Blake2b
+    )
+    .is_err());
+}
+
+/// 相同的种子生成逐字节相同的证明，不同的种子生成不同的证明，都能通过验证
+#[test]
+fn seeded_proofs_are_reproducible() {
+    let (circuit, c) = example_circuit();
+    let public = [vec![c]];
+    let seeded = |seed: u8| {
+        prover::prove_seeded(ROW, &circuit, &public, TranscriptKind::Blake2b, [seed; 32]).unwrap()
+    };
+    let (seeded_1, seeded_2, seeded_3) = (seeded(1), seeded(1), seeded(2));
+    assert_eq!(seeded_1, seeded_2);
+    assert_ne!(seeded_1, seeded_3);
+    for proof in [&seeded_1, &seeded_3] {
+        assert!(
+            prover::verify_simple(ROW, &circuit, proof, &public, TranscriptKind::Blake2b).is_ok()
+        );
+    }
+}
+
+/// 一个 Prover 只 keygen 一次，为不同 witness 的电路生成证明
+#[test]
+fn reused_keys() {
+    let (circuit_1, public_1) = SimpleCircuit::<Fp>::builder().a(2).b(3).constant(2).build();
+    let (circuit_2, public_2) = SimpleCircuit::<Fp>::builder().a(4).b(5).constant(2).build();
+    let (public_1, public_2) = (vec![public_1], vec![public_2]);
+    let batch_prover = prover::Prover::from_circuit(ROW, &circuit_1).unwrap();
+    let proof_1 = batch_prover.prove(&circuit_1, &public_1).unwrap();
+    let proof_2 = batch_prover.prove(&circuit_2, &public_2).unwrap();
+    assert!(batch_prover.verify(&proof_1, &public_1).is_ok());
+    assert!(batch_prover.verify(&proof_2, &public_2).is_ok());
+    assert!(batch_prover.verify(&proof_1, &public_2).is_err());
+}
+
+/// 批量验证三个有效的证明；把第 2 个证明的 public input 改错后，报告第一个失败的序号 2
+#[test]
+fn batch_verification() {
+    let build = |a, b| {
+        let (circuit, public) = SimpleCircuit::<Fp>::builder().a(a).b(b).constant(2).build();
+        (circuit, vec![public])
+    };
+    let (circuit_1, public_1) = build(2, 3);
+    let (circuit_2, public_2) = build(4, 5);
+    let (circuit_3, public_3) = build(1, 7);
+    let batch_prover = prover::Prover::from_circuit(ROW, &circuit_1).unwrap();
+    let mut batch = vec![
+        (batch_prover.prove(&circuit_1, &public_1).unwrap(), public_1),
+        (batch_prover.prove(&circuit_2, &public_2).unwrap(), public_2),
+        (batch_prover.prove(&circuit_3, &public_3).unwrap(), public_3),
+    ];
+    assert!(prover::verify_batch(batch_prover.params(), batch_prover.vk(), &batch).is_ok());
+    batch[2].1[0][0] += Fp::one();
+    assert!(matches!(
+        prover::verify_batch(batch_prover.params(), batch_prover.vk(), &batch),
+        Err(ProofError::BatchFailed { index: 2, .. })
+    ));
+}
+
+/// 十六进制的证明和 public input：有效时验证成功，格式错误时返回 Parse 而不是验证失败
+#[test]
+fn verify_from_hex() {
+    let (circuit, c) = example_circuit();
+    let batch_prover = prover::Prover::from_circuit(ROW, &circuit).unwrap();
+    let proof = batch_prover.prove(&circuit, &[vec![c]]).unwrap();
+    let proof_hex = hex::encode(&proof);
+    let public_hex = format!("0x{}", hex::encode(c.to_repr()));
+    assert!(batch_prover
+        .verify_from_hex(&proof_hex, &[&public_hex])
+        .is_ok());
+
+    let wrong_public = hex::encode(Fp::from(73).to_repr());
+    assert!(matches!(
+        batch_prover.verify_from_hex(&proof_hex, &[&wrong_public]),
+        Err(ProofError::Plonk(_))
+    ));
+    assert!(matches!(
+        batch_prover.verify_from_hex("zz", &[&public_hex]),
+        Err(ProofError::Parse(_))
+    ));
+    assert!(matches!(
+        batch_prover.verify_from_hex(&proof_hex, &["0102"]),
+        Err(ProofError::Parse(_))
+    ));
+    // 大于模数的 32 字节不是规范的域元素
+    let too_large = "ff".repeat(32);
+    assert!(matches!(
+        batch_prover.verify_from_hex(&proof_hex, &[&too_large]),
+        Err(ProofError::Parse(_))
+    ));
+}
+
+/// k 太小时返回 TooFewRows，并给出可用的 k
+#[test]
+fn too_few_rows_suggests_k() {
+    let (circuit, c) = example_circuit();
+    let public = vec![vec![c]];
+    let res = prover::prove_simple(2, &circuit, &public, TranscriptKind::default());
+    let Err(ProofError::TooFewRows {
+        k: 2,
+        needed_hint: Some(needed),
+    }) = res
+    else {
+        panic!("expected TooFewRows, got {:?}", res);
+    };
+    assert!(prover::run(needed, &circuit, &public)
+        .unwrap()
+        .verify()
+        .is_ok());
+}
+
+/// 从 k = 4 开始自动增大 k：REPS = 16 的电路在 2^4 行中放不下，直接换成估计的 k，与静态测量的一致
+#[test]
+fn prove_autogrow() {
+    let (circuit, public) = SimpleCircuit::<Fp, 16>::builder()
+        .a(2)
+        .b(3)
+        .constant(2)
+        .build();
+    let public = vec![public];
+    let k = layout::measure(&circuit).k;
+    assert!(k > 4);
+    let (grown_k, res) = prover::prove_autogrow(&circuit, &public, 4);
+    assert!(res.is_ok());
+    assert_eq!(grown_k, k);
+
+    // public input 错误时增大 k 没有用，在起始的 k 上直接返回错误
+    let (wrong_k, res) = prover::prove_autogrow(&circuit, &[vec![]], 4);
+    assert_eq!(wrong_k, 4);
+    assert!(matches!(res, Err(ProofError::BadPublicInput { .. })));
+}
+
+/// 证明大小：k 每加 1，IPA 多一轮，多两个 32 字节的群元素；同一个电路形状，换 witness 不改变证明大小
+#[test]
+fn proof_size_grows_with_k() {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).build();
+    let public = vec![public];
+    let sizes: Vec<usize> = (5..=8)
+        .map(|k| prover::proof_size(k, &circuit, &public).unwrap())
+        .collect();
+    assert!(sizes.windows(2).all(|w| w[1] - w[0] == 64));
+
+    let (other, other_public) = SimpleCircuit::<Fp>::builder().a(5).b(7).build();
+    assert_eq!(
+        prover::proof_size(5, &other, &[other_public]).unwrap(),
+        sizes[0]
+    );
+}
+
+/// 证明和验证的耗时都大于 0；错误的 public input 也能生成证明，但验证失败
+#[test]
+fn prove_and_verify_timed() {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).build();
+    let (prove_time, verify_time, verified) =
+        prover::prove_and_verify_timed(ROW, &circuit, &[public]);
+    assert!(verified);
+    assert!(prove_time > Duration::ZERO && verify_time > Duration::ZERO);
+    let (_, _, verified) = prover::prove_and_verify_timed(ROW, &circuit, &[vec![Fp::from(1)]]);
+    assert!(!verified);
+}
+
+/// 验证密钥与 witness 无关
+#[test]
+fn vk_is_deterministic() {
+    assert!(prover::vk_is_deterministic());
+}
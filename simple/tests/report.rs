@@ -0,0 +1,84 @@
+//! report 和 field 模块的测试：电路的元数据、JSON 报告和域元素的格式化
+
+mod common;
+
+use common::{IsSquare, OpCircuit, ROW};
+use halo2_proofs::pasta::Fp;
+use simple::{
+    field::{fp_to_decimal, fp_to_hex_be, signed_to_fp},
+    report, SimpleCircuit,
+};
+
+/// SimpleCircuit 的元数据：列数、选择器、门的名字和 lookup 个数
+#[test]
+fn circuit_metadata() {
+    let metadata = report::circuit_metadata::<SimpleCircuit<Fp>>(ROW).unwrap();
+    assert_eq!(
+        metadata,
+        report::CircuitMetadata {
+            k: ROW,
+            advice_columns: 2,
+            fixed_columns: 1,
+            instance_columns: 1,
+            selectors: 7,
+            gate_names: ["mul", "add", "add_n", "select", "and", "or", "bits"]
+                .map(String::from)
+                .to_vec(),
+            lookups: 0,
+        }
+    );
+    assert_eq!(metadata.to_json()["columns"]["advice"], 2);
+}
+
+/// JSON 报告：满足的电路 verified 为 true，不满足时 failures 中有格式化的失败
+#[test]
+fn json_report_keys() {
+    let ok_report = report::json_report(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(9)]),
+        vec![vec![]],
+        None,
+    );
+    for key in ["k", "verified", "failures", "cost", "proof_size"] {
+        assert!(ok_report.get(key).is_some(), "missing key {}", key);
+    }
+    assert_eq!(ok_report["verified"], true);
+    assert!(ok_report["cost"]["rows"].as_u64().unwrap() > 0);
+    assert!(ok_report["proof_size"].is_null());
+
+    let bad_report = report::json_report(
+        ROW,
+        &OpCircuit::<IsSquare>::new(&[Fp::from(5)]),
+        vec![vec![]],
+        None,
+    );
+    assert_eq!(bad_report["verified"], false);
+    assert_eq!(bad_report["failures"].as_array().unwrap().len(), 1);
+}
+
+/// 测试进程的命令行参数中没有 --json，maybe_emit_json 不输出报告
+#[test]
+fn maybe_emit_json_without_flag() {
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().build();
+    assert!(!report::json_requested());
+    assert!(!report::maybe_emit_json(ROW, &circuit, vec![public]));
+}
+
+/// 域元素的十进制和大端序十六进制表示：-1 为 p - 1，p = 0x4000...0000224698fc094cf91b992d30ed00000001
+#[test]
+fn field_formatting() {
+    for (x, decimal, hex) in [
+        (Fp::zero(), "0", "0x0"),
+        (Fp::one(), "1", "0x1"),
+        (Fp::from(55), "55", "0x37"),
+        (
+            -Fp::one(),
+            "28948022309329048855892746252171976963363056481941560715954676764349967630336",
+            "0x40000000000000000000000000000000224698fc094cf91b992d30ed00000000",
+        ),
+    ] {
+        assert_eq!(fp_to_decimal(x), decimal);
+        assert_eq!(fp_to_hex_be(x), hex);
+    }
+    assert_eq!(fp_to_decimal(signed_to_fp(-55) + Fp::from(55)), "0");
+}
@@ -0,0 +1,37 @@
+//! trace feature（cargo test -p simple --features trace）：统计 simple::synthesis 目标下的日志条数
+//! 日志记录器是全局的，所以这个文件中只有一个测试
+#![cfg(feature = "trace")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::SimpleCircuit;
+
+static RECORDS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingLogger;
+
+impl log::Log for CountingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "simple::synthesis"
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            RECORDS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// SimpleCircuit 的一次合成记录 7 条日志：
+/// load_private * 2、load_constant、mul * 3（a * b、平方、乘以常数）、expose_public
+#[test]
+fn synthesis_records_one_entry_per_instruction() {
+    log::set_logger(&CountingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+    let (circuit, public) = SimpleCircuit::<Fp>::builder().a(2).b(3).build();
+    MockProver::run(5, &circuit, vec![public]).unwrap();
+    assert_eq!(RECORDS.load(Ordering::SeqCst), 7);
+}
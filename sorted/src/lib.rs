@@ -0,0 +1,333 @@
+use group::ff::PrimeField;
+use halo2_poseidon::{P128Pow5T3, Spec};
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector,
+        TableColumn,
+    },
+    poly::Rotation,
+};
+use poseidon::{PoseidonChip, PoseidonConfig, RATE, WIDTH};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// 有序数组示例：证明一个 private 的数组是升序的（允许相邻元素相等），同时公开数组的哈希
+/// 1）对每一对相邻元素计算 lt_i = less_than(x_{i+1}, x_i)，lt_i = 1 表示出现了逆序
+/// 2）把所有的 (1 - lt_i) 相与（累乘），结果必须为 1
+/// 3）数组的哈希 hash(...hash(hash(x_0, x_1), x_2)..., x_{n-1}) 作为 public input
+///
+/// 比较器只对 8 bit 的数有效，数组中的元素都会通过 lookup 检查是否在 0..256 中
+pub const BITS: usize = 8;
+
+/// 电路外计算数组的哈希，与电路中的计算顺序一致
+pub fn hash_array<F>(xs: &[F]) -> F
+where
+    F: PrimeField,
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    xs[1..]
+        .iter()
+        .fold(xs[0], |acc, x| poseidon::hash_to_field([acc, *x]))
+}
+
+/// less_than 比较器：a, b 为 8 bit 的数，lt = 1 当且仅当 a < b
+/// t = a - b + 2^8 的取值范围为 [1, 2^9)，把 t 拆成 t = hi * 2^8 + lo（lo 为 8 bit，hi 为 1 bit），
+/// a >= b 时 hi = 1，a < b 时 hi = 0，所以 lt = 1 - hi
+///
+/// | a | b | lo | lt | s_lt |
+/// |---|---|----|----|------|
+///
+/// "and_not" 门用于累积比较结果：acc' = acc * (1 - lt)
+///
+/// | a   | b  | s_and_not |
+/// |-----|----|-----------|
+/// | acc | lt | 1         |
+/// | acc'|    |           |
+#[derive(Clone, Debug)]
+pub struct LessThanConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    lo: Column<Advice>,
+    lt: Column<Advice>,
+    s_lt: Selector,
+    s_and_not: Selector,
+    table: TableColumn,
+}
+
+pub struct LessThanChip<F: PrimeField> {
+    config: LessThanConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> LessThanChip<F> {
+    pub fn construct(config: LessThanConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 4],
+    ) -> LessThanConfig {
+        let [a, b, lo, lt] = advice;
+        for c in advice {
+            meta.enable_equality(c);
+        }
+        let s_lt = meta.complex_selector();
+        let s_and_not = meta.selector();
+        let table = meta.lookup_table_column();
+
+        // a、b、lo 都必须是 8 bit 的数
+        for column in [a, b, lo] {
+            meta.lookup(|meta| {
+                let s = meta.query_selector(s_lt);
+                let v = meta.query_advice(column, Rotation::cur());
+                vec![(s * v, table)]
+            });
+        }
+
+        meta.create_gate("less_than", |meta| {
+            let s = meta.query_selector(s_lt);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let lo = meta.query_advice(lo, Rotation::cur());
+            let lt = meta.query_advice(lt, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            let two_pow_bits = Expression::Constant(F::from(1 << BITS));
+            vec![
+                // lt 为布尔值
+                s.clone() * lt.clone() * (one.clone() - lt.clone()),
+                // a - b + 2^8 = (1 - lt) * 2^8 + lo
+                s * (a - b + two_pow_bits.clone() - (one - lt) * two_pow_bits - lo),
+            ]
+        });
+
+        meta.create_gate("and_not", |meta| {
+            let s = meta.query_selector(s_and_not);
+            let acc = meta.query_advice(a, Rotation::cur());
+            let lt = meta.query_advice(b, Rotation::cur());
+            let next = meta.query_advice(a, Rotation::next());
+            vec![s * (acc * (Expression::Constant(F::ONE) - lt) - next)]
+        });
+
+        LessThanConfig {
+            a,
+            b,
+            lo,
+            lt,
+            s_lt,
+            s_and_not,
+            table,
+        }
+    }
+
+    /// 加载范围检查表：0, 1, ..., 2^8 - 1
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = &self.config;
+        layouter.assign_table(
+            || "u8 range table",
+            |mut table| {
+                for i in 0..(1 << BITS) {
+                    table.assign_cell(
+                        || "value",
+                        config.table,
+                        i,
+                        || Value::known(F::from(i as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 返回 lt = [a < b]，同时检查 a、b 都是 8 bit 的数
+    pub fn less_than(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &AssignedCell<F, F>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "less_than",
+            |mut region| {
+                config.s_lt.enable(&mut region, 0)?;
+                a.copy_advice(|| "a", &mut region, config.a, 0)?;
+                b.copy_advice(|| "b", &mut region, config.b, 0)?;
+                // t = a - b + 2^8，只取低 64 位就足够（a、b 都是 8 bit 时 t < 2^9）
+                let t = a.value().zip(b.value()).map(|(a, b)| {
+                    let t = *a - b + F::from(1 << BITS);
+                    let repr = t.to_repr();
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(&repr.as_ref()[..8]);
+                    u64::from_le_bytes(bytes)
+                });
+                let lo = t.map(|t| F::from(t & ((1 << BITS) - 1)));
+                let lt = t.map(|t| if t >> BITS == 0 { F::ONE } else { F::ZERO });
+                region.assign_advice(|| "lo", config.lo, 0, || lo)?;
+                region.assign_advice(|| "lt", config.lt, 0, || lt)
+            },
+        )
+    }
+
+    /// 计算 acc * (1 - lt)，用于把若干个比较结果的“非”相与
+    pub fn and_not(
+        &self,
+        mut layouter: impl Layouter<F>,
+        acc: &AssignedCell<F, F>,
+        lt: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "and_not",
+            |mut region| {
+                config.s_and_not.enable(&mut region, 0)?;
+                acc.copy_advice(|| "acc", &mut region, config.a, 0)?;
+                lt.copy_advice(|| "lt", &mut region, config.b, 0)?;
+                let next = acc
+                    .value()
+                    .zip(lt.value())
+                    .map(|(acc, lt)| *acc * (F::ONE - lt));
+                region.assign_advice(|| "acc * (1 - lt)", config.a, 1, || next)
+            },
+        )
+    }
+
+    /// 从常量列加载 value，并约束 cell 等于它
+    pub fn constrain_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cell: Option<&AssignedCell<F, F>>,
+        value: F,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "constant",
+            |mut region| {
+                let constant =
+                    region.assign_advice_from_constant(|| "constant", self.config.a, 0, value)?;
+                if let Some(cell) = cell {
+                    region.constrain_equal(cell.cell(), constant.cell())?;
+                }
+                Ok(constant)
+            },
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SortedConfig<F: PrimeField> {
+    poseidon: PoseidonConfig<F>,
+    less_than: LessThanConfig,
+    instance: Column<Instance>,
+}
+
+/// 电路中保存 private 的数组，数组长度至少为 2
+#[derive(Default)]
+pub struct SortedCircuit<F: PrimeField> {
+    pub values: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> Circuit<F> for SortedCircuit<F>
+where
+    P128Pow5T3: Spec<F, WIDTH, RATE>,
+{
+    type Config = SortedConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 数组长度决定了电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            values: vec![Value::unknown(); self.values.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let state = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+        let rc = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column(),
+        ];
+        let constant = meta.fixed_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        let advice = [
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+            meta.advice_column(),
+        ];
+
+        SortedConfig {
+            poseidon: PoseidonChip::configure::<P128Pow5T3>(meta, state, rc, constant),
+            less_than: LessThanChip::configure(meta, advice),
+            instance,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        assert!(self.values.len() >= 2, "array needs at least 2 elements");
+        let poseidon = PoseidonChip::construct(config.poseidon);
+        let less_than = LessThanChip::construct(config.less_than);
+        less_than.load_table(layouter.namespace(|| "load table"))?;
+
+        let xs = self
+            .values
+            .iter()
+            .map(|x| poseidon.load_private(layouter.namespace(|| "load x"), *x))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // 相邻元素两两比较，把 (1 - lt) 累乘到 acc 中，acc 最终必须为 1
+        let mut acc =
+            less_than.constrain_constant(layouter.namespace(|| "acc = 1"), None, F::ONE)?;
+        for pair in xs.windows(2) {
+            let lt =
+                less_than.less_than(layouter.namespace(|| "x[i+1] < x[i]"), &pair[1], &pair[0])?;
+            acc = less_than.and_not(layouter.namespace(|| "acc and not lt"), &acc, &lt)?;
+        }
+        less_than.constrain_constant(layouter.namespace(|| "sorted"), Some(&acc), F::ONE)?;
+
+        // 数组的哈希作为 public input
+        let mut digest = xs[0].clone();
+        for x in &xs[1..] {
+            digest = poseidon.hash(layouter.namespace(|| "hash"), [digest, x.clone()])?;
+        }
+        layouter.constrain_instance(digest.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为数组的哈希
+impl<F: PrimeField> DescribeInstance for SortedCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// 4 个随机的 BITS 位整数（与示例 main 相同的长度），排序后作为数组，public input 为数组的哈希
+impl RandomWitness for SortedCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let mut values: Vec<u64> = (0..4).map(|_| rng.next_u64() % (1 << BITS)).collect();
+        values.sort_unstable();
+        let values: Vec<Fp> = values.into_iter().map(Fp::from).collect();
+        let circuit = Self {
+            values: values.iter().map(|x| Value::known(*x)).collect(),
+        };
+        (circuit, vec![vec![hash_array(&values)]])
+    }
+}
@@ -0,0 +1,39 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+use sorted::{hash_array, SortedCircuit};
+
+fn circuit(values: &[Fp]) -> SortedCircuit<Fp> {
+    SortedCircuit {
+        values: values.iter().map(|x| Value::known(*x)).collect(),
+    }
+}
+
+fn main() {
+    // 范围表有 256 行，Poseidon 每次哈希约 65 行
+    let row = 9;
+
+    // 升序数组，包含相等的相邻元素，验证成功
+    let values: Vec<Fp> = [1u64, 3, 3, 200].iter().map(|x| Fp::from(*x)).collect();
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit(&values), vec![vec![hash_array(&values)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: sorted array...");
+
+    let prover = MockProver::run(row, &circuit(&values), vec![vec![hash_array(&values)]]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 所有元素都相等，也是升序的
+    let equal: Vec<Fp> = [7u64, 7, 7].iter().map(|x| Fp::from(*x)).collect();
+    let prover_2 = MockProver::run(row, &circuit(&equal), vec![vec![hash_array(&equal)]]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // 5 > 3，不是升序，验证失败
+    let unsorted: Vec<Fp> = [1u64, 5, 3, 7].iter().map(|x| Fp::from(*x)).collect();
+    let prover_3 =
+        MockProver::run(row, &circuit(&unsorted), vec![vec![hash_array(&unsorted)]]).unwrap();
+    let res_3 = prover_3.verify();
+    println!("res3: {:?}", res_3);
+}
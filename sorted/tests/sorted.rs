@@ -0,0 +1,64 @@
+//! sorted 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+use sorted::{hash_array, SortedCircuit};
+
+/// 范围表有 256 行，Poseidon 每次哈希约 65 行
+const K: u32 = 9;
+
+fn circuit(values: &[Fp]) -> SortedCircuit<Fp> {
+    SortedCircuit {
+        values: values.iter().map(|x| Value::known(*x)).collect(),
+    }
+}
+
+fn verify(circuit: &SortedCircuit<Fp>, digest: Fp) -> bool {
+    MockProver::run(K, circuit, vec![vec![digest]])
+        .unwrap()
+        .verify()
+        .is_ok()
+}
+
+/// 升序数组，包含相等的相邻元素；所有元素都相等，也是升序的
+#[test]
+fn sorted_arrays() {
+    for values in [vec![1, 3, 3, 200], vec![7, 7, 7]] {
+        let values: Vec<Fp> = values.into_iter().map(Fp::from).collect();
+        assert!(
+            verify(&circuit(&values), hash_array(&values)),
+            "{:?}",
+            values
+        );
+    }
+}
+
+/// 5 > 3，不是升序，验证失败
+#[test]
+fn unsorted_fails() {
+    let unsorted = [1, 5, 3, 7].map(Fp::from);
+    assert!(!verify(&circuit(&unsorted), hash_array(&unsorted)));
+}
+
+/// 数组正确但公开的哈希错误，验证失败
+#[test]
+fn wrong_hash_fails() {
+    let values = [1, 3, 3, 200].map(Fp::from);
+    let equal = [7, 7, 7].map(Fp::from);
+    assert!(!verify(&circuit(&values), hash_array(&equal)));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<SortedCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let values = [1, 3, 3, 200].map(Fp::from);
+    assert!(
+        prover::roundtrip_in_memory(K, &circuit(&values), &[vec![hash_array(&values)]]).is_ok()
+    );
+}
@@ -0,0 +1,146 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector, TableColumn},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+
+///////////////////////////////////////////////////////////////////////
+/// 用预先计算的平方表代替乘法门：证明 y = x^2，x 为 private input，y 为 public input
+/// 两个表列（fixed 列）中存放 (i, i^2)，i = 0..RANGE；同一行的 (x, y) 作为一个二元组在表中查找。
+///
+/// | x | y   | s_lookup |        | x_table | y_table |
+/// |---|-----|----------|        |---------|---------|
+/// | x | x^2 | 1        |        | i       | i^2     |
+///
+/// 与乘法门 s * (x * x - y) 相比：
+/// - 查找同时约束了 0 <= x < RANGE，不需要额外的范围检查；
+/// - 表占用 RANGE 行，只适合小范围的输入，范围越大 k 越大。
+///
+/// 表的大小：x 的取值范围为 [0, RANGE)
+pub const RANGE: u64 = 16;
+
+#[derive(Clone, Debug)]
+pub struct SquareLookupConfig {
+    x: Column<Advice>,
+    y: Column<Advice>,
+    instance: Column<Instance>,
+    s_lookup: Selector,
+    x_table: TableColumn,
+    y_table: TableColumn,
+}
+
+impl SquareLookupConfig {
+    pub fn configure<F: PrimeField>(meta: &mut ConstraintSystem<F>) -> Self {
+        let x = meta.advice_column();
+        let y = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(y);
+        meta.enable_equality(instance);
+        // lookup 中使用的选择器必须是 complex selector
+        let s_lookup = meta.complex_selector();
+        let x_table = meta.lookup_table_column();
+        let y_table = meta.lookup_table_column();
+
+        // 未启用的行输入为 (0, 0)，(0, 0^2) 在表中，总能通过
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_lookup);
+            let x = meta.query_advice(x, Rotation::cur());
+            let y = meta.query_advice(y, Rotation::cur());
+            vec![(s.clone() * x, x_table), (s * y, y_table)]
+        });
+
+        Self {
+            x,
+            y,
+            instance,
+            s_lookup,
+            x_table,
+            y_table,
+        }
+    }
+
+    pub fn load_table<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "squares",
+            |mut table| {
+                for i in 0..RANGE {
+                    let row = i as usize;
+                    table.assign_cell(|| "i", self.x_table, row, || Value::known(F::from(i)))?;
+                    table.assign_cell(
+                        || "i^2",
+                        self.y_table,
+                        row,
+                        || Value::known(F::from(i * i)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// x 和证明者声称的 y；new 计算正确的 y，也可以直接构造错误的 y
+#[derive(Clone, Debug, Default)]
+pub struct SquareLookupCircuit<F: PrimeField> {
+    pub x: Value<F>,
+    pub y: Value<F>,
+}
+
+impl<F: PrimeField> SquareLookupCircuit<F> {
+    pub fn new(x: u64) -> Self {
+        Self {
+            x: Value::known(F::from(x)),
+            y: Value::known(F::from(x * x)),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SquareLookupCircuit<F> {
+    type Config = SquareLookupConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        SquareLookupConfig::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.load_table(layouter.namespace(|| "load table"))?;
+        let y = layouter.assign_region(
+            || "square",
+            |mut region| {
+                config.s_lookup.enable(&mut region, 0)?;
+                region.assign_advice(|| "x", config.x, 0, || self.x)?;
+                region.assign_advice(|| "y", config.y, 0, || self.y)
+            },
+        )?;
+        layouter.constrain_instance(y.cell(), config.instance, 0)
+    }
+}
+
+/// public input 为 y
+impl<F: PrimeField> DescribeInstance for SquareLookupCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// x 为 0..RANGE 中的随机值，public input 为 y = x^2
+impl RandomWitness for SquareLookupCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let x = rng.next_u64() % RANGE;
+        (Self::new(x), vec![vec![Fp::from(x * x)]])
+    }
+}
@@ -0,0 +1,46 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+use square_lookup::{SquareLookupCircuit, RANGE};
+
+fn main() {
+    // 定义电路的行数：表有 RANGE 行，再加上盲化行
+    let row = 5;
+
+    let circuit = SquareLookupCircuit::<Fp>::new(7);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![Fp::from(49)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: square lookup...");
+
+    // 元数据：一个 lookup，表列计为 fixed 列，没有门
+    let metadata = report::circuit_metadata::<SquareLookupCircuit<Fp>>(row).unwrap();
+    println!("metadata: {}", metadata.to_json());
+
+    // 表中的每一对 (x, x^2) 都能通过，包括两端
+    for x in [0, 1, 7, RANGE - 1] {
+        let prover = MockProver::run(
+            row,
+            &SquareLookupCircuit::<Fp>::new(x),
+            vec![vec![Fp::from(x * x)]],
+        )
+        .unwrap();
+        let res = prover.verify();
+        println!("res ({}^2 = {}): {:?}", x, x * x, res);
+    }
+
+    // 证明者声称 7^2 = 50：(7, 50) 不在表中，失败的是 lookup 而不是拷贝约束
+    let wrong_y = SquareLookupCircuit {
+        x: Value::known(Fp::from(7)),
+        y: Value::known(Fp::from(50)),
+    };
+    let prover_2 = MockProver::run(row, &wrong_y, vec![vec![Fp::from(50)]]).unwrap();
+    println!("res2 (7^2 = 50): {:?}", prover_2.verify());
+
+    // x = RANGE 超出表的范围：即使 y 是正确的平方也找不到
+    let out_of_range = SquareLookupCircuit::<Fp>::new(RANGE);
+    let prover_3 =
+        MockProver::run(row, &out_of_range, vec![vec![Fp::from(RANGE * RANGE)]]).unwrap();
+    println!("res3 ({}^2): {:?}", RANGE, prover_3.verify());
+}
@@ -0,0 +1,65 @@
+//! square_lookup 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, layout, prover, report};
+use square_lookup::{SquareLookupCircuit, RANGE};
+
+/// 表有 RANGE 行，再加上盲化行
+const K: u32 = 5;
+
+/// 元数据：一个 lookup，表列计为 fixed 列，没有门；电路正好需要 2^K 行
+#[test]
+fn metadata() {
+    assert_eq!(layout::measure(&SquareLookupCircuit::<Fp>::new(7)).k, K);
+    let metadata = report::circuit_metadata::<SquareLookupCircuit<Fp>>(K).unwrap();
+    assert_eq!(metadata.lookups, 1);
+    assert_eq!(metadata.fixed_columns, 2);
+    assert!(metadata.gate_names.is_empty());
+}
+
+/// 表中的每一对 (x, x^2) 都能通过，包括两端
+#[test]
+fn squares_in_table() {
+    for x in [0, 1, 7, RANGE - 1] {
+        let res = MockProver::run(
+            K,
+            &SquareLookupCircuit::<Fp>::new(x),
+            vec![vec![Fp::from(x * x)]],
+        )
+        .unwrap()
+        .verify();
+        assert_eq!(res, Ok(()), "x = {}", x);
+    }
+}
+
+/// 证明者声称 7^2 = 50：(7, 50) 不在表中，失败的是 lookup 而不是拷贝约束
+#[test]
+fn wrong_square_fails_lookup() {
+    let wrong_y = SquareLookupCircuit {
+        x: Value::known(Fp::from(7)),
+        y: Value::known(Fp::from(50)),
+    };
+    let prover = MockProver::run(K, &wrong_y, vec![vec![Fp::from(50)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "lookup 0");
+}
+
+/// x = RANGE 超出表的范围：即使 y 是正确的平方也找不到
+#[test]
+fn out_of_range_fails_lookup() {
+    let out_of_range = SquareLookupCircuit::<Fp>::new(RANGE);
+    let prover = MockProver::run(K, &out_of_range, vec![vec![Fp::from(RANGE * RANGE)]]).unwrap();
+    dev::assert_constraint_failure(&prover, "lookup 0");
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<SquareLookupCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = SquareLookupCircuit::<Fp>::new(7);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[vec![Fp::from(49)]]).is_ok());
+}
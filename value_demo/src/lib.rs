@@ -0,0 +1,186 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Chip, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// Value<F> 的用法示例：witness 在生成密钥时是未知的（Value::unknown()），在生成证明时才是已知的，
+/// 所以电路中不能直接取出 witness 的值做计算，只能通过 Value 的组合子描述"已知时如何计算"：
+/// - map：对一个值做变换，c = a.map(|a| a.square())；
+/// - zip：把两个值合成一个二元组，两个都已知时才已知，c = a.zip(b).map(|(a, b)| a * b)；
+/// - and_then：变换本身可能失败（例如求逆），失败时返回 Value::unknown()。
+///
+/// 其它芯片中常见的 a.value().copied() * b.value() 使用的是 Value 上重载的运算符，只适用于 +、-、*；
+/// 求逆、比较、位运算等没有对应的运算符，只能用 map / zip / and_then 写成闭包。
+/// 任何一个输入未知时，结果都是未知的，闭包不会被调用，所以同一段代码在生成密钥和生成证明时都能运行。
+///
+/// 每一行证明 c = a * b，并用 a * a_inv = 1 证明 a 不为 0，c 为 public input
+///
+/// | a | b | c     | a_inv | s |
+/// |---|---|-------|-------|---|
+/// | a | b | a * b | 1 / a | 1 |
+///
+/// 两个都已知时返回 a * b，否则返回未知
+pub fn product<F: Field>(a: Value<F>, b: Value<F>) -> Value<F> {
+    a.zip(b).map(|(a, b)| a * b)
+}
+
+/// a 已知且不为 0 时返回 1 / a；a 未知或为 0（没有逆元）时返回未知
+pub fn inverse<F: Field>(a: Value<F>) -> Value<F> {
+    a.and_then(|a| Option::<F>::from(a.invert()).map_or(Value::unknown(), Value::known))
+}
+
+/// Value 不提供取值的方法，只能在 map 的闭包中观察它是否已知
+pub fn is_known<F>(value: &Value<F>) -> bool {
+    let mut known = false;
+    value.as_ref().map(|_| known = true);
+    known
+}
+
+#[derive(Clone, Debug)]
+pub struct ValueDemoConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    a_inv: Column<Advice>,
+    instance: Column<Instance>,
+    s: Selector,
+}
+
+pub struct ValueDemoChip<F: PrimeField> {
+    config: ValueDemoConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> Chip<F> for ValueDemoChip<F> {
+    type Config = ValueDemoConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> ValueDemoChip<F> {
+    pub fn construct(config: ValueDemoConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> ValueDemoConfig {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let a_inv = meta.advice_column();
+        let instance = meta.instance_column();
+        meta.enable_equality(c);
+        meta.enable_equality(instance);
+        let s = meta.selector();
+
+        meta.create_gate("mul_and_inverse", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let a_inv = meta.query_advice(a_inv, Rotation::cur());
+            vec![
+                s.clone() * (a.clone() * b - c),
+                s * (a * a_inv - Expression::Constant(F::ONE)),
+            ]
+        });
+
+        ValueDemoConfig {
+            a,
+            b,
+            c,
+            a_inv,
+            instance,
+            s,
+        }
+    }
+
+    /// 赋值 a、b 后，从已赋值单元格的值计算 c 和 a_inv，并公开 c
+    pub fn assign(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Value<F>,
+        b: Value<F>,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        let c = layouter.assign_region(
+            || "mul and inverse",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let a = region.assign_advice(|| "a", config.a, 0, || a)?;
+                let b = region.assign_advice(|| "b", config.b, 0, || b)?;
+                // cell.value() 返回 Value<&F>，copied() 后得到 Value<F>
+                let a = a.value().copied();
+                let b = b.value().copied();
+                region.assign_advice(|| "a_inv", config.a_inv, 0, || inverse(a))?;
+                region.assign_advice(|| "c", config.c, 0, || product(a, b))
+            },
+        )?;
+        layouter.constrain_instance(c.cell(), config.instance, 0)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ValueDemoCircuit<F: PrimeField> {
+    pub a: Value<F>,
+    pub b: Value<F>,
+}
+
+impl<F: PrimeField> Circuit<F> for ValueDemoCircuit<F> {
+    type Config = ValueDemoConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ValueDemoChip::configure(meta)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        ValueDemoChip::construct(config).assign(layouter, self.a, self.b)
+    }
+}
+
+/// public input 为 c = a * b
+impl<F: PrimeField> DescribeInstance for ValueDemoCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![1]
+    }
+}
+
+/// a、b 为随机的域元素，public input 为 a * b；a = 0 没有逆元，所以重新抽取
+impl RandomWitness for ValueDemoCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let a = loop {
+            let a = Fp::random(&mut *rng);
+            if !bool::from(a.is_zero()) {
+                break a;
+            }
+        };
+        let b = Fp::random(&mut *rng);
+        let circuit = Self {
+            a: Value::known(a),
+            b: Value::known(b),
+        };
+        (circuit, vec![vec![a * b]])
+    }
+}
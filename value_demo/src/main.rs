@@ -0,0 +1,50 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{prover, report};
+use value_demo::{inverse, is_known, product, ValueDemoCircuit};
+
+fn main() {
+    // 定义电路的行数
+    let row = 4;
+
+    let circuit = ValueDemoCircuit {
+        a: Value::known(Fp::from(3)),
+        b: Value::known(Fp::from(5)),
+    };
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![vec![Fp::from(15)]]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: Value map / zip / and_then...");
+
+    // 已知的值：闭包被调用；任何一个输入未知时结果未知；0 没有逆元，and_then 返回未知
+    let known = product(Value::known(Fp::from(3)), Value::known(Fp::from(5)));
+    println!("known: 3 * 5 = {:?}", known);
+    let unknown = product(Value::unknown(), Value::known(Fp::from(5)));
+    println!("known: ? * 5 is known = {}", is_known(&unknown));
+    println!(
+        "known: 1 / 0 is known = {}",
+        is_known(&inverse(Value::known(Fp::zero())))
+    );
+
+    // witness 已知：生成证明时的情形
+    let prover = MockProver::run(row, &circuit, vec![vec![Fp::from(15)]]).unwrap();
+    let res = prover.verify();
+    println!("res1 (3 * 5): {:?}", res);
+
+    // 不同的 witness 得到相同的验证密钥
+    let other = ValueDemoCircuit {
+        a: Value::known(Fp::from(7)),
+        b: Value::known(Fp::from(11)),
+    };
+    let (_, _, verified) = prover::prove_and_verify_timed(row, &other, &[vec![Fp::from(77)]]);
+    println!("res2 (7 * 11, real proof): {}", verified);
+
+    // a = 0 时 a_inv 未知：生成证明时给未知的值赋值，MockProver 返回 Synthesis 错误
+    let zero = ValueDemoCircuit {
+        a: Value::known(Fp::zero()),
+        b: Value::known(Fp::from(5)),
+    };
+    let res_3 = MockProver::run(row, &zero, vec![vec![Fp::zero()]]);
+    println!("res3 (a = 0): {:?}", res_3.as_ref().err());
+}
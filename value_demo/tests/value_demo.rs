@@ -0,0 +1,80 @@
+//! value_demo 示例电路的测试
+
+use halo2_proofs::{
+    circuit::Value,
+    dev::MockProver,
+    pasta::Fp,
+    plonk::{Circuit, Error},
+};
+use simple::{dev, layout, prover};
+use value_demo::{inverse, is_known, product, ValueDemoCircuit};
+
+const K: u32 = 4;
+
+fn circuit(a: u64, b: u64) -> ValueDemoCircuit<Fp> {
+    ValueDemoCircuit {
+        a: Value::known(Fp::from(a)),
+        b: Value::known(Fp::from(b)),
+    }
+}
+
+/// 已知的值：闭包被调用；任何一个输入未知时结果未知；0 没有逆元，and_then 返回未知
+#[test]
+fn value_helpers() {
+    let known = product(Value::known(Fp::from(3)), Value::known(Fp::from(5)));
+    known.assert_if_known(|c| *c == Fp::from(15));
+    assert!(is_known(&known));
+    inverse(Value::known(Fp::from(3))).assert_if_known(|inv| *inv * Fp::from(3) == Fp::one());
+
+    assert!(!is_known(&product(
+        Value::unknown(),
+        Value::known(Fp::from(5))
+    )));
+    assert!(!is_known(&product(
+        Value::known(Fp::from(3)),
+        Value::unknown()
+    )));
+    assert!(!is_known(&inverse(Value::<Fp>::unknown())));
+    assert!(!is_known(&inverse(Value::known(Fp::zero()))));
+}
+
+/// witness 已知：生成证明时的情形
+#[test]
+fn known_witness() {
+    let res = MockProver::run(K, &circuit(3, 5), vec![vec![Fp::from(15)]])
+        .unwrap()
+        .verify();
+    assert_eq!(res, Ok(()));
+}
+
+/// witness 未知：生成密钥时的情形，同一个 synthesize 也能运行，不同的 witness 得到相同的验证密钥
+#[test]
+fn unknown_witness() {
+    let known = circuit(3, 5);
+    let unknown = known.without_witnesses();
+    assert_eq!(layout::measure(&unknown).k, layout::measure(&known).k);
+    assert_eq!(
+        prover::vk_fingerprint_of(K, &known),
+        prover::vk_fingerprint_of(K, &circuit(7, 11))
+    );
+}
+
+/// a = 0 时 a_inv 未知：生成证明时给未知的值赋值，MockProver 返回 Synthesis 错误
+#[test]
+fn zero_has_no_inverse() {
+    let res = MockProver::run(K, &circuit(0, 5), vec![vec![Fp::zero()]]);
+    assert!(matches!(res, Err(Error::Synthesis)));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<ValueDemoCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明：3 * 5 = 15，7 * 11 = 77
+#[test]
+fn roundtrip_in_memory() {
+    assert!(prover::roundtrip_in_memory(K, &circuit(3, 5), &[vec![Fp::from(15)]]).is_ok());
+    assert!(prover::roundtrip_in_memory(K, &circuit(7, 11), &[vec![Fp::from(77)]]).is_ok());
+}
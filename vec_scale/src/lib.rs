@@ -0,0 +1,96 @@
+use group::ff::{Field, PrimeField};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use rand_core::RngCore;
+use simple::witness::RandomWitness;
+use simple::{prover::DescribeInstance, NumInstructions, SimpleChip, SimpleConfig};
+
+///////////////////////////////////////////////////////////////////////
+/// 同时有 private、public 输入和 public 输出的向量示例：y_i = s * x_i
+/// - x：private 的向量
+/// - s：public 的标量，放在 instance 列的第 0 行，用 load_instance 加载到电路中
+/// - y：public 的输出向量，依次放在 instance 列的第 1 ~ n 行
+///
+/// instance 列：
+///
+/// | row | 0 | 1   | ... | n   |
+/// |-----|---|-----|-----|-----|
+/// |     | s | y_0 | ... | y_{n-1} |
+///
+/// 电路外计算 public input：[s, s * x_0, ..., s * x_{n-1}]
+pub fn scale_public<F: PrimeField>(scalar: F, xs: &[F]) -> Vec<F> {
+    std::iter::once(scalar)
+        .chain(xs.iter().map(|x| scalar * x))
+        .collect()
+}
+
+pub struct VecScaleCircuit<F: PrimeField> {
+    pub xs: Vec<Value<F>>,
+}
+
+impl<F: PrimeField> VecScaleCircuit<F> {
+    pub fn new(xs: &[F]) -> Self {
+        Self {
+            xs: xs.iter().map(|x| Value::known(*x)).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for VecScaleCircuit<F> {
+    type Config = SimpleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    /// 向量长度决定了电路结构，需要保留
+    fn without_witnesses(&self) -> Self {
+        Self {
+            xs: vec![Value::unknown(); self.xs.len()],
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = [meta.advice_column(), meta.advice_column()];
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+
+        SimpleChip::configure(meta, advice, instance, constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = SimpleChip::<F>::construct(config);
+
+        let scalar = chip.load_instance(layouter.namespace(|| "load scalar"), 0)?;
+        for (i, x) in self.xs.iter().enumerate() {
+            let x = chip.load_private(layouter.namespace(|| format!("load x_{}", i)), *x)?;
+            let y = chip.mul(
+                layouter.namespace(|| format!("y_{} = s * x_{}", i, i)),
+                scalar.clone(),
+                x,
+            )?;
+            chip.expose_public(layouter.namespace(|| format!("expose y_{}", i)), y, i + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// 第 0 行为 s，之后每个输出一行
+impl<F: PrimeField> DescribeInstance for VecScaleCircuit<F> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![self.xs.len() + 1]
+    }
+}
+
+/// 4 个随机的 x（与示例 main 相同的长度）和随机的 s
+impl RandomWitness for VecScaleCircuit<Fp> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        let xs: Vec<Fp> = (0..4).map(|_| Fp::random(&mut *rng)).collect();
+        let scalar = Fp::random(&mut *rng);
+        (Self::new(&xs), vec![scale_public(scalar, &xs)])
+    }
+}
@@ -0,0 +1,30 @@
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::report;
+use vec_scale::{scale_public, VecScaleCircuit};
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // x = [1, 2, 3, 4]，s = 5，y = [5, 10, 15, 20]
+    let xs = [1, 2, 3, 4].map(Fp::from);
+    let circuit = VecScaleCircuit::new(&xs);
+    let public_input = scale_public(Fp::from(5), &xs);
+
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![public_input.clone()]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: vector scaling...");
+
+    let prover = MockProver::run(row, &circuit, vec![public_input.clone()]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 输出向量中的一项错误，验证失败
+    let mut public_input_2 = public_input;
+    public_input_2[2] = Fp::from(11);
+    let prover_2 = MockProver::run(row, &circuit, vec![public_input_2]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+}
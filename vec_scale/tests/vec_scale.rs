@@ -0,0 +1,43 @@
+//! vec_scale 示例电路的测试
+
+use halo2_proofs::{dev::MockProver, pasta::Fp};
+use simple::{dev, prover, prover::DescribeInstance};
+use vec_scale::{scale_public, VecScaleCircuit};
+
+const K: u32 = 5;
+
+/// x = [1, 2, 3, 4]，s = 5，y = [5, 10, 15, 20]；同一个电路可以用不同的 public 标量 s = 7；
+/// 输出向量中的一项错误时验证失败
+#[test]
+fn scaled_vector() {
+    let xs = [1, 2, 3, 4].map(Fp::from);
+    let circuit = VecScaleCircuit::new(&xs);
+    let public_input = scale_public(Fp::from(5), &xs);
+    assert_eq!(public_input[4], Fp::from(20));
+    assert_eq!(circuit.instance_shape(), vec![public_input.len()]);
+    for public_input in [public_input.clone(), scale_public(Fp::from(7), &xs)] {
+        let res = MockProver::run(K, &circuit, vec![public_input])
+            .unwrap()
+            .verify();
+        assert_eq!(res, Ok(()));
+    }
+
+    let mut wrong = public_input;
+    wrong[2] = Fp::from(11);
+    let res = MockProver::run(K, &circuit, vec![wrong]).unwrap().verify();
+    assert!(res.is_err());
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(dev::check_random::<VecScaleCircuit<Fp>>(K, 4), Ok(()));
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let xs = [1, 2, 3, 4].map(Fp::from);
+    let circuit = VecScaleCircuit::new(&xs);
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[scale_public(Fp::from(5), &xs)]).is_ok());
+}
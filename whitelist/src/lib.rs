@@ -0,0 +1,182 @@
+use group::ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector, TableColumn},
+    poly::Rotation,
+};
+use rand_core::RngCore;
+use simple::prover::DescribeInstance;
+use simple::witness::RandomWitness;
+use std::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////
+/// 白名单示例：证明一个 private 的值属于一个固定的小集合（白名单）
+/// 白名单在 configure 阶段传入，加载到 lookup table 中，private 的值通过 lookup 约束在表中。
+///
+/// 白名单的值，在电路结构中固定，由 Whitelist trait 在 configure 时提供
+pub trait Whitelist {
+    const ALLOWED: &'static [u64];
+}
+
+/// lookup table 有两列 (tag, value)：
+///
+/// | tag | value |
+/// |-----|-------|
+/// | 0   | 0     |  <- 默认行，未启用 lookup 的行查询的是 (0, 0)
+/// | 1   | v_0   |
+/// | 1   | v_1   |
+/// | ... | ...   |
+///
+/// lookup 的输入为 (s, s * value)：
+/// - s = 0 时输入为 (0, 0)，总能在默认行中找到
+/// - s = 1 时输入为 (1, value)，只能匹配 tag 为 1 的白名单行
+///
+/// 如果只用一列 value，未启用的行需要 0 在表中，那么 value = 0 也会被当作在白名单中；
+/// tag 列把“默认行”和“白名单行”区分开，空白名单时表中只有默认行，任何值都无法通过。
+#[derive(Clone, Debug)]
+pub struct WhitelistConfig<F: PrimeField> {
+    value: Column<Advice>,
+    s_lookup: Selector,
+    table_tag: TableColumn,
+    table_value: TableColumn,
+    allowed: Vec<F>,
+}
+
+#[derive(Clone, Debug)]
+pub struct WhitelistChip<F: PrimeField> {
+    config: WhitelistConfig<F>,
+}
+
+impl<F: PrimeField> WhitelistChip<F> {
+    pub fn construct(config: WhitelistConfig<F>) -> Self {
+        Self { config }
+    }
+
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+        allowed: &[u64],
+    ) -> WhitelistConfig<F> {
+        // lookup 中使用的选择器必须是 complex selector
+        let s_lookup = meta.complex_selector();
+        let table_tag = meta.lookup_table_column();
+        let table_value = meta.lookup_table_column();
+
+        meta.lookup(|meta| {
+            let s = meta.query_selector(s_lookup);
+            let v = meta.query_advice(value, Rotation::cur());
+            vec![(s.clone(), table_tag), (s * v, table_value)]
+        });
+
+        WhitelistConfig {
+            value,
+            s_lookup,
+            table_tag,
+            table_value,
+            allowed: allowed.iter().map(|v| F::from(*v)).collect(),
+        }
+    }
+
+    /// 加载 lookup table：第 0 行为默认行 (0, 0)，之后为白名单 (1, v)
+    pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = &self.config;
+        layouter.assign_table(
+            || "whitelist table",
+            |mut table| {
+                table.assign_cell(|| "tag", config.table_tag, 0, || Value::known(F::ZERO))?;
+                table.assign_cell(|| "value", config.table_value, 0, || Value::known(F::ZERO))?;
+                for (i, v) in config.allowed.iter().enumerate() {
+                    table.assign_cell(
+                        || "tag",
+                        config.table_tag,
+                        i + 1,
+                        || Value::known(F::ONE),
+                    )?;
+                    table.assign_cell(
+                        || "value",
+                        config.table_value,
+                        i + 1,
+                        || Value::known(*v),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// 加载 private 的值并约束它在白名单中
+    pub fn assert_in_whitelist(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "whitelist lookup",
+            |mut region| {
+                config.s_lookup.enable(&mut region, 0)?;
+                region.assign_advice(|| "value", config.value, 0, || value)
+            },
+        )
+    }
+}
+
+/// 电路中只有一个 private 的值，白名单由类型参数 W 提供
+pub struct WhitelistCircuit<F: PrimeField, W: Whitelist> {
+    pub value: Value<F>,
+    _marker: PhantomData<W>,
+}
+
+impl<F: PrimeField, W: Whitelist> WhitelistCircuit<F, W> {
+    pub fn new(value: Value<F>) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField, W: Whitelist> Circuit<F> for WhitelistCircuit<F, W> {
+    type Config = WhitelistConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new(Value::unknown())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let value = meta.advice_column();
+        WhitelistChip::configure(meta, value, W::ALLOWED)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = WhitelistChip::construct(config);
+        chip.load_table(layouter.namespace(|| "load table"))?;
+        chip.assert_in_whitelist(layouter.namespace(|| "check value"), self.value)?;
+        Ok(())
+    }
+}
+
+/// 没有 public input
+impl<F: PrimeField, W: Whitelist> DescribeInstance for WhitelistCircuit<F, W> {
+    fn instance_shape(&self) -> Vec<usize> {
+        vec![]
+    }
+}
+
+/// 从白名单中随机选一个值，没有 public input；空白名单没有合法的 witness
+impl<W: Whitelist> RandomWitness for WhitelistCircuit<Fp, W> {
+    fn random(rng: &mut impl RngCore) -> (Self, Vec<Vec<Fp>>) {
+        assert!(
+            !W::ALLOWED.is_empty(),
+            "empty whitelist has no valid witness"
+        );
+        let value = W::ALLOWED[rng.next_u32() as usize % W::ALLOWED.len()];
+        (Self::new(Value::known(Fp::from(value))), vec![])
+    }
+}
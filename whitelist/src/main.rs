@@ -0,0 +1,53 @@
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::report;
+use whitelist::{Whitelist, WhitelistCircuit};
+
+/// 白名单：几个小素数
+struct SmallPrimes;
+impl Whitelist for SmallPrimes {
+    const ALLOWED: &'static [u64] = &[2, 3, 5, 7, 11];
+}
+
+/// 空白名单：任何值都不允许
+struct Empty;
+impl Whitelist for Empty {
+    const ALLOWED: &'static [u64] = &[];
+}
+
+fn main() {
+    // 定义电路的行数
+    let row = 5;
+
+    // 电路中没有 public input
+    // 7 在白名单中，验证成功
+    let circuit = WhitelistCircuit::<Fp, SmallPrimes>::new(Value::known(Fp::from(7)));
+    // --json：只输出主电路的 JSON 报告
+    if report::maybe_emit_json(row, &circuit, vec![]) {
+        return;
+    }
+    println!("Hello, this is halo2 example: whitelist...");
+
+    let prover = MockProver::run(row, &circuit, vec![]).unwrap();
+    let res = prover.verify();
+    println!("res1: {:?}", res);
+
+    // 4 不在白名单中，验证失败
+    let circuit_2 = WhitelistCircuit::<Fp, SmallPrimes>::new(Value::known(Fp::from(4)));
+    let prover_2 = MockProver::run(row, &circuit_2, vec![]).unwrap();
+    let res_2 = prover_2.verify();
+    println!("res2: {:?}", res_2);
+
+    // 0 不在白名单中：虽然表中有默认行 (0, 0)，但 tag 不匹配，验证失败
+    let circuit_3 = WhitelistCircuit::<Fp, SmallPrimes>::new(Value::known(Fp::zero()));
+    let prover_3 = MockProver::run(row, &circuit_3, vec![]).unwrap();
+    let res_3 = prover_3.verify();
+    println!("res3: {:?}", res_3);
+
+    // 空白名单：0 和 7 都无法通过
+    for v in [0, 7] {
+        let circuit_4 = WhitelistCircuit::<Fp, Empty>::new(Value::known(Fp::from(v)));
+        let prover_4 = MockProver::run(row, &circuit_4, vec![]).unwrap();
+        let res_4 = prover_4.verify();
+        println!("res4 (empty, {}): {:?}", v, res_4);
+    }
+}
@@ -0,0 +1,63 @@
+//! whitelist 示例电路的测试
+
+use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
+use simple::{dev, prover};
+use whitelist::{Whitelist, WhitelistCircuit};
+
+const K: u32 = 5;
+
+/// 白名单：几个小素数
+struct SmallPrimes;
+impl Whitelist for SmallPrimes {
+    const ALLOWED: &'static [u64] = &[2, 3, 5, 7, 11];
+}
+
+/// 空白名单：任何值都不允许
+struct Empty;
+impl Whitelist for Empty {
+    const ALLOWED: &'static [u64] = &[];
+}
+
+fn verify<W: Whitelist>(v: u64) -> bool {
+    let circuit = WhitelistCircuit::<Fp, W>::new(Value::known(Fp::from(v)));
+    MockProver::run(K, &circuit, vec![])
+        .unwrap()
+        .verify()
+        .is_ok()
+}
+
+/// 7 在白名单中，验证成功；4 不在白名单中，验证失败
+#[test]
+fn small_primes() {
+    assert!(verify::<SmallPrimes>(7));
+    assert!(!verify::<SmallPrimes>(4));
+}
+
+/// 0 不在白名单中：虽然表中有默认行 (0, 0)，但 tag 不匹配，验证失败
+#[test]
+fn zero_is_not_allowed() {
+    assert!(!verify::<SmallPrimes>(0));
+}
+
+/// 空白名单：0 和 7 都无法通过
+#[test]
+fn empty_whitelist() {
+    assert!(!verify::<Empty>(0));
+    assert!(!verify::<Empty>(7));
+}
+
+/// 随机生成的 witness 同样能通过验证
+#[test]
+fn random_witness() {
+    assert_eq!(
+        dev::check_random::<WhitelistCircuit<Fp, SmallPrimes>>(K, 4),
+        Ok(())
+    );
+}
+
+/// 在内存中生成并验证真实的证明
+#[test]
+fn roundtrip_in_memory() {
+    let circuit = WhitelistCircuit::<Fp, SmallPrimes>::new(Value::known(Fp::from(7)));
+    assert!(prover::roundtrip_in_memory(K, &circuit, &[]).is_ok());
+}